@@ -1,13 +1,15 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
-use massa_models::address::ExecutionAddressCycleInfo;
+use massa_hash::Hash;
+use massa_models::address::{ExecutionAddressCycleInfo, ScheduledRollCount};
 use massa_models::endorsement::EndorsementId;
 use massa_models::operation::OperationId;
 use massa_models::slot::{IndexedSlot, Slot};
 use massa_models::{address::Address, amount::Amount, block_id::BlockId};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-use crate::slot::SlotAmount;
+use crate::{display_if_true, display_option, slot::SlotAmount};
 
 /// All you ever dream to know about an address
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -16,18 +18,20 @@ pub struct AddressInfo {
     pub address: Address,
     /// the thread the address belongs to
     pub thread: u8,
+    /// true if the address holds bytecode (final or candidate), i.e. is a smart contract
+    pub is_contract: bool,
 
-    /// final balance
-    pub final_balance: Amount,
-    /// final roll count
-    pub final_roll_count: u64,
+    /// final balance, `None` if the execution info is unavailable (best-effort mode only)
+    pub final_balance: Option<Amount>,
+    /// final roll count, `None` if the execution info is unavailable (best-effort mode only)
+    pub final_roll_count: Option<u64>,
     /// final datastore keys
     pub final_datastore_keys: Vec<Vec<u8>>,
 
-    /// candidate balance
-    pub candidate_balance: Amount,
-    /// candidate roll count
-    pub candidate_roll_count: u64,
+    /// candidate balance, `None` if the execution info is unavailable (best-effort mode only)
+    pub candidate_balance: Option<Amount>,
+    /// candidate roll count, `None` if the execution info is unavailable (best-effort mode only)
+    pub candidate_roll_count: Option<u64>,
     /// candidate datastore keys
     pub candidate_datastore_keys: Vec<Vec<u8>>,
 
@@ -46,22 +50,49 @@ pub struct AddressInfo {
     /// created endorsements
     pub created_endorsements: Vec<EndorsementId>,
 
+    /// created operations currently in the pool, still in flight
+    pub pool_operations: Vec<OperationId>,
+
     /// cycle information
     pub cycle_infos: Vec<ExecutionAddressCycleInfo>,
+
+    /// active roll counts scheduled for the upcoming cycles, reflecting pending roll buys and sells
+    #[serde(default)]
+    pub roll_schedule: Vec<ScheduledRollCount>,
+
+    /// sub-queries that failed in best-effort mode, by name, with their error.
+    /// The fields they fill are left empty
+    #[serde(default)]
+    pub unavailable: BTreeMap<String, String>,
+
+    /// label of the request, echoed to correlate the response with the node logs
+    #[serde(default)]
+    pub label: Option<String>,
 }
 
 impl std::fmt::Display for AddressInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Address {} (thread {}):", self.address, self.thread)?;
+        if let Some(label) = &self.label {
+            writeln!(f, "Label: {}", label)?;
+        }
+        writeln!(
+            f,
+            "Address {} (thread {}){}:",
+            self.address,
+            self.thread,
+            display_if_true(self.is_contract, "smart contract")
+        )?;
         writeln!(
             f,
             "\tBalance: final={}, candidate={}",
-            self.final_balance, self.candidate_balance
+            display_option(&self.final_balance, "unavailable"),
+            display_option(&self.candidate_balance, "unavailable")
         )?;
         writeln!(
             f,
             "\tRolls: final={}, candidate={}",
-            self.final_roll_count, self.candidate_roll_count
+            display_option(&self.final_roll_count, "unavailable"),
+            display_option(&self.candidate_roll_count, "unavailable")
         )?;
         write!(f, "\tLocked coins:")?;
         if self.deferred_credits.is_empty() {
@@ -75,6 +106,7 @@ impl std::fmt::Display for AddressInfo {
                 )?;
             }
         }
+        writeln!(f, "\tOperations in pool: {}", self.pool_operations.len())?;
         writeln!(f, "\tCycle infos:")?;
         for cycle_info in &self.cycle_infos {
             writeln!(
@@ -94,6 +126,23 @@ impl std::fmt::Display for AddressInfo {
                 },
             )?;
         }
+        writeln!(f, "\tScheduled active rolls:")?;
+        for scheduled in &self.roll_schedule {
+            writeln!(
+                f,
+                "\t\tCycle {} ({}): {} active rolls",
+                scheduled.cycle,
+                if scheduled.is_final {
+                    "final"
+                } else {
+                    "candidate"
+                },
+                scheduled.active_rolls,
+            )?;
+        }
+        for (name, error) in &self.unavailable {
+            writeln!(f, "\tUnavailable {} info: {}", name, error)?;
+        }
         //writeln!(f, "\tProduced blocks: {}", self.created_blocks.iter().map(|id| id.to_string()).intersperse(", ".into()).collect())?;
         //writeln!(f, "\tProduced operations: {}", self.created_operations.iter().map(|id| id.to_string()).intersperse(", ".into()).collect())?;
         //writeln!(f, "\tProduced endorsements: {}", self.created_endorsements.iter().map(|id| id.to_string()).intersperse(", ".into()).collect())?;
@@ -127,16 +176,16 @@ pub struct CompactAddressInfo {
     pub address: Address,
     /// the thread it is
     pub thread: u8,
-    /// candidate rolls
-    pub candidate_rolls: u64,
-    /// final rolls
-    pub final_rolls: u64,
+    /// candidate rolls, `None` if unavailable
+    pub candidate_rolls: Option<u64>,
+    /// final rolls, `None` if unavailable
+    pub final_rolls: Option<u64>,
     /// active rolls
     pub active_rolls: u64,
-    /// final balance
-    pub final_balance: Amount,
-    /// candidate balance
-    pub candidate_balance: Amount,
+    /// final balance, `None` if unavailable
+    pub final_balance: Option<Amount>,
+    /// candidate balance, `None` if unavailable
+    pub candidate_balance: Option<Amount>,
 }
 
 impl std::fmt::Display for CompactAddressInfo {
@@ -145,12 +194,15 @@ impl std::fmt::Display for CompactAddressInfo {
         writeln!(
             f,
             "\tBalance: final={}, candidate={}",
-            self.final_balance, self.candidate_balance
+            display_option(&self.final_balance, "unavailable"),
+            display_option(&self.candidate_balance, "unavailable")
         )?;
         writeln!(
             f,
             "\tRolls: active={}, final={}, candidate={}",
-            self.active_rolls, self.final_rolls, self.candidate_rolls
+            self.active_rolls,
+            display_option(&self.final_rolls, "unavailable"),
+            display_option(&self.candidate_rolls, "unavailable")
         )?;
         Ok(())
     }
@@ -177,3 +229,54 @@ impl std::fmt::Display for AddressFilter {
         }
     }
 }
+
+/// Hashes of the bytecode of an address, `None` when it holds no bytecode
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AddressBytecodeHash {
+    /// the address
+    pub address: Address,
+    /// hash of the final bytecode
+    pub final_hash: Option<Hash>,
+    /// hash of the candidate bytecode
+    pub candidate_hash: Option<Hash>,
+}
+
+/// Addresses found by one call of `find_addresses_with_key_prefix`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct KeyPrefixAddresses {
+    /// addresses having a datastore key starting with the prefix, in address order
+    pub addresses: Vec<Address>,
+    /// last visited address, from which to resume the scan. `None` once the end of the ledger is reached
+    pub next: Option<Address>,
+}
+
+/// Category of an address, given by its prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum AddressCategory {
+    /// user address, prefixed with `AU`
+    UserAddress,
+    /// smart contract address, prefixed with `AS`
+    SCAddress,
+}
+
+/// A valid address with its thread and category, as returned by `parse_address`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ParsedAddress {
+    /// the address
+    pub address: Address,
+    /// thread of the address
+    pub thread: u8,
+    /// category of the address
+    pub category: AddressCategory,
+}
+
+impl std::fmt::Display for ParsedAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Address: {}", self.address)?;
+        writeln!(f, "Thread: {}", self.thread)?;
+        match self.category {
+            AddressCategory::UserAddress => writeln!(f, "Category: user address"),
+            AddressCategory::SCAddress => writeln!(f, "Category: smart contract address"),
+        }
+    }
+}
@@ -1,10 +1,11 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 use massa_models::{address::Address, block::Block, block_id::BlockId, slot::Slot};
+use massa_time::MassaTime;
 
 use serde::{Deserialize, Serialize};
 
-use crate::display_if_true;
+use crate::{display_if_true, display_option};
 
 /// refactor to delete
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -26,6 +27,12 @@ pub struct BlockInfoContent {
     pub is_candidate: bool,
     /// true if discarded
     pub is_discarded: bool,
+    /// version the block was produced with
+    #[serde(default)]
+    pub current_version: u32,
+    /// version announced by the block producer, if any
+    #[serde(default)]
+    pub announced_version: Option<u32>,
     /// block
     pub block: Block,
 }
@@ -42,6 +49,15 @@ impl std::fmt::Display for BlockInfo {
                 display_if_true(content.is_in_blockclique, " (blockclique)"),
                 display_if_true(content.is_discarded, " (discarded)"),
             )?;
+            writeln!(
+                f,
+                "Version: {}{}",
+                content.current_version,
+                content
+                    .announced_version
+                    .map(|version| format!(" (announcing {})", version))
+                    .unwrap_or_default()
+            )?;
             writeln!(f, "Block: {}", content.block)?;
         } else {
             writeln!(f, "Block {} not found", self.id)?;
@@ -50,6 +66,30 @@ impl std::fmt::Display for BlockInfo {
     }
 }
 
+/// Blocks at a given slot, they can differ until the blockclique block of the slot is final
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SlotBlocks {
+    /// final block at the slot, if any
+    #[serde(rename = "final")]
+    pub final_block: Option<BlockInfo>,
+    /// blockclique block at the slot, if any, which can still be reorged out unless it is also final
+    #[serde(rename = "candidate")]
+    pub candidate_block: Option<BlockInfo>,
+}
+
+impl std::fmt::Display for SlotBlocks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.final_block {
+            Some(block) => writeln!(f, "Final block: {}", block.id)?,
+            None => writeln!(f, "No final block")?,
+        }
+        match &self.candidate_block {
+            Some(block) => writeln!(f, "Candidate block: {}", block.id),
+            None => writeln!(f, "No candidate block"),
+        }
+    }
+}
+
 /// A block resume (without the block itself)
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BlockSummary {
@@ -67,6 +107,12 @@ pub struct BlockSummary {
     pub creator: Address,
     /// the block parents
     pub parents: Vec<BlockId>,
+    /// version the block was produced with, unknown for stale blocks
+    #[serde(default)]
+    pub current_version: Option<u32>,
+    /// version announced by the block producer, if any
+    #[serde(default)]
+    pub announced_version: Option<u32>,
 }
 
 impl std::fmt::Display for BlockSummary {
@@ -81,6 +127,16 @@ impl std::fmt::Display for BlockSummary {
         )?;
         writeln!(f, "Slot: {}", self.slot)?;
         writeln!(f, "Creator: {}", self.creator)?;
+        if let Some(version) = self.current_version {
+            writeln!(
+                f,
+                "Version: {}{}",
+                version,
+                self.announced_version
+                    .map(|version| format!(" (announcing {})", version))
+                    .unwrap_or_default()
+            )?;
+        }
         writeln!(f, "Parents' IDs:")?;
         for parent in &self.parents {
             writeln!(f, "\t- {}", parent)?;
@@ -88,3 +144,117 @@ impl std::fmt::Display for BlockSummary {
         Ok(())
     }
 }
+
+/// A maximal clique containing a block, as listed by `get_cliques`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CliqueMembership {
+    /// index of the clique in the list of maximal cliques
+    pub index: usize,
+    /// clique fitness
+    pub fitness: u64,
+    /// true if the clique is the blockclique
+    pub is_blockclique: bool,
+}
+
+/// An active block competing at a given slot, with the cliques it belongs to
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SlotForkChoiceBlock {
+    /// id
+    pub id: BlockId,
+    /// the block creator
+    pub creator: Address,
+    /// fitness of the block itself
+    pub fitness: u64,
+    /// true if the block is final
+    pub is_final: bool,
+    /// true if the block is the one chosen by the blockclique at this slot (always the case of a final block)
+    pub is_in_blockclique: bool,
+    /// the maximal cliques containing the block (none for a final block)
+    pub cliques: Vec<CliqueMembership>,
+}
+
+impl std::fmt::Display for SlotForkChoiceBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Block's ID: {}{}{}",
+            self.id,
+            display_if_true(self.is_final, "final"),
+            display_if_true(self.is_in_blockclique, "in blockclique"),
+        )?;
+        writeln!(f, "Creator: {}", self.creator)?;
+        writeln!(f, "Fitness: {}", self.fitness)?;
+        writeln!(f, "Cliques:")?;
+        for clique in &self.cliques {
+            writeln!(
+                f,
+                "\t- #{} fitness {}{}",
+                clique.index,
+                clique.fitness,
+                display_if_true(clique.is_blockclique, "blockclique"),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A maximal clique with the summaries of its blocks, as listed by `get_graph_cliques_detailed`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DetailedClique {
+    /// index of the clique in the list of maximal cliques
+    pub index: usize,
+    /// clique fitness
+    pub fitness: u64,
+    /// true if the clique is the blockclique
+    pub is_blockclique: bool,
+    /// the blocks of the clique within the requested slot range, sorted by slot
+    pub blocks: Vec<BlockSummary>,
+}
+
+impl std::fmt::Display for DetailedClique {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Clique #{} fitness {}{}",
+            self.index,
+            self.fitness,
+            display_if_true(self.is_blockclique, "blockclique"),
+        )?;
+        for block in &self.blocks {
+            writeln!(
+                f,
+                "\t- {} at slot {}{}",
+                block.id,
+                block.slot,
+                display_if_true(block.is_final, "final"),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A blockclique block, the time at which it was due and the time at which it reached the node
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BlockTimestamp {
+    /// id
+    pub id: BlockId,
+    /// the slot the block is in
+    pub slot: Slot,
+    /// the timestamp of the slot, at which the block was expected to be created
+    pub timestamp: MassaTime,
+    /// the time at which the node received or produced the block, if still known
+    pub received_at: Option<MassaTime>,
+}
+
+impl std::fmt::Display for BlockTimestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Block {} at slot {} ({}), received at {}",
+            self.id,
+            self.slot,
+            self.timestamp,
+            display_option(&self.received_at, "unknown")
+        )
+    }
+}
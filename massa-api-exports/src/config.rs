@@ -1,7 +1,9 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
+use massa_models::{address::Address, amount::Amount};
 use massa_signature::KeyPair;
 use massa_time::MassaTime;
+use std::collections::BTreeMap;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 
@@ -21,6 +23,36 @@ pub struct APIConfig {
     pub bind_api: SocketAddr,
     /// max argument count
     pub max_arguments: u64,
+    /// max number of calls per second for each method (method name -> limit). Methods not listed are not limited
+    pub rate_limits: BTreeMap<String, u32>,
+    /// max total size in bytes of the payloads (bytecode, serialized operations) of a single request
+    pub max_request_bytes: u64,
+    /// max number of slots covered by the time interval of `get_graph_interval`
+    pub max_graph_interval_slots: u64,
+    /// max number of ledger addresses visited by a call of `find_addresses_with_key_prefix`
+    pub max_key_prefix_scan: u64,
+    /// whether to compress responses (gzip or deflate) for clients sending a matching `Accept-Encoding` header
+    pub enable_response_compression: bool,
+    /// responses smaller than this number of bytes are never compressed
+    pub compression_min_response_size: u16,
+    /// time during which the result of `get_status` is cached and shared between callers. 0 disables the cache
+    pub status_cache_ttl: MassaTime,
+    /// number of times a failing component query of `get_status` is retried, after a short backoff, before failing
+    pub status_retry_count: u32,
+    /// wall-clock time after which a read-only execution is reported as failed. 0 disables the deadline.
+    /// The execution itself cannot be interrupted and stays bounded by `max_read_only_gas`
+    pub read_only_deadline: MassaTime,
+    /// time after which `send_operations` stops waiting for the pool to accept its operations
+    pub pool_add_timeout: MassaTime,
+    /// caller address used by read-only executions that do not specify one.
+    /// Fixed so that repeated calls are deterministic, contracts branching on the caller all see this address
+    pub read_only_default_caller: Address,
+    /// max number of public API requests and read-only call subscriptions processed at the same time
+    pub max_concurrent_requests: usize,
+    /// whether the public API exposes the addresses the node stakes with (`get_active_staking_addresses`)
+    pub expose_staking_addresses: bool,
+    /// whether the public API exposes the ids of the operations in the pool (`get_pool_operation_ids`)
+    pub expose_pool_operations: bool,
     /// openrpc specification path
     pub openrpc_spec_path: PathBuf,
     /// bootstrap whitelist path
@@ -33,6 +65,8 @@ pub struct APIConfig {
     pub max_response_body_size: u32,
     /// maximum number of incoming connections allowed.
     pub max_connections: u32,
+    /// number of worker threads of the runtime each API server runs on.
+    pub worker_threads: usize,
     /// maximum number of subscriptions per connection.
     pub max_subscriptions_per_connection: u32,
     /// max length for logging for requests and responses. Logs bigger than this limit will be truncated.
@@ -61,6 +95,12 @@ pub struct APIConfig {
     pub max_parameter_size: u32,
     /// max gas in a block
     pub max_gas_per_block: u64,
+    /// max gas of a read-only execution
+    pub max_read_only_gas: u64,
+    /// storage cost of a new ledger entry, deducted from the coins that create it
+    pub ledger_entry_base_cost: Amount,
+    /// minimal fee of the operations accepted by the pool
+    pub minimal_fees: Amount,
     /// thread count
     pub thread_count: u8,
     /// `genesis_timestamp`
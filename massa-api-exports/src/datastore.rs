@@ -8,13 +8,17 @@ use serde::{Deserialize, Serialize};
 pub struct DatastoreEntryInput {
     /// associated address of the entry
     pub address: Address,
-    /// datastore key
+    /// datastore key, empty to get all the entries of the address (see `get_datastore_entries`)
     pub key: Vec<u8>,
 }
 
 /// Datastore entry query output structure
 #[derive(Debug, Deserialize, Clone, Serialize)]
 pub struct DatastoreEntryOutput {
+    /// associated address of the entry
+    pub address: Address,
+    /// datastore key
+    pub key: Vec<u8>,
     /// final datastore entry value
     pub final_value: Option<Vec<u8>>,
     /// candidate datastore entry value
@@ -23,6 +27,8 @@ pub struct DatastoreEntryOutput {
 
 impl std::fmt::Display for DatastoreEntryOutput {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "address: {}", self.address)?;
+        writeln!(f, "key: {:?}", self.key)?;
         writeln!(f, "final value: {:?}", self.final_value)?;
         writeln!(f, "candidate value: {:?}", self.candidate_value)?;
         Ok(())
@@ -0,0 +1,32 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use massa_models::{address::Address, slot::Slot};
+use serde::{Deserialize, Serialize};
+
+/// A denunciation of the pool waiting to be included in a block
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct PendingDenunciation {
+    /// the denounced address
+    pub address: Address,
+    /// slot of the denounced block header or endorsement
+    pub slot: Slot,
+    /// index of the denounced endorsement, `None` for a block header denunciation
+    pub index: Option<u32>,
+}
+
+impl std::fmt::Display for PendingDenunciation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.index {
+            Some(index) => writeln!(
+                f,
+                "Endorsement denunciation of {} at slot {} (index {})",
+                self.address, self.slot, index
+            ),
+            None => writeln!(
+                f,
+                "Block header denunciation of {} at slot {}",
+                self.address, self.slot
+            ),
+        }
+    }
+}
@@ -5,9 +5,11 @@ use jsonrpsee::types::{ErrorObject, ErrorObjectOwned};
 
 use massa_hash::MassaHashError;
 use massa_models::error::ModelsError;
+use massa_serialization::DeserializeError;
 use massa_time::TimeError;
 use massa_versioning::versioning_factory::FactoryError;
 use massa_wallet::WalletError;
+use serde::{Deserialize, Serialize};
 
 /// Errors of the api component.
 #[non_exhaustive]
@@ -31,8 +33,13 @@ pub enum ApiError {
     TimeError(#[from] TimeError),
     /// Wallet error: {0}
     WalletError(#[from] WalletError),
-    /// Not found
-    NotFound,
+    /// {entity} not found: {id}
+    NotFound {
+        /// kind of the missing entity (e.g. "address", "operation")
+        entity: String,
+        /// identifier of the missing entity
+        id: String,
+    },
     /// Inconsistency error: {0}
     InconsistencyError(String),
     /// Missing command sender: {0}
@@ -43,10 +50,73 @@ pub enum ApiError {
     WrongAPI,
     /// Bad request: {0}
     BadRequest(String),
+    /// Request too large: {0}
+    RequestTooLarge(String),
     /// Internal server error: {0}
     InternalServerError(String),
     /// Versioning Factory error: {0}
     FactoryError(#[from] FactoryError),
+    /// Selector not ready: {0}
+    SelectorNotReady(String),
+    /// Rate limited: {0}
+    RateLimited(String),
+    /// Service temporarily unavailable, retry later: {0}
+    ServiceUnavailable(String),
+    /// Deserialization error: {0}
+    DeserializeError(DeserializeErrorDetails),
+    /// Node overloaded: {0}
+    Overloaded(String),
+    /// Requested range too large: {0}
+    RangeTooLarge(String),
+}
+
+/// Where and why a deserialization failed, sent as the data of the JSON-RPC error
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeserializeErrorDetails {
+    /// offset in bytes in the input at which the deserialization failed, if known
+    pub offset: Option<usize>,
+    /// descriptions of the error, from the outermost context to the innermost error (e.g. the failing field)
+    pub context: Vec<String>,
+}
+
+impl DeserializeErrorDetails {
+    /// Build the details of a deserialization error.
+    /// `input_len` is the length of the input the offset is relative to,
+    /// which must end with the bytes that failed to deserialize.
+    pub fn new(err: &nom::Err<DeserializeError>, input_len: usize) -> Self {
+        match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => DeserializeErrorDetails {
+                offset: err
+                    .failed_input()
+                    .and_then(|failed_input| input_len.checked_sub(failed_input.len())),
+                context: err.contexts().map(str::to_string).collect(),
+            },
+            nom::Err::Incomplete(_) => DeserializeErrorDetails {
+                offset: Some(input_len),
+                context: vec!["incomplete input".to_string()],
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for DeserializeErrorDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.context.join(" / "))?;
+        if let Some(offset) = self.offset {
+            write!(f, " at byte {}", offset)?;
+        }
+        Ok(())
+    }
+}
+
+/// Structured data attached to some JSON-RPC errors
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ErrorData<'a> {
+    /// where and why a deserialization failed
+    Deserialize(&'a DeserializeErrorDetails),
+    /// what could not be found
+    NotFound { entity: &'a str, id: &'a str },
 }
 
 impl From<ApiError> for ErrorObjectOwned {
@@ -55,7 +125,7 @@ impl From<ApiError> for ErrorObjectOwned {
         let code = match err {
             ApiError::BadRequest(_) => -32000,
             ApiError::InternalServerError(_) => -32001,
-            ApiError::NotFound => -32004,
+            ApiError::NotFound { .. } => -32004,
             ApiError::SendChannelError(_) => -32006,
             ApiError::ReceiveChannelError(_) => -32007,
             ApiError::MassaHashError(_) => -32008,
@@ -70,8 +140,40 @@ impl From<ApiError> for ErrorObjectOwned {
             ApiError::MissingConfig(_) => -32018,
             ApiError::WrongAPI => -32019,
             ApiError::FactoryError(_) => -32020,
+            ApiError::RequestTooLarge(_) => -32021,
+            ApiError::SelectorNotReady(_) => -32022,
+            ApiError::RateLimited(_) => -32023,
+            ApiError::ServiceUnavailable(_) => -32024,
+            ApiError::DeserializeError(_) => -32025,
+            ApiError::Overloaded(_) => -32026,
+            ApiError::RangeTooLarge(_) => -32027,
+        };
+
+        let data = match &err {
+            ApiError::DeserializeError(details) => Some(ErrorData::Deserialize(details)),
+            ApiError::NotFound { entity, id } => Some(ErrorData::NotFound { entity, id }),
+            _ => None,
         };
+        ErrorObject::owned(code, err.to_string(), data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        ErrorObject::owned(code, err.to_string(), None::<()>)
+    #[test]
+    fn not_found_names_the_missing_entity() {
+        let err: ErrorObjectOwned = ApiError::NotFound {
+            entity: "operation".to_string(),
+            id: "O1abc".to_string(),
+        }
+        .into();
+        assert_eq!(err.code(), -32004);
+        assert_eq!(err.message(), "operation not found: O1abc");
+        assert_eq!(
+            err.data().map(|data| data.get()),
+            Some(r#"{"entity":"operation","id":"O1abc"}"#)
+        );
     }
 }
@@ -1,8 +1,25 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
-use massa_final_state::StateChanges;
-use massa_models::{address::Address, amount::Amount, output_event::SCOutputEvent, slot::Slot};
+use massa_final_state::{StateChanges, StateChangesDeserializer, StateChangesSerializer};
+use massa_models::{
+    address::Address,
+    amount::Amount,
+    execution::ExecutionTraceStep,
+    output_event::{SCOutputEvent, SCOutputEventDeserializer, SCOutputEventSerializer},
+    serialization::{StringDeserializer, StringSerializer, VecU8Deserializer, VecU8Serializer},
+    slot::{Slot, SlotDeserializer, SlotSerializer},
+};
+use massa_serialization::{
+    Deserializer, SerializeError, Serializer, U32VarIntDeserializer, U32VarIntSerializer,
+    U64VarIntDeserializer, U64VarIntSerializer,
+};
+use nom::{
+    error::{context, ContextError, ParseError},
+    multi::length_count,
+    IResult, Parser,
+};
 use serde::{Deserialize, Serialize};
+use std::ops::Bound::{Excluded, Included};
 use std::{collections::VecDeque, fmt::Display};
 
 /// The result of the read-only execution.
@@ -27,10 +44,19 @@ pub struct ExecuteReadOnlyResponse {
     pub gas_cost: u64,
     /// state changes caused by the execution step
     pub state_changes: StateChanges,
+    /// call trace of the execution, only set when requested, up to the failure if the execution failed. Not part of the binary encoding
+    #[serde(default)]
+    pub trace: Option<Vec<ExecutionTraceStep>>,
+    /// label of the request, echoed to correlate the response with the node logs. Not part of the binary encoding
+    #[serde(default)]
+    pub label: Option<String>,
 }
 
 impl Display for ExecuteReadOnlyResponse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(label) = &self.label {
+            writeln!(f, "Label: {}", label)?;
+        }
         writeln!(f, "Executed at slot: {}", self.executed_at)?;
         writeln!(
             f,
@@ -48,18 +74,219 @@ impl Display for ExecuteReadOnlyResponse {
                 writeln!(f, "{}", event)?; // id already displayed in event
             }
         }
+        if let Some(trace) = &self.trace {
+            writeln!(f, "Call trace:")?;
+            for step in trace.iter() {
+                writeln!(f, "\t{:?}", step)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Item of a streamed read-only call: the events as they are emitted, then the outcome of the call
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum ReadOnlyStreamItem {
+    /// An event emitted by the execution.
+    Event(SCOutputEvent),
+    /// The end of the execution, always the last item of the stream.
+    Done {
+        /// The slot at which the read-only execution occurred, `None` if it could not be run.
+        executed_at: Option<Slot>,
+        /// The result of the read-only execution.
+        result: ReadOnlyResult,
+        /// The gas cost for the execution
+        gas_cost: u64,
+        /// call trace of the execution, only set when requested
+        trace: Option<Vec<ExecutionTraceStep>>,
+    },
+}
+
+/// Serializer for `ExecuteReadOnlyResponse`, used by the binary variants of the read-only endpoints
+pub struct ExecuteReadOnlyResponseSerializer {
+    slot_serializer: SlotSerializer,
+    result_variant_serializer: U32VarIntSerializer,
+    result_ok_serializer: VecU8Serializer,
+    result_error_serializer: StringSerializer<U64VarIntSerializer, u64>,
+    u64_serializer: U64VarIntSerializer,
+    event_serializer: SCOutputEventSerializer,
+    state_changes_serializer: StateChangesSerializer,
+}
+
+impl ExecuteReadOnlyResponseSerializer {
+    /// Creates a `ExecuteReadOnlyResponseSerializer`
+    pub fn new() -> Self {
+        Self {
+            slot_serializer: SlotSerializer::new(),
+            result_variant_serializer: U32VarIntSerializer::new(),
+            result_ok_serializer: VecU8Serializer::new(),
+            result_error_serializer: StringSerializer::new(U64VarIntSerializer::new()),
+            u64_serializer: U64VarIntSerializer::new(),
+            event_serializer: SCOutputEventSerializer::new(),
+            state_changes_serializer: StateChangesSerializer::new(),
+        }
+    }
+}
+
+impl Default for ExecuteReadOnlyResponseSerializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Serializer<ExecuteReadOnlyResponse> for ExecuteReadOnlyResponseSerializer {
+    fn serialize(
+        &self,
+        value: &ExecuteReadOnlyResponse,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), SerializeError> {
+        self.slot_serializer.serialize(&value.executed_at, buffer)?;
+        match &value.result {
+            ReadOnlyResult::Ok(ret) => {
+                self.result_variant_serializer.serialize(&0, buffer)?;
+                self.result_ok_serializer.serialize(ret, buffer)?;
+            }
+            ReadOnlyResult::Error(err) => {
+                self.result_variant_serializer.serialize(&1, buffer)?;
+                self.result_error_serializer.serialize(err, buffer)?;
+            }
+        }
+        let events_len: u64 = value.output_events.len().try_into().map_err(|err| {
+            SerializeError::NumberTooBig(format!("too many output events: {}", err))
+        })?;
+        self.u64_serializer.serialize(&events_len, buffer)?;
+        for event in value.output_events.iter() {
+            self.event_serializer.serialize(event, buffer)?;
+        }
+        self.u64_serializer.serialize(&value.gas_cost, buffer)?;
+        self.state_changes_serializer
+            .serialize(&value.state_changes, buffer)?;
         Ok(())
     }
 }
 
+/// Deserializer for `ExecuteReadOnlyResponse`, decodes the responses of the binary variants of the read-only endpoints.
+/// The trace and the label are not part of the binary encoding and are left empty.
+pub struct ExecuteReadOnlyResponseDeserializer {
+    slot_deserializer: SlotDeserializer,
+    result_variant_deserializer: U32VarIntDeserializer,
+    result_ok_deserializer: VecU8Deserializer,
+    result_error_deserializer: StringDeserializer<U64VarIntDeserializer, u64>,
+    events_length_deserializer: U64VarIntDeserializer,
+    event_deserializer: SCOutputEventDeserializer,
+    gas_cost_deserializer: U64VarIntDeserializer,
+    state_changes_deserializer: StateChangesDeserializer,
+}
+
+impl ExecuteReadOnlyResponseDeserializer {
+    /// Creates a `ExecuteReadOnlyResponseDeserializer`
+    ///
+    /// # Arguments
+    /// * `thread_count`: number of threads
+    /// * `max_result_length`: maximum length of the returned value or of the error message
+    /// * `max_events`: maximum number of output events
+    /// * `event_deserializer`: deserializer of the output events
+    /// * `state_changes_deserializer`: deserializer of the state changes
+    pub fn new(
+        thread_count: u8,
+        max_result_length: u64,
+        max_events: u64,
+        event_deserializer: SCOutputEventDeserializer,
+        state_changes_deserializer: StateChangesDeserializer,
+    ) -> Self {
+        Self {
+            slot_deserializer: SlotDeserializer::new(
+                (Included(0), Included(u64::MAX)),
+                (Included(0), Excluded(thread_count)),
+            ),
+            result_variant_deserializer: U32VarIntDeserializer::new(Included(0), Included(1)),
+            result_ok_deserializer: VecU8Deserializer::new(
+                Included(0),
+                Included(max_result_length),
+            ),
+            result_error_deserializer: StringDeserializer::new(U64VarIntDeserializer::new(
+                Included(0),
+                Included(max_result_length),
+            )),
+            events_length_deserializer: U64VarIntDeserializer::new(
+                Included(0),
+                Included(max_events),
+            ),
+            event_deserializer,
+            gas_cost_deserializer: U64VarIntDeserializer::new(Included(0), Included(u64::MAX)),
+            state_changes_deserializer,
+        }
+    }
+}
+
+impl Deserializer<ExecuteReadOnlyResponse> for ExecuteReadOnlyResponseDeserializer {
+    fn deserialize<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+        &self,
+        buffer: &'a [u8],
+    ) -> IResult<&'a [u8], ExecuteReadOnlyResponse, E> {
+        let (rest, executed_at) = context("Failed executed_at deserialization", |input| {
+            self.slot_deserializer.deserialize(input)
+        })
+        .parse(buffer)?;
+        let (rest, variant) = context("Failed result variant deserialization", |input| {
+            self.result_variant_deserializer.deserialize(input)
+        })
+        .parse(rest)?;
+        let (rest, result) = match variant {
+            0 => context("Failed result deserialization", |input| {
+                self.result_ok_deserializer.deserialize(input)
+            })
+            .map(ReadOnlyResult::Ok)
+            .parse(rest)?,
+            _ => context("Failed error deserialization", |input| {
+                self.result_error_deserializer.deserialize(input)
+            })
+            .map(ReadOnlyResult::Error)
+            .parse(rest)?,
+        };
+        let (rest, output_events) = context(
+            "Failed output_events deserialization",
+            length_count(
+                |input| self.events_length_deserializer.deserialize(input),
+                |input| self.event_deserializer.deserialize(input),
+            ),
+        )
+        .parse(rest)?;
+        let (rest, gas_cost) = context("Failed gas_cost deserialization", |input| {
+            self.gas_cost_deserializer.deserialize(input)
+        })
+        .parse(rest)?;
+        let (rest, state_changes) = context("Failed state_changes deserialization", |input| {
+            self.state_changes_deserializer.deserialize(input)
+        })
+        .parse(rest)?;
+        Ok((
+            rest,
+            ExecuteReadOnlyResponse {
+                executed_at,
+                result,
+                output_events: output_events.into_iter().collect(),
+                gas_cost,
+                state_changes,
+                trace: None,
+                label: None,
+            },
+        ))
+    }
+}
+
 /// read only bytecode execution request
 #[derive(Debug, Deserialize, Clone, Serialize)]
 pub struct ReadOnlyBytecodeExecution {
     /// max available gas
     pub max_gas: u64,
-    /// byte code
+    /// byte code, must be empty if `from_address` is set
+    #[serde(default)]
     pub bytecode: Vec<u8>,
-    /// caller's address, optional
+    /// if set, the current bytecode of this address is executed instead of `bytecode`
+    #[serde(default)]
+    pub from_address: Option<Address>,
+    /// caller's address, optional. Defaults to the node's `read_only_default_caller`
     pub address: Option<Address>,
     /// Operation datastore, optional
     pub operation_datastore: Option<Vec<u8>>,
@@ -68,6 +295,9 @@ pub struct ReadOnlyBytecodeExecution {
     /// whether to start execution from final or active state. Default false
     #[serde(default)]
     pub is_final: bool,
+    /// label attached to the node logs of this execution, no effect on the execution itself
+    #[serde(default)]
+    pub label: Option<String>,
 }
 
 /// read SC call request
@@ -81,7 +311,7 @@ pub struct ReadOnlyCall {
     pub target_function: String,
     /// function parameter
     pub parameter: Vec<u8>,
-    /// caller's address, optional
+    /// caller's address, optional. Defaults to the node's `read_only_default_caller`
     pub caller_address: Option<Address>,
     /// coins
     pub coins: Option<Amount>,
@@ -90,4 +320,156 @@ pub struct ReadOnlyCall {
     /// whether to start execution from final or active state. Default false
     #[serde(default)]
     pub is_final: bool,
+    /// label attached to the node logs of this execution, no effect on the execution itself
+    #[serde(default)]
+    pub label: Option<String>,
+    /// record the call trace of the execution (calls, returns and coin transfers) and return it
+    /// in the response. Default false, the recording has a cost
+    #[serde(default)]
+    pub trace: bool,
+}
+
+/// read-only calls of several functions sharing the same caller context
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub struct ReadOnlyBulkCall {
+    /// max available gas of each call
+    pub max_gas: u64,
+    /// caller's address, optional. Defaults to the node's `read_only_default_caller`
+    pub caller_address: Option<Address>,
+    /// fee of each call
+    pub fee: Option<Amount>,
+    /// whether to start the executions from final or active state. Default false
+    #[serde(default)]
+    pub is_final: bool,
+    /// label attached to the node logs of the executions, no effect on the executions themselves
+    #[serde(default)]
+    pub label: Option<String>,
+    /// the functions to call, in order
+    pub calls: Vec<ReadOnlyCallTarget>,
+}
+
+/// function called by a `ReadOnlyBulkCall`
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub struct ReadOnlyCallTarget {
+    /// target address
+    pub target_address: Address,
+    /// target function
+    pub target_function: String,
+    /// function parameter
+    pub parameter: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use massa_models::config::{
+        ENDORSEMENT_COUNT, MAX_BOOTSTRAP_ASYNC_POOL_CHANGES, MAX_DATASTORE_ENTRY_COUNT,
+        MAX_DATASTORE_KEY_LENGTH, MAX_DATASTORE_VALUE_LENGTH, MAX_DEFERRED_CREDITS_LENGTH,
+        MAX_DENUNCIATION_CHANGES_LENGTH, MAX_EXECUTED_OPS_CHANGES_LENGTH, MAX_FUNCTION_NAME_LENGTH,
+        MAX_LEDGER_CHANGES_COUNT, MAX_PARAMETERS_SIZE, MAX_PRODUCTION_STATS_LENGTH,
+        MAX_ROLLS_COUNT_LENGTH, THREAD_COUNT,
+    };
+    use massa_models::output_event::EventExecutionContext;
+    use massa_serialization::DeserializeError;
+    use std::str::FromStr;
+
+    fn deserializer() -> ExecuteReadOnlyResponseDeserializer {
+        ExecuteReadOnlyResponseDeserializer::new(
+            THREAD_COUNT,
+            10_000,
+            100,
+            SCOutputEventDeserializer::new(THREAD_COUNT, 100, 10_000),
+            StateChangesDeserializer::new(
+                THREAD_COUNT,
+                MAX_BOOTSTRAP_ASYNC_POOL_CHANGES,
+                MAX_FUNCTION_NAME_LENGTH,
+                MAX_PARAMETERS_SIZE as u64,
+                MAX_LEDGER_CHANGES_COUNT,
+                MAX_DATASTORE_KEY_LENGTH,
+                MAX_DATASTORE_VALUE_LENGTH,
+                MAX_DATASTORE_ENTRY_COUNT,
+                MAX_ROLLS_COUNT_LENGTH,
+                MAX_PRODUCTION_STATS_LENGTH,
+                MAX_DEFERRED_CREDITS_LENGTH,
+                MAX_EXECUTED_OPS_CHANGES_LENGTH,
+                ENDORSEMENT_COUNT,
+                MAX_DENUNCIATION_CHANGES_LENGTH,
+            ),
+        )
+    }
+
+    #[test]
+    fn execute_read_only_response_round_trip() {
+        let event = SCOutputEvent {
+            context: EventExecutionContext {
+                slot: Slot::new(3, 1),
+                block: None,
+                read_only: true,
+                index_in_slot: 0,
+                call_stack: VecDeque::from([Address::from_str(
+                    "AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x",
+                )
+                .unwrap()]),
+                origin_operation_id: None,
+                is_final: false,
+                is_error: false,
+            },
+            data: "massa".to_string(),
+        };
+        let responses = [
+            ExecuteReadOnlyResponse {
+                executed_at: Slot::new(3, 1),
+                result: ReadOnlyResult::Ok(vec![1, 2, 3]),
+                output_events: VecDeque::from([event]),
+                gas_cost: 1234,
+                state_changes: Default::default(),
+                trace: None,
+                label: Some("not encoded".to_string()),
+            },
+            ExecuteReadOnlyResponse {
+                executed_at: Slot::new(4, 0),
+                result: ReadOnlyResult::Error("out of gas".to_string()),
+                output_events: Default::default(),
+                gas_cost: 0,
+                state_changes: Default::default(),
+                trace: None,
+                label: None,
+            },
+        ];
+
+        for response in responses {
+            let mut buffer = Vec::new();
+            ExecuteReadOnlyResponseSerializer::new()
+                .serialize(&response, &mut buffer)
+                .unwrap();
+            let (rest, decoded) = deserializer()
+                .deserialize::<DeserializeError>(&buffer)
+                .unwrap();
+            assert!(rest.is_empty());
+            assert_eq!(decoded.executed_at, response.executed_at);
+            match (&decoded.result, &response.result) {
+                (ReadOnlyResult::Ok(a), ReadOnlyResult::Ok(b)) => assert_eq!(a, b),
+                (ReadOnlyResult::Error(a), ReadOnlyResult::Error(b)) => assert_eq!(a, b),
+                _ => panic!("result variant mismatch"),
+            }
+            assert_eq!(decoded.output_events.len(), response.output_events.len());
+            for (a, b) in decoded
+                .output_events
+                .iter()
+                .zip(response.output_events.iter())
+            {
+                assert_eq!(a.data, b.data);
+                assert_eq!(a.context.call_stack, b.context.call_stack);
+            }
+            assert_eq!(decoded.gas_cost, response.gas_cost);
+            assert!(decoded.label.is_none());
+
+            // everything that is encoded survives the round trip
+            let mut reencoded = Vec::new();
+            ExecuteReadOnlyResponseSerializer::new()
+                .serialize(&decoded, &mut reencoded)
+                .unwrap();
+            assert_eq!(reencoded, buffer);
+        }
+    }
 }
@@ -17,6 +17,8 @@ pub mod block;
 pub mod config;
 /// datastore serialization / deserialization
 pub mod datastore;
+/// denunciations
+pub mod denunciation;
 /// endorsements
 pub mod endorsement;
 /// models error
@@ -65,6 +67,14 @@ fn display_option_bool(
     }
 }
 
+/// Help to format an optional value
+fn display_option<T: std::fmt::Display>(value: &Option<T>, text_none: &str) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => text_none.to_string(),
+    }
+}
+
 /// Just a wrapper with a optional beginning and end
 #[derive(Debug, Deserialize, Clone, Copy, Serialize)]
 pub struct TimeInterval {
@@ -1,13 +1,16 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
+use massa_models::amount::Amount;
 use massa_models::node::NodeId;
-use massa_models::stats::{ConsensusStats, ExecutionStats, NetworkStats};
+use massa_models::stats::{ConsensusStats, ExecutionStats, NetworkStats, PoolStats};
 use massa_models::{config::CompactConfig, slot::Slot, version::Version};
 use massa_time::MassaTime;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::net::IpAddr;
 
+use crate::display_option;
+
 /// node status
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct NodeStatus {
@@ -27,18 +30,24 @@ pub struct NodeStatus {
     pub next_cycle_time: MassaTime,
     /// connected nodes (node id, ip address, true if the connection is outgoing, false if incoming)
     pub connected_nodes: BTreeMap<NodeId, (IpAddr, bool)>,
-    /// latest slot, none if now is before genesis timestamp
+    /// latest slot derived from the current time (not from finality), none if now is before genesis timestamp
     pub last_slot: Option<Slot>,
     /// next slot
     pub next_slot: Slot,
+    /// latest final slot of each thread, according to consensus
+    pub final_slots: Vec<Slot>,
     /// consensus stats
     pub consensus_stats: ConsensusStats,
-    /// pool stats (operation count and endorsement count)
-    pub pool_stats: (usize, usize),
+    /// pool stats
+    pub pool_stats: PoolStats,
     /// network stats
     pub network_stats: NetworkStats,
     /// execution stats
     pub execution_stats: ExecutionStats,
+    /// true if the node runs a bootstrap server
+    pub is_bootstrap_server: bool,
+    /// number of bootstrap sessions currently served
+    pub active_bootstrap_sessions: u64,
     /// compact configuration
     pub config: CompactConfig,
 }
@@ -63,19 +72,36 @@ impl std::fmt::Display for NodeStatus {
             writeln!(f, "Last slot: {}", self.last_slot.unwrap())?;
         }
         writeln!(f, "Next slot: {}", self.next_slot)?;
+        writeln!(
+            f,
+            "Final slots: {}",
+            self.final_slots
+                .iter()
+                .map(|slot| slot.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
         writeln!(f)?;
 
         writeln!(f, "{}", self.consensus_stats)?;
 
-        writeln!(f, "Pool stats:")?;
-        writeln!(f, "\tOperations count: {}", self.pool_stats.0)?;
-        writeln!(f, "\tEndorsements count: {}", self.pool_stats.1)?;
-        writeln!(f)?;
+        writeln!(f, "{}", self.pool_stats)?;
 
         writeln!(f, "{}", self.network_stats)?;
 
         writeln!(f, "{}", self.execution_stats)?;
 
+        if self.is_bootstrap_server {
+            writeln!(
+                f,
+                "Bootstrap server: {} active session(s)",
+                self.active_bootstrap_sessions
+            )?;
+        } else {
+            writeln!(f, "Bootstrap server: disabled")?;
+        }
+        writeln!(f)?;
+
         writeln!(f, "Connected nodes:")?;
         for (node_id, (ip_addr, is_outgoing)) in &self.connected_nodes {
             writeln!(
@@ -89,3 +115,99 @@ impl std::fmt::Display for NodeStatus {
         Ok(())
     }
 }
+
+/// cheap node health, meant for liveness and readiness probes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NodeHealth {
+    /// true if the node is ready to answer requests about the current state
+    pub ready: bool,
+    /// true if the execution lags more than one period behind the current slot
+    pub syncing: bool,
+}
+
+impl std::fmt::Display for NodeHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Ready: {}", self.ready)?;
+        writeln!(f, "Syncing: {}", self.syncing)
+    }
+}
+
+/// fee and gas limits applied by the node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FeeParameters {
+    /// minimal fee for an operation to be accepted by the pool
+    pub minimal_fee: Amount,
+    /// max gas that the operations of a block can use, an operation using more is never included
+    pub max_gas_per_block: u64,
+    /// max gas that a read-only execution can use
+    pub max_read_only_gas: u64,
+}
+
+impl std::fmt::Display for FeeParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Minimal fee: {}", self.minimal_fee)?;
+        writeln!(f, "Max gas per block: {}", self.max_gas_per_block)?;
+        writeln!(f, "Max read-only gas: {}", self.max_read_only_gas)
+    }
+}
+
+/// versions of the node and of the network protocol
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VersionInfo {
+    /// node version
+    pub version: Version,
+    /// network version currently active, set as `current_version` in the headers of produced blocks.
+    /// Blocks received from peers must carry the version active at their slot
+    pub current_network_version: u32,
+    /// network version announced in the headers of produced blocks (`announced_version`), if a version transition is started or locked in
+    pub announced_network_version: Option<u32>,
+}
+
+impl std::fmt::Display for VersionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Version: {}", self.version)?;
+        writeln!(
+            f,
+            "Current network version: {}",
+            self.current_network_version
+        )?;
+        match self.announced_network_version {
+            Some(version) => writeln!(f, "Announced network version: {}", version),
+            None => writeln!(f, "Announced network version: none"),
+        }
+    }
+}
+
+/// a peer currently banned by the node
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BannedPeerInfo {
+    /// node id of the banned peer
+    pub node_id: NodeId,
+    /// IPs the peer last announced it listens on
+    pub ips: Vec<IpAddr>,
+    /// time at which the peer was banned, if known
+    pub banned_at: Option<MassaTime>,
+    /// why the peer was banned, if known
+    pub reason: Option<String>,
+}
+
+impl std::fmt::Display for BannedPeerInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Node id: {}", self.node_id)?;
+        writeln!(
+            f,
+            "IPs: {}",
+            self.ips
+                .iter()
+                .map(|ip| ip.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+        writeln!(
+            f,
+            "Banned at: {}",
+            display_option(&self.banned_at, "unknown")
+        )?;
+        writeln!(f, "Reason: {}", display_option(&self.reason, "unknown"))
+    }
+}
@@ -1,14 +1,18 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 use massa_models::{
+    address::Address,
+    amount::Amount,
+    block_header::SecuredHeader,
     block_id::BlockId,
     operation::{OperationId, SecureShareOperation},
+    slot::Slot,
 };
 
 use massa_signature::{PublicKey, Signature};
 use serde::{Deserialize, Serialize};
 
-use crate::{display_if_true, display_option_bool};
+use crate::{display_if_true, display_option, display_option_bool, execution::ReadOnlyResult};
 
 /// operation input
 #[derive(Serialize, Deserialize, Debug)]
@@ -21,6 +25,81 @@ pub struct OperationInput {
     pub serialized_content: Vec<u8>,
 }
 
+/// result of the dry-run validation of an operation input
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OperationValidity {
+    /// id of the operation, known if it could be deserialized and verified
+    pub id: Option<OperationId>,
+    /// true if the operation would be sent to the pool by `send_operations`
+    pub is_valid: bool,
+    /// reason why the operation is invalid
+    pub error: Option<String>,
+}
+
+impl std::fmt::Display for OperationValidity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.id, &self.error) {
+            (Some(id), _) if self.is_valid => writeln!(f, "Operation {} is valid", id),
+            (_, Some(err)) => writeln!(f, "Invalid operation: {}", err),
+            _ => writeln!(f, "Invalid operation"),
+        }
+    }
+}
+
+/// operation decoded from its serialized form, nothing is submitted
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DecodedOperation {
+    /// the decoded operation, with its id and creator address
+    pub operation: SecureShareOperation,
+    /// bytes left after the operation, non-empty if the input is not correctly framed
+    pub rest: Vec<u8>,
+    /// result of the signature verification, `None` if it was not requested
+    pub signature_valid: Option<bool>,
+}
+
+/// ledger changes an operation would make if it was executed now, nothing is persisted
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OperationSimulation {
+    /// id of the simulated operation
+    pub id: OperationId,
+    /// slot at which the simulation was run, `None` if the execution failed or for a transaction, which is not executed by the VM
+    pub executed_at: Option<Slot>,
+    /// result of the execution
+    pub result: ReadOnlyResult,
+    /// gas spent by the execution, 0 for a transaction
+    pub gas_cost: u64,
+    /// new balance of each address whose balance would change
+    pub balance_changes: Vec<(Address, Amount)>,
+    /// datastore entries that would change: address, key, and new value (`None` if deleted)
+    pub datastore_changes: Vec<(Address, Vec<u8>, Option<Vec<u8>>)>,
+}
+
+impl std::fmt::Display for OperationSimulation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.executed_at {
+            Some(slot) => writeln!(f, "Simulation of operation {} at slot {}", self.id, slot)?,
+            None => writeln!(f, "Simulation of operation {}", self.id)?,
+        }
+        match &self.result {
+            ReadOnlyResult::Error(e) => writeln!(f, "Execution failed: {}", e)?,
+            ReadOnlyResult::Ok(_) => writeln!(f, "Execution succeeded")?,
+        }
+        writeln!(f, "Gas cost: {}", self.gas_cost)?;
+        for (address, balance) in &self.balance_changes {
+            writeln!(f, "Balance of {}: {}", address, balance)?;
+        }
+        for (address, key, value) in &self.datastore_changes {
+            match value {
+                Some(value) => {
+                    writeln!(f, "Datastore of {}: {:?} set to {:?}", address, key, value)?
+                }
+                None => writeln!(f, "Datastore of {}: {:?} deleted", address, key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Operation and contextual info about it
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OperationInfo {
@@ -31,7 +110,8 @@ pub struct OperationInfo {
     /// the operation appears in `in_blocks`
     /// if it appears in multiple blocks, these blocks are in different cliques
     pub in_blocks: Vec<BlockId>,
-    /// true if the operation is final (for example in a final block)
+    /// true if the operation was executed in a final slot,
+    /// unless consensus still holds the `in_blocks` it knows as non-final
     pub is_operation_final: Option<bool>,
     /// Thread in which the operation can be included
     pub thread: u8,
@@ -39,6 +119,10 @@ pub struct OperationInfo {
     pub operation: SecureShareOperation,
     /// true if the operation execution succeeded, false if failed, None means unknown
     pub op_exec_status: Option<bool>,
+    /// fee paid by the operation
+    pub fee: Amount,
+    /// serialized size of the operation in bytes, as propagated on the network
+    pub size_bytes: usize,
 }
 
 impl std::fmt::Display for OperationInfo {
@@ -56,6 +140,7 @@ impl std::fmt::Display for OperationInfo {
             ),
             display_option_bool(self.op_exec_status, "succes", "failed", "status unknown")
         )?;
+        writeln!(f, "Fee: {}, size: {} bytes", self.fee, self.size_bytes)?;
         writeln!(f, "In blocks:")?;
         for block_id in &self.in_blocks {
             writeln!(f, "\t- {}", block_id)?;
@@ -65,6 +150,46 @@ impl std::fmt::Display for OperationInfo {
     }
 }
 
+/// Proof that an operation is included in a block.
+///
+/// The `operation_merkle_root` of a block header is a hash computed over the ordered list
+/// of the block operation ids (see `compute_operations_hash`), so the proof carries the whole list:
+/// a verifier checks the header signature, recomputes the root from `operation_ids`
+/// and checks that `operation_ids[index]` is the operation.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OperationInclusionProof {
+    /// id of the block containing the operation
+    pub block_id: BlockId,
+    /// signed header of the block, carrying the `operation_merkle_root`
+    pub header: SecuredHeader,
+    /// index of the operation in the block
+    pub index: usize,
+    /// ordered ids of all the operations of the block
+    pub operation_ids: Vec<OperationId>,
+}
+
+impl std::fmt::Display for OperationInclusionProof {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Operation {} is at index {} in block {}",
+            display_option(&self.operation_ids.get(self.index), "(no operation id)"),
+            self.index,
+            self.block_id
+        )?;
+        writeln!(
+            f,
+            "Operation merkle root: {}",
+            self.header.content.operation_merkle_root
+        )?;
+        writeln!(f, "Block operations:")?;
+        for op_id in &self.operation_ids {
+            writeln!(f, "\t- {}", op_id)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use jsonrpsee::core::__reexports::serde_json::{self, Value};
@@ -12,3 +12,42 @@ pub struct SlotAmount {
     /// amount
     pub amount: Amount,
 }
+
+/// execution cursors, as processed by the execution module
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct ExecutionSlots {
+    /// last slot executed in the final state
+    pub final_slot: Slot,
+    /// last slot executed in the active (speculative) state
+    pub active_slot: Slot,
+}
+
+impl std::fmt::Display for ExecutionSlots {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Final slot: {}", self.final_slot)?;
+        writeln!(f, "Active slot: {}", self.active_slot)?;
+        Ok(())
+    }
+}
+
+/// execution progress of a single thread
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct ThreadExecutionSlots {
+    /// thread
+    pub thread: u8,
+    /// last slot of the thread executed in the final state
+    pub final_slot: Slot,
+    /// last slot of the thread executed in the active (speculative) state
+    pub active_slot: Slot,
+}
+
+impl std::fmt::Display for ThreadExecutionSlots {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Thread {}: final slot {}, active slot {}",
+            self.thread, self.final_slot, self.active_slot
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(feature = "benchmarking")]
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[cfg(feature = "benchmarking")]
+fn criterion_benchmark(c: &mut Criterion) {
+    use massa_models::operation::SecureShareOperation;
+    use massa_protocol_exports::test_exports::tools::create_operation_with_expire_period;
+    use massa_signature::KeyPair;
+    use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+
+    // same batch size as a large `send_operations` request
+    let keypair = KeyPair::generate(0).unwrap();
+    let operations: Vec<SecureShareOperation> = (0..100)
+        .map(|expire_period| create_operation_with_expire_period(&keypair, expire_period))
+        .collect();
+
+    c.bench_function("verify 100 operations sequentially", |b| {
+        b.iter(|| {
+            black_box(&operations)
+                .iter()
+                .map(|op| op.verify_signature())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        })
+    });
+
+    c.bench_function("verify 100 operations in parallel", |b| {
+        b.iter(|| {
+            black_box(&operations)
+                .par_iter()
+                .map(|op| op.verify_signature())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        })
+    });
+}
+
+#[cfg(feature = "benchmarking")]
+criterion_group!(benches, criterion_benchmark);
+
+#[cfg(feature = "benchmarking")]
+criterion_main!(benches);
+
+#[cfg(not(feature = "benchmarking"))]
+fn main() {
+    println!("Please use the `--features benchmarking` flag to run this benchmark.");
+}
@@ -0,0 +1,67 @@
+//! Copyright (c) 2022 MASSA LABS <info@massa.net>
+//! Admission control of the API requests, shared by the public API and the API v2.
+
+use crate::rate_limiter::RateLimiter;
+use massa_api_exports::error::ApiError;
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Rate limits, request counts and in-flight request permits.
+/// Clones share the same state, so that the limits apply to all the APIs given a clone.
+#[derive(Clone)]
+pub struct RequestAdmission {
+    /// per-method rate limiter
+    rate_limiter: Arc<RateLimiter>,
+    /// number of requests received by each method since the node started
+    request_counts: Arc<Mutex<BTreeMap<String, u64>>>,
+    /// bounds the number of requests processed at the same time, see `max_concurrent_requests`.
+    /// Read-only executions running past `read_only_deadline` keep the permit of their request
+    request_semaphore: Arc<Semaphore>,
+}
+
+impl RequestAdmission {
+    /// Create the admission control, allowing up to `max_concurrent_requests` requests at the same time
+    pub fn new(max_concurrent_requests: usize) -> Self {
+        RequestAdmission {
+            rate_limiter: Default::default(),
+            request_counts: Default::default(),
+            request_semaphore: Arc::new(Semaphore::new(max_concurrent_requests)),
+        }
+    }
+
+    /// Admit a request to `method`: check its rate limit in `rate_limits`, then take one of the in-flight request permits.
+    /// The permit is released when dropped, at the end of the request.
+    pub(crate) fn admit(
+        &self,
+        method: &str,
+        rate_limits: &BTreeMap<String, u32>,
+    ) -> Result<OwnedSemaphorePermit, ApiError> {
+        *self
+            .request_counts
+            .lock()
+            .entry(method.to_string())
+            .or_default() += 1;
+        if !self.rate_limiter.try_acquire(method, rate_limits) {
+            return Err(ApiError::RateLimited(format!(
+                "too many `{}` requests, retry later",
+                method
+            )));
+        }
+        self.request_semaphore
+            .clone()
+            .try_acquire_owned()
+            .map_err(|_| {
+                ApiError::Overloaded(format!(
+                    "too many requests in progress, `{}` rejected, retry later",
+                    method
+                ))
+            })
+    }
+
+    /// number of requests received by each method since the node started
+    pub(crate) fn request_counts(&self) -> BTreeMap<String, u64> {
+        self.request_counts.lock().clone()
+    }
+}
@@ -3,7 +3,7 @@
 use std::net::SocketAddr;
 
 use crate::api_trait::MassaApiServer;
-use crate::{ApiServer, ApiV2, StopHandle, API};
+use crate::{ApiServer, ApiV2, RequestAdmission, StopHandle, API};
 use async_trait::async_trait;
 use futures::future::{self, Either};
 use futures::StreamExt;
@@ -11,10 +11,13 @@ use jsonrpsee::core::{Error as JsonRpseeError, RpcResult, SubscriptionResult};
 use jsonrpsee::{PendingSubscriptionSink, SubscriptionMessage};
 use massa_api_exports::config::APIConfig;
 use massa_api_exports::error::ApiError;
+use massa_api_exports::execution::{ReadOnlyCall, ReadOnlyResult, ReadOnlyStreamItem};
 use massa_api_exports::page::{PageRequest, PagedVec, PagedVecV2};
 use massa_api_exports::ApiRequest;
 use massa_consensus_exports::{ConsensusBroadcasts, ConsensusController};
-use massa_execution_exports::ExecutionController;
+use massa_execution_exports::{
+    ExecutionController, ExecutionStackElement, ReadOnlyExecutionRequest, ReadOnlyExecutionTarget,
+};
 use massa_models::address::Address;
 use massa_models::block_id::BlockId;
 use massa_models::slot::Slot;
@@ -25,6 +28,9 @@ use massa_time::MassaTime;
 use serde::Serialize;
 use tokio_stream::wrappers::BroadcastStream;
 
+/// Number of events of a streamed read-only call that can wait to be sent to the client
+const READ_ONLY_EVENT_STREAM_CAPACITY: usize = 1024;
+
 impl API<ApiV2> {
     /// generate a new massa API
     pub fn new(
@@ -34,6 +40,7 @@ impl API<ApiV2> {
         pool_broadcasts: PoolBroadcasts,
         api_settings: APIConfig,
         version: Version,
+        admission: RequestAdmission,
     ) -> Self {
         API(ApiV2 {
             consensus_controller,
@@ -42,6 +49,7 @@ impl API<ApiV2> {
             pool_broadcasts,
             api_settings,
             version,
+            admission,
         })
     }
 }
@@ -152,6 +160,118 @@ impl MassaApiServer for API<ApiV2> {
     ) -> SubscriptionResult {
         broadcast_via_ws(self.0.pool_broadcasts.operation_sender.clone(), pending).await
     }
+
+    async fn subscribe_read_only_call(
+        &self,
+        pending: PendingSubscriptionSink,
+        call: ReadOnlyCall,
+    ) -> SubscriptionResult {
+        let api_settings = &self.0.api_settings;
+        // the permit is held until the end of the execution
+        let permit = match self
+            .0
+            .admission
+            .admit("subscribe_read_only_call", &api_settings.rate_limits)
+        {
+            Ok(permit) => permit,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(());
+            }
+        };
+        if let Err(err) =
+            crate::check_read_only_costs(api_settings, call.max_gas, call.fee, call.coins)
+        {
+            pending.reject(err).await;
+            return Ok(());
+        }
+
+        let caller_address = call
+            .caller_address
+            .unwrap_or(api_settings.read_only_default_caller);
+        // the execution fails if the client does not consume the events fast enough
+        let (event_sender, mut event_receiver) =
+            tokio::sync::mpsc::channel(READ_ONLY_EVENT_STREAM_CAPACITY);
+        let req = ReadOnlyExecutionRequest {
+            max_gas: call.max_gas,
+            target: ReadOnlyExecutionTarget::FunctionCall {
+                target_func: call.target_function,
+                target_addr: call.target_address,
+                parameter: call.parameter,
+            },
+            call_stack: vec![
+                ExecutionStackElement {
+                    address: caller_address,
+                    coins: Default::default(),
+                    owned_addresses: vec![caller_address],
+                    operation_datastore: None, // should always be None
+                },
+                ExecutionStackElement {
+                    address: call.target_address,
+                    coins: call.coins.unwrap_or_default(),
+                    owned_addresses: vec![call.target_address],
+                    operation_datastore: None, // should always be None
+                },
+            ],
+            is_final: call.is_final,
+            coins: call.coins,
+            fee: call.fee,
+            label: call.label,
+            trace: call.trace,
+            event_sender: Some(event_sender),
+        };
+
+        let sink = pending.accept().await?;
+
+        // if the client leaves, the execution fails at its next event or completes bounded by its gas
+        let execution_controller = self.0.execution_controller.clone();
+        let mut execution = tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            execution_controller.execute_readonly_request(req)
+        });
+        let result = loop {
+            tokio::select! {
+                Some(event) = event_receiver.recv() => {
+                    let notif = SubscriptionMessage::from_json(&ReadOnlyStreamItem::Event(event))?;
+                    if sink.send(notif).await.is_err() {
+                        return Ok(());
+                    }
+                }
+                result = &mut execution => break result,
+            }
+        };
+
+        // events emitted right before the end of the execution may not be forwarded yet
+        while let Ok(event) = event_receiver.try_recv() {
+            let notif = SubscriptionMessage::from_json(&ReadOnlyStreamItem::Event(event))?;
+            if sink.send(notif).await.is_err() {
+                return Ok(());
+            }
+        }
+
+        let done = match result {
+            Ok(Ok(output)) => ReadOnlyStreamItem::Done {
+                executed_at: Some(output.out.slot),
+                result: ReadOnlyResult::Ok(output.call_result),
+                gas_cost: output.gas_cost,
+                trace: output.trace,
+            },
+            Ok(Err(err)) => ReadOnlyStreamItem::Done {
+                executed_at: None,
+                result: ReadOnlyResult::Error(format!("readonly call failed: {}", err)),
+                gas_cost: 0,
+                trace: None,
+            },
+            Err(err) => ReadOnlyStreamItem::Done {
+                executed_at: None,
+                result: ReadOnlyResult::Error(format!("readonly call failed: {}", err)),
+                gas_cost: 0,
+                trace: None,
+            },
+        };
+        let _ = sink.send(SubscriptionMessage::from_json(&done)?).await;
+        Ok(())
+    }
 }
 
 // Brodcast the stream(sender) content via a WebSocket
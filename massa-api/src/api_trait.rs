@@ -2,6 +2,7 @@
 //! Json RPC API for a massa-node
 use jsonrpsee::core::{RpcResult, SubscriptionResult};
 use jsonrpsee::proc_macros::rpc;
+use massa_api_exports::execution::ReadOnlyCall;
 use massa_api_exports::page::PagedVecV2;
 use massa_api_exports::ApiRequest;
 use massa_models::address::Address;
@@ -57,4 +58,14 @@ pub trait MassaApi {
 		item = Operation
 	)]
     async fn subscribe_new_operations(&self) -> SubscriptionResult;
+
+    /// Read-only call whose events are streamed as they are emitted, followed by a `Done` item carrying its result.
+    /// The call fails if the client does not consume the events fast enough.
+    /// Admitted like the public API requests: it counts against their rate limits and concurrent request limit.
+    #[subscription(
+        name = "subscribe_read_only_call" => "read_only_call",
+        unsubscribe = "unsubscribe_read_only_call",
+        item = ReadOnlyStreamItem
+    )]
+    async fn subscribe_read_only_call(&self, call: ReadOnlyCall) -> SubscriptionResult;
 }
@@ -4,55 +4,76 @@
 #![warn(missing_docs)]
 #![warn(unused_crate_dependencies)]
 
+pub use admission::RequestAdmission;
 use api_trait::MassaApiServer;
-use hyper::Method;
+use hyper::http::Extensions;
+use hyper::{HeaderMap, Method, StatusCode};
 use jsonrpsee::core::{Error as JsonRpseeError, RpcResult};
 use jsonrpsee::proc_macros::rpc;
 use jsonrpsee::server::middleware::HostFilterLayer;
 use jsonrpsee::server::{BatchRequestConfig, ServerBuilder, ServerHandle};
 use jsonrpsee::RpcModule;
 use massa_api_exports::{
-    address::{AddressFilter, AddressInfo},
-    block::{BlockInfo, BlockSummary},
+    address::{AddressBytecodeHash, AddressFilter, AddressInfo, KeyPrefixAddresses, ParsedAddress},
+    block::{
+        BlockInfo, BlockSummary, BlockTimestamp, DetailedClique, SlotBlocks, SlotForkChoiceBlock,
+    },
     config::APIConfig,
     datastore::{DatastoreEntryInput, DatastoreEntryOutput},
+    denunciation::PendingDenunciation,
     endorsement::EndorsementInfo,
-    error::ApiError::WrongAPI,
-    execution::{ExecuteReadOnlyResponse, ReadOnlyBytecodeExecution, ReadOnlyCall},
-    node::NodeStatus,
-    operation::{OperationInfo, OperationInput},
+    error::ApiError::{self, WrongAPI},
+    execution::{
+        ExecuteReadOnlyResponse, ReadOnlyBulkCall, ReadOnlyBytecodeExecution, ReadOnlyCall,
+    },
+    node::{BannedPeerInfo, FeeParameters, NodeHealth, NodeStatus, VersionInfo},
+    operation::{
+        DecodedOperation, OperationInclusionProof, OperationInfo, OperationInput,
+        OperationSimulation, OperationValidity,
+    },
     page::{PageRequest, PagedVec},
+    slot::{ExecutionSlots, SlotAmount, ThreadExecutionSlots},
     TimeInterval,
 };
 use massa_consensus_exports::{ConsensusBroadcasts, ConsensusController};
 use massa_execution_exports::ExecutionController;
+use massa_ledger_exports::LedgerStats;
 use massa_models::clique::Clique;
 use massa_models::composite::PubkeySig;
 use massa_models::node::NodeId;
 use massa_models::operation::OperationId;
 use massa_models::output_event::SCOutputEvent;
-use massa_models::prehash::PreHashSet;
+use massa_models::prehash::{PreHashMap, PreHashSet};
 use massa_models::{
-    address::Address, block::Block, block_id::BlockId, endorsement::EndorsementId,
-    execution::EventFilter, slot::Slot, version::Version,
+    address::Address, amount::Amount, block::Block, block_header::SecuredHeader, block_id::BlockId,
+    endorsement::EndorsementId, execution::EventFilter, slot::Slot, stats::ConsensusStats,
+    version::Version,
 };
 use massa_pool_exports::{PoolBroadcasts, PoolController};
 use massa_pos_exports::SelectorController;
 use massa_protocol_exports::{ProtocolConfig, ProtocolController};
 use massa_storage::Storage;
+use massa_time::MassaTime;
 use massa_versioning::keypair_factory::KeyPairFactory;
 use massa_wallet::Wallet;
 use parking_lot::RwLock;
 use serde_json::Value;
+use std::collections::BTreeSet;
 use std::net::{IpAddr, SocketAddr};
-use std::sync::{Arc, Condvar, Mutex};
+use std::path::PathBuf;
+use std::sync::{atomic::AtomicUsize, Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+use tower_http::compression::predicate::{Predicate, SizeAbove};
+use tower_http::compression::CompressionLayer;
 use tower_http::cors::{Any, CorsLayer};
 use tracing::{info, warn};
 
+mod admission;
 mod api;
 mod api_trait;
 mod private;
 mod public;
+mod rate_limiter;
 
 #[cfg(feature = "testing")]
 use massa_channel as _;
@@ -86,10 +107,21 @@ pub struct Public {
     pub node_id: NodeId,
     /// keypair factory
     pub keypair_factory: KeyPairFactory,
+    /// node wallet, only used to list the staking addresses
+    pub node_wallet: Arc<RwLock<Wallet>>,
+    /// rate limits and in-flight request permits, shared with the API v2
+    pub(crate) admission: RequestAdmission,
+    /// last computed node status and when it was computed, see `status_cache_ttl`.
+    /// Async lock: it is held while the status is computed, which may wait between retries
+    pub(crate) status_cache: tokio::sync::RwLock<Option<(Instant, NodeStatus)>>,
+    /// number of active sessions of the bootstrap server, `None` if the node is not a bootstrap server
+    pub bootstrap_sessions: Option<Arc<AtomicUsize>>,
 }
 
 /// Private API content
 pub struct Private {
+    /// link to the consensus component
+    pub consensus_controller: Box<dyn ConsensusController>,
     /// link to the protocol component
     pub protocol_controller: Box<dyn ProtocolController>,
     /// link to the execution component
@@ -117,6 +149,8 @@ pub struct ApiV2 {
     pub api_settings: APIConfig,
     /// node version
     pub version: Version,
+    /// rate limits and in-flight request permits, shared with the public API
+    pub(crate) admission: RequestAdmission,
 }
 
 /// The API wrapper
@@ -187,18 +221,46 @@ async fn serve<T>(
 
     let allowed_hosts = HostFilterLayer::new(hosts).expect("failed to build allowed hosts filter");
 
+    // the layer is always there to keep the service type the same, but only compresses if enabled
+    let enable_compression = api_config.enable_response_compression;
+    let compression = CompressionLayer::new().compress_when(
+        SizeAbove::new(api_config.compression_min_response_size).and(
+            move |_: StatusCode, _: hyper::Version, _: &HeaderMap, _: &Extensions| {
+                enable_compression
+            },
+        ),
+    );
+
     let middleware = tower::ServiceBuilder::new()
         .layer(cors)
-        .layer(allowed_hosts);
-
-    let server = server_builder
-        .set_middleware(middleware)
-        .build(url)
+        .layer(allowed_hosts)
+        .layer(compression);
+
+    // the server runs on its own runtime, so that the requests still running when it is stopped can be aborted
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(api_config.worker_threads)
+        .thread_name("massa-api")
+        .enable_all()
+        .build()
+        .expect("failed to build API runtime");
+
+    let server_builder = server_builder.set_middleware(middleware);
+    let url = *url;
+    let server = runtime
+        .spawn(async move { server_builder.build(url).await })
         .await
+        .expect("failed to build server")
         .expect("failed to build server");
 
-    let server_handler = server.start(api);
-    let stop_handler = StopHandle { server_handler };
+    // the server and its connections are spawned on the runtime entered here
+    let server_handler = {
+        let _guard = runtime.enter();
+        server.start(api)
+    };
+    let stop_handler = StopHandle {
+        server_handler,
+        runtime: Some(runtime),
+    };
 
     Ok(stop_handler)
 }
@@ -206,6 +268,8 @@ async fn serve<T>(
 /// Used to be able to stop the API
 pub struct StopHandle {
     server_handler: ServerHandle,
+    /// runtime the server runs on, shut down along with the handle
+    runtime: Option<tokio::runtime::Runtime>,
 }
 
 impl StopHandle {
@@ -217,7 +281,37 @@ impl StopHandle {
             }
             Err(err) => warn!("API thread panicked: {:?}", err),
         }
-        self.server_handler.stopped().await;
+        self.server_handler.clone().stopped().await;
+    }
+
+    /// stop accepting new requests and wait up to `timeout` for the in-flight ones to complete.
+    /// Past the timeout, the server is aborted along with the requests still running
+    pub async fn stop_graceful(self, timeout: Duration) {
+        match self.server_handler.stop() {
+            Ok(_) => {
+                info!("API stop signal sent successfully");
+            }
+            Err(err) => warn!("API thread panicked: {:?}", err),
+        }
+        if tokio::time::timeout(timeout, self.server_handler.clone().stopped())
+            .await
+            .is_err()
+        {
+            warn!(
+                "API in-flight requests did not complete within {:?}, forcing shutdown",
+                timeout
+            );
+        }
+        // dropping the handle aborts whatever is still running on the server runtime
+    }
+}
+
+impl Drop for StopHandle {
+    fn drop(&mut self) {
+        // a runtime cannot be dropped from an async context: its tasks are dropped without waiting for them
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
     }
 }
 
@@ -239,6 +333,7 @@ pub trait MassaRpc {
     async fn add_staking_secret_keys(&self, arg: Vec<String>) -> RpcResult<()>;
 
     /// Execute bytecode in read-only mode.
+    /// Requests without a caller address run as the configured `read_only_default_caller`.
     #[method(name = "execute_read_only_bytecode")]
     async fn execute_read_only_bytecode(
         &self,
@@ -246,12 +341,35 @@ pub trait MassaRpc {
     ) -> RpcResult<Vec<ExecuteReadOnlyResponse>>;
 
     /// Execute an SC function in read-only mode.
+    /// Requests without a caller address run as the configured `read_only_default_caller`.
     #[method(name = "execute_read_only_call")]
     async fn execute_read_only_call(
         &self,
         arg: Vec<ReadOnlyCall>,
     ) -> RpcResult<Vec<ExecuteReadOnlyResponse>>;
 
+    /// Execute SC functions in read-only mode with a shared caller, gas and fee.
+    /// The responses are in the order of the calls, which count as arguments of `execute_read_only_call`.
+    #[method(name = "execute_read_only_bulk_call")]
+    async fn execute_read_only_bulk_call(
+        &self,
+        arg: ReadOnlyBulkCall,
+    ) -> RpcResult<Vec<ExecuteReadOnlyResponse>>;
+
+    /// Execute bytecode in read-only mode.
+    /// Each response is serialized with the node binary serializers and base64-encoded.
+    #[method(name = "execute_read_only_bytecode_binary")]
+    async fn execute_read_only_bytecode_binary(
+        &self,
+        arg: Vec<ReadOnlyBytecodeExecution>,
+    ) -> RpcResult<Vec<String>>;
+
+    /// Execute an SC function in read-only mode.
+    /// Each response is serialized with the node binary serializers and base64-encoded.
+    #[method(name = "execute_read_only_call_binary")]
+    async fn execute_read_only_call_binary(&self, arg: Vec<ReadOnlyCall>)
+        -> RpcResult<Vec<String>>;
+
     /// Remove a vector of addresses used to stake.
     /// No confirmation to expect.
     #[method(name = "remove_staking_addresses")]
@@ -261,6 +379,20 @@ pub trait MassaRpc {
     #[method(name = "get_staking_addresses")]
     async fn get_staking_addresses(&self) -> RpcResult<PreHashSet<Address>>;
 
+    /// Return hash set of staking addresses, without any key.
+    /// Only available if the node is configured to expose them.
+    #[method(name = "get_active_staking_addresses")]
+    async fn get_active_staking_addresses(&self) -> RpcResult<PreHashSet<Address>>;
+
+    /// Export the block graph between two optional slots (start included, end excluded) in Graphviz DOT format,
+    /// nodes colored by finality and clique membership. Heavy on large graphs.
+    #[method(name = "export_block_graph_dot")]
+    async fn export_block_graph_dot(
+        &self,
+        start_slot: Option<Slot>,
+        end_slot: Option<Slot>,
+    ) -> RpcResult<String>;
+
     /// Bans given IP address(es).
     /// No confirmation to expect.
     #[method(name = "node_ban_by_ip")]
@@ -326,14 +458,89 @@ pub trait MassaRpc {
     #[method(name = "node_unban_by_id")]
     async fn node_unban_by_id(&self, arg: Vec<NodeId>) -> RpcResult<()>;
 
+    /// Returns the peers currently banned, with the time and reason of their ban.
+    #[method(name = "get_banned_peers")]
+    async fn get_banned_peers(&self) -> RpcResult<Vec<BannedPeerInfo>>;
+
     /// Summary of the current state: time, last final blocks (hash, thread, slot, timestamp), clique count, connected nodes count.
     #[method(name = "get_status")]
     async fn get_status(&self) -> RpcResult<NodeStatus>;
 
+    /// Cheap health check for liveness and readiness probes.
+    /// Only compares the execution cursor to the wall-clock slot, without querying the other components.
+    #[method(name = "get_health")]
+    async fn get_health(&self) -> RpcResult<NodeHealth>;
+
+    /// Node metrics in the Prometheus text exposition format: connected peers, pool sizes,
+    /// final and active execution slots, and the number of requests received by each API method.
+    #[method(name = "get_metrics_text")]
+    async fn get_metrics_text(&self) -> RpcResult<String>;
+
+    /// Returns the minimal operation fee and the gas limits applied by the node.
+    #[method(name = "get_fee_parameters")]
+    async fn get_fee_parameters(&self) -> RpcResult<FeeParameters>;
+
+    /// Returns the minimal amount of coins that creates a new ledger entry when sent to an address without one.
+    /// Sending less to such an address fails at execution.
+    #[method(name = "get_minimal_entry_balance")]
+    async fn get_minimal_entry_balance(&self) -> RpcResult<Amount>;
+
+    /// Returns the node version along with the network version it currently uses
+    /// and the one it announces in the headers of the blocks it produces.
+    #[method(name = "get_version_info")]
+    async fn get_version_info(&self) -> RpcResult<VersionInfo>;
+
+    /// Returns the number of addresses, the number of datastore entries and the approximate
+    /// byte size of the final ledger.
+    #[method(name = "get_ledger_stats")]
+    async fn get_ledger_stats(&self) -> RpcResult<LedgerStats>;
+
+    /// Returns the consensus stats snapshots taken every stats timespan over the last `duration` milliseconds,
+    /// oldest first, followed by the stats of the current timespan.
+    /// The number of past snapshots is capped by the maximum number of arguments per request.
+    #[method(name = "get_consensus_stats_history")]
+    async fn get_consensus_stats_history(
+        &self,
+        duration: MassaTime,
+    ) -> RpcResult<Vec<ConsensusStats>>;
+
+    /// Returns the final and active slots processed so far by the execution module.
+    /// They can lag behind the wall-clock slot while the node is catching up.
+    #[method(name = "get_execution_slots")]
+    async fn get_execution_slots(&self) -> RpcResult<ExecutionSlots>;
+
+    /// Returns, for each thread, the last final and active slots processed by the execution module.
+    /// Reads of the final or candidate state reflect execution up to these slots.
+    #[method(name = "get_execution_status")]
+    async fn get_execution_status(&self) -> RpcResult<Vec<ThreadExecutionSlots>>;
+
+    /// Get the timestamp (in milliseconds) at which a slot starts.
+    #[method(name = "get_slot_timestamp")]
+    async fn get_slot_timestamp(&self, arg: Slot) -> RpcResult<MassaTime>;
+
+    /// Get the latest slot started at a timestamp (in milliseconds).
+    #[method(name = "get_timestamp_slot")]
+    async fn get_timestamp_slot(&self, arg: MassaTime) -> RpcResult<Slot>;
+
     /// Get cliques.
     #[method(name = "get_cliques")]
     async fn get_cliques(&self) -> RpcResult<Vec<Clique>>;
 
+    /// Get the active blocks competing at a slot with their fitness, the maximal cliques each one
+    /// belongs to and whether it is the block chosen by the blockclique.
+    #[method(name = "get_slot_fork_choice")]
+    async fn get_slot_fork_choice(&self, slot: Slot) -> RpcResult<Vec<SlotForkChoiceBlock>>;
+
+    /// Get the maximal cliques together with the summaries of their blocks between `start_slot` (included)
+    /// and `end_slot` (excluded), both taken from the same graph extract.
+    /// The range is bounded like in `get_graph_interval`.
+    #[method(name = "get_graph_cliques_detailed")]
+    async fn get_graph_cliques_detailed(
+        &self,
+        start_slot: Option<Slot>,
+        end_slot: Option<Slot>,
+    ) -> RpcResult<Vec<DetailedClique>>;
+
     /// Returns the active stakers and their active roll counts for the current cycle.
     #[method(name = "get_stakers")]
     async fn get_stakers(
@@ -341,9 +548,87 @@ pub trait MassaRpc {
         page_request: Option<PageRequest>,
     ) -> RpcResult<PagedVec<(Address, u64)>>;
 
+    /// Returns the total number of active rolls across all stakers for a cycle, the current one by default.
+    #[method(name = "get_total_active_rolls")]
+    async fn get_total_active_rolls(&self, cycle: Option<u64>) -> RpcResult<u64>;
+
+    /// Returns the active roll count of an address at each of the requested cycles, as `(cycle, rolls)` pairs in order.
+    /// Every cycle must be within the retained cycle history.
+    #[method(name = "get_multi_cycle_rolls")]
+    async fn get_multi_cycle_rolls(
+        &self,
+        address: Address,
+        cycles: Vec<u64>,
+    ) -> RpcResult<Vec<(u64, u64)>>;
+
+    /// Returns the seed bytes the draws of a cycle were performed with,
+    /// allowing to verify `get_stakers` and the address selections independently.
+    #[method(name = "get_cycle_draw_seed")]
+    async fn get_cycle_draw_seed(&self, cycle: u64) -> RpcResult<Vec<u8>>;
+
+    /// Returns the addresses drawn to endorse the block of a slot, with their endorsement index, in order.
+    /// Fails if the draws of the cycle of the slot are not available in the selector.
+    #[method(name = "get_endorsement_draws")]
+    async fn get_endorsement_draws(&self, slot: Slot) -> RpcResult<Vec<(u64, Address)>>;
+
+    /// Returns the address drawn to produce the block of a slot.
+    /// Fails if the draws of the cycle of the slot are not available in the selector.
+    #[method(name = "get_block_producer")]
+    async fn get_block_producer(&self, slot: Slot) -> RpcResult<Address>;
+
     /// Returns operation(s) information associated to a given list of operation(s) ID(s).
+    /// If `only_final` is set, the pool is not queried and only operations executed in final slots are returned.
     #[method(name = "get_operations")]
-    async fn get_operations(&self, arg: Vec<OperationId>) -> RpcResult<Vec<OperationInfo>>;
+    async fn get_operations(
+        &self,
+        arg: Vec<OperationId>,
+        only_final: Option<bool>,
+    ) -> RpcResult<Vec<OperationInfo>>;
+
+    /// Returns the information of a single operation, or `None` if the node does not know it.
+    #[method(name = "get_operation")]
+    async fn get_operation(&self, operation_id: OperationId) -> RpcResult<Option<OperationInfo>>;
+
+    /// Returns up to `limit` IDs of operations whose string representation starts with `prefix`.
+    /// Only the operations retained by the node (pool and recent blocks) are searched, not the whole history.
+    /// The IDs are sorted by their string representation: use `get_operations` on them for details.
+    #[method(name = "find_operations_by_prefix")]
+    async fn find_operations_by_prefix(
+        &self,
+        prefix: String,
+        limit: u64,
+    ) -> RpcResult<Vec<OperationId>>;
+
+    /// Returns, for each given operation, the blocks (candidate or final) that include it and whether each block is final.
+    #[method(name = "get_operation_inclusions")]
+    async fn get_operation_inclusions(
+        &self,
+        arg: Vec<OperationId>,
+    ) -> RpcResult<PreHashMap<OperationId, Vec<(BlockId, bool)>>>;
+
+    /// Returns, for each given operation, whether it is currently in the pool.
+    /// Lightweight check for wallets right after `send_operations`.
+    #[method(name = "is_operation_in_pool")]
+    async fn is_operation_in_pool(&self, arg: Vec<OperationId>) -> RpcResult<Vec<bool>>;
+
+    /// Returns the ids of at most `limit` operations currently in the pool, by decreasing priority.
+    /// Only available if the node is configured to expose them.
+    #[method(name = "get_pool_operation_ids")]
+    async fn get_pool_operation_ids(&self, limit: u64) -> RpcResult<Vec<OperationId>>;
+
+    /// Returns the denunciations of the pool that are not executed yet, by increasing slot.
+    /// At most `max_arguments` denunciations are returned.
+    #[method(name = "get_pending_denunciations")]
+    async fn get_pending_denunciations(&self) -> RpcResult<Vec<PendingDenunciation>>;
+
+    /// Returns the proof that an operation is included in a block.
+    /// If the block is unknown or does not contain the operation a `None` is returned.
+    #[method(name = "get_operation_proof")]
+    async fn get_operation_proof(
+        &self,
+        block_id: BlockId,
+        operation_id: OperationId,
+    ) -> RpcResult<Option<OperationInclusionProof>>;
 
     /// Returns endorsement(s) information associated to a given list of endorsement(s) ID(s)
     #[method(name = "get_endorsements")]
@@ -353,17 +638,52 @@ pub trait MassaRpc {
     #[method(name = "get_blocks")]
     async fn get_blocks(&self, arg: Vec<BlockId>) -> RpcResult<Vec<BlockInfo>>;
 
+    /// Get the signed header of a block, without its operations. `None` if the block is unknown.
+    #[method(name = "get_block_header")]
+    async fn get_block_header(&self, arg: BlockId) -> RpcResult<Option<SecuredHeader>>;
+
     /// Get information on the block at a slot in the blockclique.
     /// If there is no block at this slot a `None` is returned.
     #[method(name = "get_blockclique_block_by_slot")]
     async fn get_blockclique_block_by_slot(&self, arg: Slot) -> RpcResult<Option<Block>>;
 
+    /// Get the block produced at a given slot in the blockclique, `None` if the slot was missed.
+    #[method(name = "get_block_at_slot")]
+    async fn get_block_at_slot(&self, slot: Slot) -> RpcResult<Option<BlockInfo>>;
+
+    /// Get both the final block and the blockclique (candidate) block at a given slot.
+    /// The candidate block can still be reorged out as long as there is no final block at the slot.
+    #[method(name = "get_blocks_at_slot")]
+    async fn get_blocks_at_slot(&self, slot: Slot) -> RpcResult<SlotBlocks>;
+
     /// Get the block graph within the specified time interval.
-    /// Optional parameters: from `<time_start>` (included) and to `<time_end>` (excluded) millisecond timestamp
+    /// Optional parameters: from `<time_start>` (included) and to `<time_end>` (excluded) millisecond timestamp.
+    /// The interval covers at most `max_graph_interval_slots` slots, an open start is clamped to this span.
+    /// If `only_blockclique` is set, only the final blocks and the blocks of the blockclique are returned,
+    /// without the stale ones.
     #[method(name = "get_graph_interval")]
-    async fn get_graph_interval(&self, arg: TimeInterval) -> RpcResult<Vec<BlockSummary>>;
+    async fn get_graph_interval(
+        &self,
+        arg: TimeInterval,
+        only_blockclique: Option<bool>,
+    ) -> RpcResult<Vec<BlockSummary>>;
+
+    /// Get the most recent stale blocks, most recent first.
+    /// At most `limit` blocks are returned, capped by the maximum number of arguments per request.
+    #[method(name = "get_recent_stale_blocks")]
+    async fn get_recent_stale_blocks(&self, limit: u64) -> RpcResult<Vec<BlockSummary>>;
+
+    /// Get the latest `count` blocks of the blockclique, final blocks included, oldest first,
+    /// with the timestamp of their slot and the time at which the node received them, if still known.
+    /// Used to monitor the intervals between produced blocks and their propagation delays.
+    /// `count` is capped by the maximum number of arguments per request.
+    #[method(name = "get_recent_block_intervals")]
+    async fn get_recent_block_intervals(&self, count: u64) -> RpcResult<Vec<BlockTimestamp>>;
 
     /// Get multiple datastore entries.
+    /// An empty key is not looked up as such: it stands for all the entries of the address, final or candidate,
+    /// each one returned with its key. An entry stored under the empty key is only returned among them.
+    /// The total number of entries, after expansion, is capped by the maximum number of arguments per request.
     #[method(name = "get_datastore_entries")]
     async fn get_datastore_entries(
         &self,
@@ -371,16 +691,100 @@ pub trait MassaRpc {
     ) -> RpcResult<Vec<DatastoreEntryOutput>>;
 
     /// Get addresses.
+    /// The optional label tags the node logs of the request and is echoed in each result.
+    /// In best-effort mode (disabled by default), the sub-queries that fail are listed in the `unavailable`
+    /// field of each result and their fields are left empty (balances and roll counts are null),
+    /// instead of failing the whole request.
     #[method(name = "get_addresses")]
-    async fn get_addresses(&self, arg: Vec<Address>) -> RpcResult<Vec<AddressInfo>>;
+    async fn get_addresses(
+        &self,
+        arg: Vec<Address>,
+        label: Option<String>,
+        best_effort: Option<bool>,
+    ) -> RpcResult<Vec<AddressInfo>>;
+
+    /// Get the soonest slot at which the address is drawn to produce a block,
+    /// or null if it is not drawn within the draw lookahead.
+    #[method(name = "get_next_block_draw")]
+    async fn get_next_block_draw(&self, arg: Address) -> RpcResult<Option<Slot>>;
+
+    /// Get the thread of each address, in order.
+    #[method(name = "get_address_threads")]
+    async fn get_address_threads(&self, arg: Vec<Address>) -> RpcResult<Vec<u8>>;
+
+    /// Parse an address string, returning its thread and category.
+    /// Invalid addresses are reported as a bad request error.
+    #[method(name = "parse_address")]
+    async fn parse_address(&self, arg: String) -> RpcResult<ParsedAddress>;
 
     /// Get addresses bytecode.
     #[method(name = "get_addresses_bytecode")]
     async fn get_addresses_bytecode(&self, args: Vec<AddressFilter>) -> RpcResult<Vec<Vec<u8>>>;
 
-    /// Adds operations to pool. Returns operations that were ok and sent to pool.
+    /// Get the hashes of the final and candidate bytecode of addresses, in order.
+    /// Addresses without bytecode get `None` hashes.
+    #[method(name = "get_bytecode_hash")]
+    async fn get_bytecode_hash(&self, args: Vec<Address>) -> RpcResult<Vec<AddressBytecodeHash>>;
+
+    /// Get the final and candidate datastore keys of addresses, in order, without the rest of the address info.
+    /// Unknown addresses get empty key sets.
+    #[method(name = "get_datastore_keys")]
+    async fn get_datastore_keys(
+        &self,
+        args: Vec<Address>,
+    ) -> RpcResult<Vec<(Address, BTreeSet<Vec<u8>>, BTreeSet<Vec<u8>>)>>;
+
+    /// Get the deferred credits of addresses, i.e. the coins locked until a given slot
+    /// (for instance after a roll sale).
+    #[method(name = "get_deferred_credits")]
+    async fn get_deferred_credits(
+        &self,
+        args: Vec<AddressFilter>,
+    ) -> RpcResult<Vec<Vec<SlotAmount>>>;
+
+    /// Get the balance of an address as of the end of a slot.
+    /// Only the slots between the last final slot and the last executed slot are retained.
+    #[method(name = "get_balance_at_slot")]
+    async fn get_balance_at_slot(&self, address: Address, slot: Slot) -> RpcResult<Amount>;
+
+    /// Adds operations to pool. Returns the ids of the operations that were ok and sent to pool,
+    /// each with a flag telling whether the pool accepted it.
     #[method(name = "send_operations")]
-    async fn send_operations(&self, arg: Vec<OperationInput>) -> RpcResult<Vec<OperationId>>;
+    async fn send_operations(
+        &self,
+        arg: Vec<OperationInput>,
+    ) -> RpcResult<Vec<(OperationId, bool)>>;
+
+    /// Runs the checks of `send_operations` (deserialization, signature, gas limit and expiry)
+    /// without submitting the operations to the pool. Returns the validity of each operation, in order.
+    #[method(name = "validate_operations")]
+    async fn validate_operations(
+        &self,
+        arg: Vec<OperationInput>,
+    ) -> RpcResult<Vec<OperationValidity>>;
+
+    /// Returns the id `send_operations` would assign to an operation, after running the same checks,
+    /// without submitting the operation to the pool.
+    #[method(name = "compute_operation_id")]
+    async fn compute_operation_id(&self, arg: OperationInput) -> RpcResult<OperationId>;
+
+    /// Runs an `ExecuteSC` or `CallSC` operation speculatively on top of the candidate state and
+    /// returns the balances and datastore entries it would change, within the read-only gas limit.
+    /// A transaction is applied to the candidate balances of its sender (minus amount and fee) and recipient.
+    /// The operation is neither sent to the pool nor propagated, and no state change is persisted.
+    /// Roll operations cannot be simulated: they are rejected with a bad request error.
+    #[method(name = "simulate_operation")]
+    async fn simulate_operation(&self, arg: OperationInput) -> RpcResult<OperationSimulation>;
+
+    /// Decodes a serialized operation (signature, creator public key, then content, as built by
+    /// `send_operations`) and returns its structure along with the bytes left after it.
+    /// The operation is not submitted and its signature is only verified if `verify_signature` is true.
+    #[method(name = "decode_operation")]
+    async fn decode_operation(
+        &self,
+        bytes: Vec<u8>,
+        verify_signature: Option<bool>,
+    ) -> RpcResult<DecodedOperation>;
 
     /// Get events optionally filtered by:
     /// * start slot
@@ -392,6 +796,34 @@ pub trait MassaRpc {
     async fn get_filtered_sc_output_event(&self, arg: EventFilter)
         -> RpcResult<Vec<SCOutputEvent>>;
 
+    /// Get the events matching the filter, like `get_filtered_sc_output_event`,
+    /// grouped by the operation that originated them, in emission order within each operation.
+    /// Events not originated by an operation are left out.
+    #[method(name = "get_filtered_sc_output_event_by_operation")]
+    async fn get_filtered_sc_output_event_by_operation(
+        &self,
+        arg: EventFilter,
+    ) -> RpcResult<PreHashMap<OperationId, Vec<SCOutputEvent>>>;
+
+    /// Write the events matching the filter to a file of the node, as newline-delimited JSON.
+    /// The file is created, or truncated if it exists.
+    /// The events are read and written page by page, so they are never all held in memory.
+    /// Returns the number of exported events.
+    #[method(name = "export_sc_output_events")]
+    async fn export_sc_output_events(&self, filter: EventFilter, path: PathBuf) -> RpcResult<u64>;
+
+    /// Returns up to `limit` addresses of the final ledger having a datastore key starting with `prefix`, in address order.
+    /// The scan starts after the address `after` and visits at most `max_key_prefix_scan` addresses:
+    /// call again with the returned `next` address until it is `None` to scan the whole ledger.
+    /// This scan is heavy, which is why it is only available on the private API.
+    #[method(name = "find_addresses_with_key_prefix")]
+    async fn find_addresses_with_key_prefix(
+        &self,
+        prefix: Vec<u8>,
+        after: Option<Address>,
+        limit: u64,
+    ) -> RpcResult<KeyPrefixAddresses>;
+
     /// Get OpenRPC specification.
     #[method(name = "rpc.discover")]
     async fn get_openrpc_spec(&self) -> RpcResult<Value>;
@@ -400,3 +832,23 @@ pub trait MassaRpc {
 fn wrong_api<T>() -> RpcResult<T> {
     Err((WrongAPI).into())
 }
+
+/// check the cost parameters of a read-only request before handing it to execution:
+/// the gas cannot exceed the read-only gas limit and the spent amounts cannot overflow
+fn check_read_only_costs(
+    api_settings: &APIConfig,
+    max_gas: u64,
+    fee: Option<Amount>,
+    coins: Option<Amount>,
+) -> Result<(), ApiError> {
+    if max_gas > api_settings.max_read_only_gas {
+        return Err(ApiError::BadRequest(format!(
+            "max_gas {} is higher than the read-only gas limit {}",
+            max_gas, api_settings.max_read_only_gas
+        )));
+    }
+    fee.unwrap_or_default()
+        .checked_add(coins.unwrap_or_default())
+        .ok_or_else(|| ApiError::BadRequest("fee and coins overflow".to_string()))?;
+    Ok(())
+}
@@ -5,42 +5,71 @@ use crate::{MassaRpcServer, Private, RpcServer, StopHandle, Value, API};
 use async_trait::async_trait;
 use jsonrpsee::core::{Error as JsonRpseeError, RpcResult};
 use massa_api_exports::{
-    address::{AddressFilter, AddressInfo},
-    block::{BlockInfo, BlockSummary},
+    address::{AddressBytecodeHash, AddressFilter, AddressInfo, KeyPrefixAddresses, ParsedAddress},
+    block::{
+        BlockInfo, BlockSummary, BlockTimestamp, DetailedClique, SlotBlocks, SlotForkChoiceBlock,
+    },
     config::APIConfig,
     datastore::{DatastoreEntryInput, DatastoreEntryOutput},
+    denunciation::PendingDenunciation,
     endorsement::EndorsementInfo,
     error::ApiError,
-    execution::{ExecuteReadOnlyResponse, ReadOnlyBytecodeExecution, ReadOnlyCall},
-    node::NodeStatus,
-    operation::{OperationInfo, OperationInput},
+    execution::{
+        ExecuteReadOnlyResponse, ReadOnlyBulkCall, ReadOnlyBytecodeExecution, ReadOnlyCall,
+    },
+    node::{BannedPeerInfo, FeeParameters, NodeHealth, NodeStatus, VersionInfo},
+    operation::{
+        DecodedOperation, OperationInclusionProof, OperationInfo, OperationInput,
+        OperationSimulation, OperationValidity,
+    },
     page::{PageRequest, PagedVec},
+    slot::{ExecutionSlots, SlotAmount, ThreadExecutionSlots},
     ListType, ScrudOperation, TimeInterval,
 };
+use massa_consensus_exports::ConsensusController;
 use massa_execution_exports::ExecutionController;
 use massa_hash::Hash;
+use massa_ledger_exports::LedgerStats;
 use massa_models::{
-    address::Address, block::Block, block_id::BlockId, clique::Clique, composite::PubkeySig,
-    endorsement::EndorsementId, execution::EventFilter, node::NodeId, operation::OperationId,
-    output_event::SCOutputEvent, prehash::PreHashSet, slot::Slot,
+    address::Address,
+    amount::Amount,
+    block::Block,
+    block_header::SecuredHeader,
+    block_id::BlockId,
+    clique::Clique,
+    composite::PubkeySig,
+    endorsement::EndorsementId,
+    execution::EventFilter,
+    node::NodeId,
+    operation::OperationId,
+    output_event::SCOutputEvent,
+    prehash::{PreHashMap, PreHashSet},
+    slot::Slot,
+    stats::ConsensusStats,
 };
 use massa_protocol_exports::{PeerId, ProtocolController};
 use massa_signature::KeyPair;
+use massa_time::MassaTime;
 use massa_wallet::Wallet;
 use parking_lot::RwLock;
 use std::net::{IpAddr, SocketAddr};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::{collections::BTreeSet, sync::Mutex};
 use std::{
     fs::{remove_file, OpenOptions},
+    io::{BufWriter, Write},
     sync::Condvar,
 };
 
+/// Number of events read at once from execution by `export_sc_output_events`
+const EVENTS_EXPORT_PAGE_SIZE: usize = 1000;
+
 impl API<Private> {
     /// generate a new private API
     pub fn new(
+        consensus_controller: Box<dyn ConsensusController>,
         protocol_controller: Box<dyn ProtocolController>,
         execution_controller: Box<dyn ExecutionController>,
         api_settings: APIConfig,
@@ -48,6 +77,7 @@ impl API<Private> {
         node_wallet: Arc<RwLock<Wallet>>,
     ) -> Self {
         API(Private {
+            consensus_controller,
             protocol_controller,
             execution_controller,
             api_settings,
@@ -125,6 +155,27 @@ impl MassaRpcServer for API<Private> {
         crate::wrong_api::<_>()
     }
 
+    async fn execute_read_only_bulk_call(
+        &self,
+        _req: ReadOnlyBulkCall,
+    ) -> RpcResult<Vec<ExecuteReadOnlyResponse>> {
+        crate::wrong_api::<_>()
+    }
+
+    async fn execute_read_only_bytecode_binary(
+        &self,
+        _reqs: Vec<ReadOnlyBytecodeExecution>,
+    ) -> RpcResult<Vec<String>> {
+        crate::wrong_api::<_>()
+    }
+
+    async fn execute_read_only_call_binary(
+        &self,
+        _reqs: Vec<ReadOnlyCall>,
+    ) -> RpcResult<Vec<String>> {
+        crate::wrong_api::<_>()
+    }
+
     async fn remove_staking_addresses(&self, addresses: Vec<Address>) -> RpcResult<()> {
         let node_wallet = self.0.node_wallet.clone();
 
@@ -147,6 +198,27 @@ impl MassaRpcServer for API<Private> {
         Ok(w_wallet.get_wallet_address_list())
     }
 
+    async fn get_active_staking_addresses(&self) -> RpcResult<PreHashSet<Address>> {
+        crate::wrong_api::<PreHashSet<Address>>()
+    }
+
+    async fn export_block_graph_dot(
+        &self,
+        start_slot: Option<Slot>,
+        end_slot: Option<Slot>,
+    ) -> RpcResult<String> {
+        // the export and its rendering can be heavy: keep them off the RPC worker threads
+        let consensus_controller = self.0.consensus_controller.clone();
+        tokio::task::spawn_blocking(move || {
+            consensus_controller
+                .get_block_graph_status(start_slot, end_slot)
+                .map(|graph| graph.to_dot())
+        })
+        .await
+        .map_err(|e| ApiError::InternalServerError(e.to_string()))?
+        .map_err(|e| ApiError::ConsensusError(e.to_string()).into())
+    }
+
     async fn node_ban_by_ip(&self, _ips: Vec<IpAddr>) -> RpcResult<()> {
         //TODO: Reinvoke
         // let network_command_sender = self.0.network_command_sender.clone();
@@ -183,6 +255,24 @@ impl MassaRpcServer for API<Private> {
             .map_err(|e| ApiError::ProtocolError(e.to_string()).into())
     }
 
+    async fn get_banned_peers(&self) -> RpcResult<Vec<BannedPeerInfo>> {
+        let banned_peers = self
+            .0
+            .protocol_controller
+            .get_banned_peers()
+            .map_err(|e| ApiError::ProtocolError(e.to_string()))?;
+        Ok(banned_peers
+            .into_iter()
+            .map(|peer| BannedPeerInfo {
+                //TODO: Change when unify node id and peer id
+                node_id: NodeId::new(peer.peer_id.get_public_key()),
+                ips: peer.ips,
+                banned_at: peer.banned_at,
+                reason: peer.reason,
+            })
+            .collect())
+    }
+
     async fn node_unban_by_ip(&self, _ips: Vec<IpAddr>) -> RpcResult<()> {
         //TODO: Reinvoke
         // let network_command_sender = self.0.network_command_sender.clone();
@@ -199,34 +289,173 @@ impl MassaRpcServer for API<Private> {
         crate::wrong_api::<NodeStatus>()
     }
 
+    async fn get_health(&self) -> RpcResult<NodeHealth> {
+        crate::wrong_api::<NodeHealth>()
+    }
+
+    async fn get_metrics_text(&self) -> RpcResult<String> {
+        crate::wrong_api::<String>()
+    }
+
+    async fn get_fee_parameters(&self) -> RpcResult<FeeParameters> {
+        crate::wrong_api::<FeeParameters>()
+    }
+
+    async fn get_minimal_entry_balance(&self) -> RpcResult<Amount> {
+        crate::wrong_api::<Amount>()
+    }
+
+    async fn get_version_info(&self) -> RpcResult<VersionInfo> {
+        crate::wrong_api::<VersionInfo>()
+    }
+
+    async fn get_ledger_stats(&self) -> RpcResult<LedgerStats> {
+        crate::wrong_api::<LedgerStats>()
+    }
+
+    async fn get_consensus_stats_history(&self, _: MassaTime) -> RpcResult<Vec<ConsensusStats>> {
+        crate::wrong_api::<Vec<ConsensusStats>>()
+    }
+
+    async fn get_execution_slots(&self) -> RpcResult<ExecutionSlots> {
+        crate::wrong_api::<ExecutionSlots>()
+    }
+
+    async fn get_execution_status(&self) -> RpcResult<Vec<ThreadExecutionSlots>> {
+        crate::wrong_api::<Vec<ThreadExecutionSlots>>()
+    }
+
+    async fn get_slot_timestamp(&self, _: Slot) -> RpcResult<MassaTime> {
+        crate::wrong_api::<MassaTime>()
+    }
+
+    async fn get_timestamp_slot(&self, _: MassaTime) -> RpcResult<Slot> {
+        crate::wrong_api::<Slot>()
+    }
+
     async fn get_cliques(&self) -> RpcResult<Vec<Clique>> {
         crate::wrong_api::<Vec<Clique>>()
     }
 
+    async fn get_slot_fork_choice(&self, _: Slot) -> RpcResult<Vec<SlotForkChoiceBlock>> {
+        crate::wrong_api::<Vec<SlotForkChoiceBlock>>()
+    }
+
+    async fn get_graph_cliques_detailed(
+        &self,
+        _: Option<Slot>,
+        _: Option<Slot>,
+    ) -> RpcResult<Vec<DetailedClique>> {
+        crate::wrong_api::<Vec<DetailedClique>>()
+    }
+
     async fn get_stakers(&self, _: Option<PageRequest>) -> RpcResult<PagedVec<(Address, u64)>> {
         crate::wrong_api::<PagedVec<(Address, u64)>>()
     }
 
-    async fn get_operations(&self, _: Vec<OperationId>) -> RpcResult<Vec<OperationInfo>> {
+    async fn get_total_active_rolls(&self, _: Option<u64>) -> RpcResult<u64> {
+        crate::wrong_api::<u64>()
+    }
+
+    async fn get_multi_cycle_rolls(&self, _: Address, _: Vec<u64>) -> RpcResult<Vec<(u64, u64)>> {
+        crate::wrong_api::<Vec<(u64, u64)>>()
+    }
+
+    async fn get_cycle_draw_seed(&self, _: u64) -> RpcResult<Vec<u8>> {
+        crate::wrong_api::<Vec<u8>>()
+    }
+
+    async fn get_endorsement_draws(&self, _: Slot) -> RpcResult<Vec<(u64, Address)>> {
+        crate::wrong_api::<Vec<(u64, Address)>>()
+    }
+
+    async fn get_block_producer(&self, _: Slot) -> RpcResult<Address> {
+        crate::wrong_api::<Address>()
+    }
+
+    async fn get_operations(
+        &self,
+        _: Vec<OperationId>,
+        _: Option<bool>,
+    ) -> RpcResult<Vec<OperationInfo>> {
         crate::wrong_api::<Vec<OperationInfo>>()
     }
 
+    async fn get_operation(&self, _: OperationId) -> RpcResult<Option<OperationInfo>> {
+        crate::wrong_api::<Option<OperationInfo>>()
+    }
+
+    async fn find_operations_by_prefix(&self, _: String, _: u64) -> RpcResult<Vec<OperationId>> {
+        crate::wrong_api::<Vec<OperationId>>()
+    }
+
+    async fn get_operation_inclusions(
+        &self,
+        _: Vec<OperationId>,
+    ) -> RpcResult<PreHashMap<OperationId, Vec<(BlockId, bool)>>> {
+        crate::wrong_api::<PreHashMap<OperationId, Vec<(BlockId, bool)>>>()
+    }
+
+    async fn is_operation_in_pool(&self, _: Vec<OperationId>) -> RpcResult<Vec<bool>> {
+        crate::wrong_api::<Vec<bool>>()
+    }
+
+    async fn get_pool_operation_ids(&self, _: u64) -> RpcResult<Vec<OperationId>> {
+        crate::wrong_api::<Vec<OperationId>>()
+    }
+
+    async fn get_pending_denunciations(&self) -> RpcResult<Vec<PendingDenunciation>> {
+        crate::wrong_api::<Vec<PendingDenunciation>>()
+    }
+
+    async fn get_operation_proof(
+        &self,
+        _: BlockId,
+        _: OperationId,
+    ) -> RpcResult<Option<OperationInclusionProof>> {
+        crate::wrong_api::<Option<OperationInclusionProof>>()
+    }
+
     async fn get_endorsements(&self, _: Vec<EndorsementId>) -> RpcResult<Vec<EndorsementInfo>> {
         crate::wrong_api::<Vec<EndorsementInfo>>()
     }
 
+    async fn get_block_at_slot(&self, _: Slot) -> RpcResult<Option<BlockInfo>> {
+        crate::wrong_api::<Option<BlockInfo>>()
+    }
+
+    async fn get_blocks_at_slot(&self, _: Slot) -> RpcResult<SlotBlocks> {
+        crate::wrong_api::<SlotBlocks>()
+    }
+
     async fn get_blocks(&self, _: Vec<BlockId>) -> RpcResult<Vec<BlockInfo>> {
         crate::wrong_api::<Vec<BlockInfo>>()
     }
 
+    async fn get_block_header(&self, _: BlockId) -> RpcResult<Option<SecuredHeader>> {
+        crate::wrong_api::<Option<SecuredHeader>>()
+    }
+
     async fn get_blockclique_block_by_slot(&self, _: Slot) -> RpcResult<Option<Block>> {
         crate::wrong_api::<Option<Block>>()
     }
 
-    async fn get_graph_interval(&self, _: TimeInterval) -> RpcResult<Vec<BlockSummary>> {
+    async fn get_graph_interval(
+        &self,
+        _: TimeInterval,
+        _: Option<bool>,
+    ) -> RpcResult<Vec<BlockSummary>> {
         crate::wrong_api::<Vec<BlockSummary>>()
     }
 
+    async fn get_recent_stale_blocks(&self, _: u64) -> RpcResult<Vec<BlockSummary>> {
+        crate::wrong_api::<Vec<BlockSummary>>()
+    }
+
+    async fn get_recent_block_intervals(&self, _: u64) -> RpcResult<Vec<BlockTimestamp>> {
+        crate::wrong_api::<Vec<BlockTimestamp>>()
+    }
+
     async fn get_datastore_entries(
         &self,
         _: Vec<DatastoreEntryInput>,
@@ -234,22 +463,124 @@ impl MassaRpcServer for API<Private> {
         crate::wrong_api()
     }
 
-    async fn get_addresses(&self, _: Vec<Address>) -> RpcResult<Vec<AddressInfo>> {
+    async fn get_addresses(
+        &self,
+        _: Vec<Address>,
+        _: Option<String>,
+        _: Option<bool>,
+    ) -> RpcResult<Vec<AddressInfo>> {
         crate::wrong_api::<Vec<AddressInfo>>()
     }
 
+    async fn get_next_block_draw(&self, _: Address) -> RpcResult<Option<Slot>> {
+        crate::wrong_api::<Option<Slot>>()
+    }
+
+    async fn get_address_threads(&self, _: Vec<Address>) -> RpcResult<Vec<u8>> {
+        crate::wrong_api::<Vec<u8>>()
+    }
+
+    async fn parse_address(&self, _: String) -> RpcResult<ParsedAddress> {
+        crate::wrong_api::<ParsedAddress>()
+    }
+
     async fn get_addresses_bytecode(&self, _: Vec<AddressFilter>) -> RpcResult<Vec<Vec<u8>>> {
         crate::wrong_api::<Vec<Vec<u8>>>()
     }
 
-    async fn send_operations(&self, _: Vec<OperationInput>) -> RpcResult<Vec<OperationId>> {
-        crate::wrong_api::<Vec<OperationId>>()
+    async fn get_bytecode_hash(&self, _: Vec<Address>) -> RpcResult<Vec<AddressBytecodeHash>> {
+        crate::wrong_api::<Vec<AddressBytecodeHash>>()
+    }
+
+    async fn get_datastore_keys(
+        &self,
+        _: Vec<Address>,
+    ) -> RpcResult<Vec<(Address, BTreeSet<Vec<u8>>, BTreeSet<Vec<u8>>)>> {
+        crate::wrong_api::<Vec<(Address, BTreeSet<Vec<u8>>, BTreeSet<Vec<u8>>)>>()
+    }
+
+    async fn get_deferred_credits(&self, _: Vec<AddressFilter>) -> RpcResult<Vec<Vec<SlotAmount>>> {
+        crate::wrong_api::<Vec<Vec<SlotAmount>>>()
+    }
+
+    async fn get_balance_at_slot(&self, _: Address, _: Slot) -> RpcResult<Amount> {
+        crate::wrong_api::<Amount>()
+    }
+
+    async fn send_operations(&self, _: Vec<OperationInput>) -> RpcResult<Vec<(OperationId, bool)>> {
+        crate::wrong_api::<Vec<(OperationId, bool)>>()
+    }
+
+    async fn validate_operations(
+        &self,
+        _: Vec<OperationInput>,
+    ) -> RpcResult<Vec<OperationValidity>> {
+        crate::wrong_api::<Vec<OperationValidity>>()
+    }
+
+    async fn compute_operation_id(&self, _: OperationInput) -> RpcResult<OperationId> {
+        crate::wrong_api::<OperationId>()
+    }
+
+    async fn simulate_operation(&self, _: OperationInput) -> RpcResult<OperationSimulation> {
+        crate::wrong_api::<OperationSimulation>()
+    }
+
+    async fn decode_operation(&self, _: Vec<u8>, _: Option<bool>) -> RpcResult<DecodedOperation> {
+        crate::wrong_api::<DecodedOperation>()
     }
 
     async fn get_filtered_sc_output_event(&self, _: EventFilter) -> RpcResult<Vec<SCOutputEvent>> {
         crate::wrong_api::<Vec<SCOutputEvent>>()
     }
 
+    async fn get_filtered_sc_output_event_by_operation(
+        &self,
+        _: EventFilter,
+    ) -> RpcResult<PreHashMap<OperationId, Vec<SCOutputEvent>>> {
+        crate::wrong_api::<PreHashMap<OperationId, Vec<SCOutputEvent>>>()
+    }
+
+    async fn export_sc_output_events(&self, filter: EventFilter, path: PathBuf) -> RpcResult<u64> {
+        let execution_controller = self.0.execution_controller.clone();
+        tokio::task::spawn_blocking(move || {
+            // read the events page by page, each page is written before the next one is read
+            let mut after = None;
+            let pages = std::iter::from_fn(move || {
+                let page = execution_controller.get_filtered_sc_output_event_page(
+                    filter.clone(),
+                    after,
+                    EVENTS_EXPORT_PAGE_SIZE,
+                );
+                let last = page.last()?;
+                after = Some((last.context.slot, last.context.index_in_slot));
+                Some(page)
+            });
+            write_events_to_jsonl_file(&path, pages.flatten())
+        })
+        .await
+        .map_err(|err| ApiError::InternalServerError(err.to_string()))?
+        .map_err(|err| err.into())
+    }
+
+    async fn find_addresses_with_key_prefix(
+        &self,
+        prefix: Vec<u8>,
+        after: Option<Address>,
+        limit: u64,
+    ) -> RpcResult<KeyPrefixAddresses> {
+        let limit = std::cmp::min(limit, self.0.api_settings.max_arguments) as usize;
+        let max_visited = self.0.api_settings.max_key_prefix_scan as usize;
+        let execution_controller = self.0.execution_controller.clone();
+        // the scan reads the database: keep it off the async runtime
+        let (addresses, next) = tokio::task::spawn_blocking(move || {
+            execution_controller.find_addresses_with_key_prefix(&prefix, after, max_visited, limit)
+        })
+        .await
+        .map_err(|err| ApiError::InternalServerError(err.to_string()))?;
+        Ok(KeyPrefixAddresses { addresses, next })
+    }
+
     async fn node_peers_whitelist(&self) -> RpcResult<Vec<IpAddr>> {
         //TODO: Reinvoke
         // let network_command_sender = self.0.network_command_sender.clone();
@@ -459,6 +790,49 @@ fn read_ips_from_jsonfile(
         })
 }
 
+/// Write events to a newline-delimited json file, one event per line, as they are yielded.
+/// Returns the number of written events.
+pub(crate) fn write_events_to_jsonl_file(
+    path: &Path,
+    events: impl IntoIterator<Item = SCOutputEvent>,
+) -> Result<u64, ApiError> {
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+        .map_err(|e| {
+            ApiError::InternalServerError(format!(
+                "failed to create events export file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+    let mut writer = BufWriter::new(file);
+    let mut count = 0u64;
+    for event in events {
+        serde_json::to_writer(&mut writer, &event)
+            .map_err(|e| e.to_string())
+            .and_then(|_| writer.write_all(b"\n").map_err(|e| e.to_string()))
+            .map_err(|e| {
+                ApiError::InternalServerError(format!(
+                    "failed to write events export file {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+        count += 1;
+    }
+    writer.flush().map_err(|e| {
+        ApiError::InternalServerError(format!(
+            "failed to write events export file {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+    Ok(count)
+}
+
 /// Write bootstrap list IP(s) from json file
 fn write_ips_to_jsonfile(
     bootstrap_list_file: PathBuf,
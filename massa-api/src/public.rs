@@ -1,35 +1,53 @@
 //! Copyright (c) 2022 MASSA LABS <info@massa.net>
 #![allow(clippy::too_many_arguments)]
 
-use crate::{MassaRpcServer, Public, RpcServer, StopHandle, Value, API};
+use crate::{MassaRpcServer, Public, RequestAdmission, RpcServer, StopHandle, Value, API};
 use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use itertools::{izip, Itertools};
 use jsonrpsee::core::{Error as JsonRpseeError, RpcResult};
 use massa_api_exports::{
-    address::{AddressFilter, AddressInfo},
-    block::{BlockInfo, BlockInfoContent, BlockSummary},
+    address::{
+        AddressBytecodeHash, AddressCategory, AddressFilter, AddressInfo, KeyPrefixAddresses,
+        ParsedAddress,
+    },
+    block::{
+        BlockInfo, BlockInfoContent, BlockSummary, BlockTimestamp, CliqueMembership,
+        DetailedClique, SlotBlocks, SlotForkChoiceBlock,
+    },
     config::APIConfig,
     datastore::{DatastoreEntryInput, DatastoreEntryOutput},
+    denunciation::PendingDenunciation,
     endorsement::EndorsementInfo,
-    error::ApiError,
-    execution::{ExecuteReadOnlyResponse, ReadOnlyBytecodeExecution, ReadOnlyCall, ReadOnlyResult},
-    node::NodeStatus,
-    operation::{OperationInfo, OperationInput},
+    error::{ApiError, DeserializeErrorDetails},
+    execution::{
+        ExecuteReadOnlyResponse, ExecuteReadOnlyResponseSerializer, ReadOnlyBulkCall,
+        ReadOnlyBytecodeExecution, ReadOnlyCall, ReadOnlyResult,
+    },
+    node::{BannedPeerInfo, FeeParameters, NodeHealth, NodeStatus, VersionInfo},
+    operation::{
+        DecodedOperation, OperationInclusionProof, OperationInfo, OperationInput,
+        OperationSimulation, OperationValidity,
+    },
     page::{PageRequest, PagedVec},
-    slot::SlotAmount,
+    slot::{ExecutionSlots, SlotAmount, ThreadExecutionSlots},
     TimeInterval,
 };
 use massa_consensus_exports::block_status::DiscardReason;
 use massa_consensus_exports::ConsensusController;
 use massa_execution_exports::{
-    ExecutionController, ExecutionQueryRequest, ExecutionQueryRequestItem,
-    ExecutionQueryResponseItem, ExecutionStackElement, ReadOnlyExecutionRequest,
+    ExecutionAddressInfo, ExecutionController, ExecutionError, ExecutionQueryError,
+    ExecutionQueryRequest, ExecutionQueryRequestItem, ExecutionQueryResponseItem,
+    ExecutionStackElement, ReadOnlyExecutionOutput, ReadOnlyExecutionRequest,
     ReadOnlyExecutionTarget,
 };
+use massa_hash::Hash;
+use massa_ledger_exports::{LedgerChanges, LedgerStats, SetOrDelete, SetOrKeep, SetUpdateOrDelete};
 use massa_models::{
     address::Address,
     amount::Amount,
     block::{Block, BlockGraphStatus},
+    block_header::SecuredHeader,
     block_id::BlockId,
     clique::Clique,
     composite::PubkeySig,
@@ -37,32 +55,45 @@ use massa_models::{
     datastore::DatastoreDeserializer,
     endorsement::EndorsementId,
     endorsement::SecureShareEndorsement,
-    error::ModelsError,
-    execution::EventFilter,
+    execution::{EventFilter, ExecutionTraceStep},
     node::NodeId,
     operation::OperationDeserializer,
     operation::OperationId,
-    operation::{OperationType, SecureShareOperation},
+    operation::{Operation, OperationType, SecureShareOperation},
     output_event::SCOutputEvent,
     prehash::{PreHashMap, PreHashSet},
     secure_share::SecureShareDeserializer,
     slot::{IndexedSlot, Slot},
+    stats::{ConsensusStats, PoolStats},
     timeslots,
     timeslots::{get_latest_block_slot_at_timestamp, time_range_to_slot_range},
     version::Version,
 };
 use massa_pool_exports::PoolController;
-use massa_pos_exports::SelectorController;
+use massa_pos_exports::{PosError, SelectorController};
 use massa_protocol_exports::{PeerConnectionType, ProtocolConfig, ProtocolController};
-use massa_serialization::{DeserializeError, Deserializer};
+use massa_serialization::{DeserializeError, Deserializer, Serializer};
 use massa_storage::Storage;
 use massa_time::MassaTime;
-use massa_versioning::versioning_factory::FactoryStrategy;
-use massa_versioning::{
-    keypair_factory::KeyPairFactory, versioning::MipStore, versioning_factory::VersioningFactory,
-};
-use std::collections::BTreeMap;
+use massa_versioning::{keypair_factory::KeyPairFactory, versioning::MipStore};
+use massa_wallet::Wallet;
+use parking_lot::RwLock;
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use std::collections::{BTreeMap, BTreeSet};
 use std::net::{IpAddr, SocketAddr};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use std::time::{Duration, Instant};
+use tokio::sync::OwnedSemaphorePermit;
+use tracing::{debug, info_span};
+
+/// backoff before the first retry of a failing component query of `get_status`, multiplied by the retry number after that
+const STATUS_RETRY_BACKOFF: Duration = Duration::from_millis(10);
 
 impl API<Public> {
     /// generate a new public API
@@ -78,6 +109,9 @@ impl API<Public> {
         node_id: NodeId,
         storage: Storage,
         mip_store: MipStore,
+        node_wallet: Arc<RwLock<Wallet>>,
+        bootstrap_sessions: Option<Arc<AtomicUsize>>,
+        admission: RequestAdmission,
     ) -> Self {
         API(Public {
             consensus_controller,
@@ -91,7 +125,697 @@ impl API<Public> {
             protocol_config,
             storage,
             keypair_factory: KeyPairFactory { mip_store },
+            node_wallet,
+            admission,
+            status_cache: Default::default(),
+            bootstrap_sessions,
+        })
+    }
+
+    /// get the info of the given blocks, with their graph status.
+    /// Blocks absent from storage are skipped.
+    fn get_block_infos(&self, mut ids: Vec<BlockId>) -> Vec<BlockInfo> {
+        let mut blocks: Vec<Block> = Vec::with_capacity(ids.len());
+        {
+            let block_storage_lock = self.0.storage.read_blocks();
+            ids.retain(|id| {
+                if let Some(wrapped_block) = block_storage_lock.get(id) {
+                    blocks.push(wrapped_block.content.clone());
+                    return true;
+                };
+                false
+            });
+        }
+        let block_statuses = self.0.consensus_controller.get_block_statuses(&ids);
+        ids.into_iter()
+            .zip(blocks)
+            .zip(block_statuses)
+            .map(|((id, content), graph_status)| BlockInfo {
+                id,
+                content: Some(BlockInfoContent {
+                    current_version: content.header.content.current_version,
+                    announced_version: content.header.content.announced_version,
+                    is_final: graph_status == BlockGraphStatus::Final,
+                    is_in_blockclique: graph_status == BlockGraphStatus::ActiveInBlockclique,
+                    is_candidate: graph_status == BlockGraphStatus::ActiveInBlockclique
+                        || graph_status == BlockGraphStatus::ActiveInAlternativeCliques,
+                    is_discarded: graph_status == BlockGraphStatus::Discarded,
+                    block: content,
+                }),
+            })
+            .collect()
+    }
+
+    /// Bound the slots scanned by a graph query to `max_graph_interval_slots`:
+    /// an open end stands for the current slot, and an open start is clamped to the maximum span before the end.
+    /// Returns the start slot to query.
+    fn bound_graph_range(
+        &self,
+        start_slot: Option<Slot>,
+        end_slot: Option<Slot>,
+    ) -> Result<Option<Slot>, ApiError> {
+        let api_settings = &self.0.api_settings;
+        let max_span = api_settings.max_graph_interval_slots;
+        let span_end = match end_slot {
+            Some(slot) => slot,
+            None => {
+                let now = MassaTime::now().map_err(|e| {
+                    ApiError::InconsistencyError(format!("Unable to get current time: {}", e))
+                })?;
+                get_latest_block_slot_at_timestamp(
+                    api_settings.thread_count,
+                    api_settings.t0,
+                    api_settings.genesis_timestamp,
+                    now,
+                )
+                .map_err(ApiError::ModelsError)?
+                .unwrap_or_else(|| Slot::new(0, 0))
+            }
+        };
+        Ok(match start_slot {
+            Some(start_slot) => {
+                if start_slot < span_end {
+                    let span = timeslots::slot_count_in_range(
+                        start_slot,
+                        span_end,
+                        api_settings.thread_count,
+                    )
+                    .unwrap_or(u64::MAX);
+                    if span > max_span {
+                        return Err(ApiError::RangeTooLarge(format!(
+                            "the interval covers {} slots, max is {}",
+                            span, max_span
+                        )));
+                    }
+                }
+                Some(start_slot)
+            }
+            None => {
+                let thread_count = api_settings.thread_count as u64;
+                let start_index = span_end
+                    .period
+                    .saturating_mul(thread_count)
+                    .saturating_add(span_end.thread as u64)
+                    .saturating_sub(max_span);
+                Some(Slot::new(
+                    start_index / thread_count,
+                    (start_index % thread_count) as u8,
+                ))
+            }
+        })
+    }
+
+    /// Run a component query of the node status, retrying it up to `status_retry_count` times
+    /// with an increasing backoff while it fails, so that a transient error does not fail `get_status`
+    async fn with_status_retries<T, E>(
+        &self,
+        mut query: impl FnMut() -> Result<T, E>,
+    ) -> Result<T, E> {
+        let mut retries = 0;
+        loop {
+            match query() {
+                Err(_) if retries < self.0.api_settings.status_retry_count => {
+                    retries += 1;
+                    tokio::time::sleep(STATUS_RETRY_BACKOFF * retries).await;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// compute the node status by querying every component
+    async fn compute_status(&self) -> Result<NodeStatus, ApiError> {
+        let version = self.0.version;
+        let api_settings = self.0.api_settings.clone();
+        let protocol_config = self.0.protocol_config.clone();
+        let node_id = self.0.node_id;
+        let config = CompactConfig::default();
+        let now = match MassaTime::now() {
+            Ok(now) => now,
+            Err(e) => return Err(ApiError::TimeError(e)),
+        };
+
+        let last_slot_result = get_latest_block_slot_at_timestamp(
+            api_settings.thread_count,
+            api_settings.t0,
+            api_settings.genesis_timestamp,
+            now,
+        );
+        let last_slot = match last_slot_result {
+            Ok(last_slot) => last_slot,
+            Err(e) => return Err(ApiError::ModelsError(e)),
+        };
+
+        let execution_stats = self.0.execution_controller.get_stats();
+        let consensus_stats_result = self
+            .with_status_retries(|| self.0.consensus_controller.get_stats())
+            .await;
+        let consensus_stats = match consensus_stats_result {
+            Ok(consensus_stats) => consensus_stats,
+            Err(e) => return Err(ApiError::ConsensusError(e.to_string())),
+        };
+
+        let (network_stats, peers) = match self
+            .with_status_retries(|| self.0.protocol_controller.get_stats())
+            .await
+        {
+            Ok((stats, peers)) => (stats, peers),
+            Err(e) => return Err(ApiError::ProtocolError(e.to_string())),
+        };
+
+        let pool_stats = PoolStats {
+            operation_count: self.0.pool_command_sender.get_operation_count() as u64,
+            endorsement_count: self.0.pool_command_sender.get_endorsement_count() as u64,
+            operation_count_per_thread: self
+                .0
+                .pool_command_sender
+                .get_operation_count_per_thread()
+                .into_iter()
+                .map(|count| count as u64)
+                .collect(),
+        };
+
+        let next_slot_result = last_slot
+            .unwrap_or_else(|| Slot::new(0, 0))
+            .get_next_slot(api_settings.thread_count);
+
+        let next_slot = match next_slot_result {
+            Ok(next_slot) => next_slot,
+            Err(e) => return Err(ApiError::ModelsError(e)),
+        };
+
+        let connected_nodes = peers
+            .iter()
+            .map(|(id, peer)| {
+                let is_outgoing = match peer.1 {
+                    PeerConnectionType::IN => false,
+                    PeerConnectionType::OUT => true,
+                };
+                (NodeId::new(id.get_public_key()), (peer.0.ip(), is_outgoing))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let current_cycle = last_slot
+            .unwrap_or_else(|| Slot::new(0, 0))
+            .get_cycle(api_settings.periods_per_cycle);
+
+        let cycle_duration = match api_settings.t0.checked_mul(api_settings.periods_per_cycle) {
+            Ok(cycle_duration) => cycle_duration,
+            Err(e) => return Err(ApiError::TimeError(e)),
+        };
+
+        let current_cycle_time_result = if current_cycle == 0 {
+            Ok(api_settings.genesis_timestamp)
+        } else {
+            cycle_duration.checked_mul(current_cycle).and_then(
+                |elapsed_time_before_current_cycle| {
+                    api_settings
+                        .genesis_timestamp
+                        .checked_add(elapsed_time_before_current_cycle)
+                },
+            )
+        };
+
+        let current_cycle_time = match current_cycle_time_result {
+            Ok(current_cycle_time) => current_cycle_time,
+            Err(e) => return Err(ApiError::TimeError(e)),
+        };
+
+        let next_cycle_time = match current_cycle_time.checked_add(cycle_duration) {
+            Ok(next_cycle_time) => next_cycle_time,
+            Err(e) => return Err(ApiError::TimeError(e)),
+        };
+
+        Ok(NodeStatus {
+            node_id,
+            node_ip: protocol_config.routable_ip,
+            version,
+            current_time: now,
+            current_cycle_time,
+            next_cycle_time,
+            connected_nodes,
+            last_slot,
+            next_slot,
+            final_slots: self.0.consensus_controller.get_latest_final_slots(),
+            execution_stats,
+            is_bootstrap_server: self.0.bootstrap_sessions.is_some(),
+            active_bootstrap_sessions: self
+                .0
+                .bootstrap_sessions
+                .as_ref()
+                .map_or(0, |sessions| sessions.load(Ordering::Relaxed) as u64),
+            consensus_stats,
+            network_stats,
+            pool_stats,
+            config,
+            current_cycle,
+        })
+    }
+
+    /// build the deserializer of secure-shared operations from the API settings
+    fn operation_deserializer(&self) -> SecureShareDeserializer<Operation, OperationDeserializer> {
+        let api_cfg = &self.0.api_settings;
+        SecureShareDeserializer::new(OperationDeserializer::new(
+            api_cfg.max_datastore_value_length,
+            api_cfg.max_function_name_length,
+            api_cfg.max_parameter_size,
+            api_cfg.max_op_datastore_entry_count,
+            api_cfg.max_op_datastore_key_length,
+            api_cfg.max_op_datastore_value_length,
+        ))
+    }
+
+    /// Check that the payload of a request (bytecodes, datastores or serialized operations)
+    /// does not exceed `max_request_bytes`.
+    fn check_request_bytes(&self, request_bytes: usize) -> Result<(), ApiError> {
+        let max_request_bytes = self.0.api_settings.max_request_bytes;
+        if request_bytes as u64 > max_request_bytes {
+            return Err(ApiError::RequestTooLarge(format!(
+                "request payload is {} bytes, max is {}",
+                request_bytes, max_request_bytes
+            )));
+        }
+        Ok(())
+    }
+
+    /// Deserialize operation inputs and check their signature, gas limit and expiry,
+    /// as done before submitting them to the pool.
+    /// The checks run on the blocking thread pool, not to hold an async worker for the whole batch.
+    /// The outer error is about the request as a whole, the inner ones are per operation.
+    async fn check_operation_inputs(
+        &self,
+        ops: Vec<OperationInput>,
+    ) -> Result<Vec<Result<SecureShareOperation, ApiError>>, ApiError> {
+        let api_cfg = &self.0.api_settings;
+        let max_gas_per_block = api_cfg.max_gas_per_block;
+        let operation_deserializer = self.operation_deserializer();
+        let now = MassaTime::now().map_err(|e| {
+            ApiError::InconsistencyError(format!("Unable to get current time: {}", e))
+        })?;
+        let last_slot = get_latest_block_slot_at_timestamp(
+            api_cfg.thread_count,
+            api_cfg.t0,
+            api_cfg.genesis_timestamp,
+            now,
+        )
+        .map_err(ApiError::ModelsError)?;
+        // operations are independent and signature checks are CPU-bound: check them in parallel,
+        // the results keep the order of the inputs
+        tokio::task::spawn_blocking(move || {
+            ops.into_par_iter()
+                .map(|op_input| {
+                    // offsets in deserialization errors are relative to the serialized content
+                    let content_len = op_input.serialized_content.len();
+                    let mut op_serialized = Vec::new();
+                    op_serialized.extend(op_input.signature.to_bytes());
+                    op_serialized.extend(op_input.creator_public_key.to_bytes());
+                    op_serialized.extend(op_input.serialized_content);
+                    let (rest, op): (&[u8], SecureShareOperation) = operation_deserializer
+                        .deserialize::<DeserializeError>(&op_serialized)
+                        .map_err(|err| {
+                            ApiError::DeserializeError(DeserializeErrorDetails::new(&err, content_len))
+                        })?;
+                    match op.content.op {
+                        OperationType::CallSC { max_gas, .. } | OperationType::ExecuteSC { max_gas, .. } => {
+                            if max_gas > max_gas_per_block {
+                                return Err(ApiError::InconsistencyError("Gas limit of the operation is higher than the block gas limit. Your operation will never be included in a block.".into()));
+                            }
+                        },
+                        _ => {}
+                    };
+                    if let Some(slot) = last_slot {
+                        if op.content.expire_period < slot.period {
+                            return Err(ApiError::InconsistencyError("Operation expire_period is lower than the current period of this node. Your operation will never be included in a block.".into()));
+                        }
+                    }
+                    if !rest.is_empty() {
+                        return Err(ApiError::DeserializeError(DeserializeErrorDetails {
+                            offset: content_len.checked_sub(rest.len()),
+                            context: vec![
+                                "There is data left after operation deserialization".to_owned(),
+                            ],
+                        }));
+                    }
+                    op.verify_signature().map_err(ApiError::ModelsError)?;
+                    Ok(op)
+                })
+                .collect()
         })
+        .await
+        .map_err(|err| ApiError::InternalServerError(err.to_string()))
+    }
+
+    /// get the final or candidate bytecode of an address, failing if it has none
+    fn get_address_bytecode(&self, address: Address, is_final: bool) -> Result<Vec<u8>, ApiError> {
+        let query = if is_final {
+            ExecutionQueryRequestItem::AddressBytecodeFinal(address)
+        } else {
+            ExecutionQueryRequestItem::AddressBytecodeCandidate(address)
+        };
+        let response = self
+            .0
+            .execution_controller
+            .query_state(ExecutionQueryRequest {
+                requests: vec![query],
+            })
+            .responses
+            .pop();
+        match response {
+            Some(Ok(ExecutionQueryResponseItem::Bytecode(bytecode))) if !bytecode.0.is_empty() => {
+                Ok(bytecode.0)
+            }
+            Some(Ok(ExecutionQueryResponseItem::Bytecode(_)))
+            | Some(Err(ExecutionQueryError::NotFound(_))) => Err(ApiError::BadRequest(format!(
+                "address {} has no bytecode",
+                address
+            ))),
+            _ => Err(ApiError::InternalServerError(
+                "unexpected response type".to_string(),
+            )),
+        }
+    }
+
+    /// gather the information of the operations found in storage, in the order of the IDs
+    fn get_operations_info(
+        &self,
+        operations_ids: Vec<OperationId>,
+        only_final: bool,
+    ) -> Result<Vec<OperationInfo>, ApiError> {
+        // get the operations and the list of blocks that contain them from storage
+        let secure_share_operations: Vec<SecureShareOperation> = {
+            let read_ops = self.0.storage.read_operations();
+            operations_ids
+                .iter()
+                .filter_map(|id| read_ops.get(id).cloned())
+                .collect()
+        };
+
+        let storage_info: Vec<(SecureShareOperation, PreHashSet<BlockId>)> = {
+            let read_blocks = self.0.storage.read_blocks();
+            secure_share_operations
+                .into_iter()
+                .map(|secure_share_operation| {
+                    let op_id = secure_share_operation.id;
+                    (
+                        secure_share_operation,
+                        read_blocks
+                            .get_blocks_by_operation(&op_id)
+                            .cloned()
+                            .unwrap_or_default(),
+                    )
+                })
+                .collect()
+        };
+
+        // keep only the ops id (found in storage)
+        let ops: Vec<OperationId> = storage_info.iter().map(|(op, _)| op.id).collect();
+
+        let api_cfg = self.0.api_settings.clone();
+        if ops.len() as u64 > api_cfg.max_arguments {
+            return Err(ApiError::BadRequest("too many arguments".into()));
+        }
+
+        // ask pool whether it carries the operations, final operations are not looked up there
+        let in_pool = if only_final {
+            vec![false; ops.len()]
+        } else {
+            self.0.pool_command_sender.contains_operations(&ops)
+        };
+
+        let op_exec_statuses = self.0.execution_controller.get_ops_exec_status(&ops);
+
+        // compute operation finality and operation execution status from *_op_exec_statuses
+        let (is_operation_final, statuses): (Vec<Option<bool>>, Vec<Option<bool>>) =
+            op_exec_statuses
+                .into_iter()
+                .map(|(spec_exec, final_exec)| match (spec_exec, final_exec) {
+                    (Some(true), Some(true)) => (Some(true), Some(true)),
+                    (Some(false), Some(false)) => (Some(true), Some(false)),
+                    (Some(true), None) => (Some(false), Some(true)),
+                    (Some(false), None) => (Some(false), Some(false)),
+                    _ => (None, None),
+                })
+                .collect::<Vec<(Option<bool>, Option<bool>)>>()
+                .into_iter()
+                .unzip();
+
+        // consensus status of the blocks including the operations
+        let block_statuses: PreHashMap<BlockId, BlockGraphStatus> = {
+            let involved_blocks: Vec<BlockId> = storage_info
+                .iter()
+                .flat_map(|(_, in_blocks)| in_blocks.iter().copied())
+                .unique()
+                .collect();
+            if involved_blocks.is_empty() {
+                PreHashMap::default()
+            } else {
+                let statuses = self
+                    .0
+                    .consensus_controller
+                    .get_block_statuses(&involved_blocks);
+                involved_blocks.into_iter().zip(statuses).collect()
+            }
+        };
+
+        // gather all values into a vector of OperationInfo instances
+        let mut res: Vec<OperationInfo> = Vec::with_capacity(ops.len());
+        let zipped_iterator = izip!(
+            ops.into_iter(),
+            storage_info.into_iter(),
+            in_pool.into_iter(),
+            is_operation_final.into_iter(),
+            statuses.into_iter(),
+        );
+        for (id, (operation, in_blocks), in_pool, is_operation_final, op_exec_status) in
+            zipped_iterator
+        {
+            // execution can see an operation as final before consensus confirms the finality of a block
+            // including it (e.g. while catching up): while consensus still holds such a block as non-final,
+            // the operation is not final yet. Blocks unknown to consensus (e.g. pruned once final) leave
+            // the finality reported by execution unchanged
+            let is_operation_final = match is_operation_final {
+                Some(true) => {
+                    let consensus_statuses = in_blocks
+                        .iter()
+                        .filter_map(|block_id| block_statuses.get(block_id));
+                    let mut has_final_block = false;
+                    let mut has_pending_block = false;
+                    for status in consensus_statuses {
+                        match status {
+                            BlockGraphStatus::Final => has_final_block = true,
+                            BlockGraphStatus::NotFound => {}
+                            _ => has_pending_block = true,
+                        }
+                    }
+                    Some(has_final_block || !has_pending_block)
+                }
+                is_operation_final => is_operation_final,
+            };
+            if only_final && is_operation_final != Some(true) {
+                continue;
+            }
+            res.push(OperationInfo {
+                id,
+                in_pool,
+                is_operation_final,
+                thread: operation
+                    .content_creator_address
+                    .get_thread(api_cfg.thread_count),
+                fee: operation.content.fee,
+                size_bytes: operation.serialized_size(),
+                operation,
+                in_blocks: in_blocks.into_iter().collect(),
+                op_exec_status,
+            });
+        }
+
+        // return values in the right order
+        Ok(res)
+    }
+
+    /// cycle of the current slot, or of the restart slot if the network has not reached it yet
+    fn current_cycle(&self) -> Result<u64, ApiError> {
+        let cfg = &self.0.api_settings;
+        let now = MassaTime::now().map_err(ApiError::TimeError)?;
+
+        match get_latest_block_slot_at_timestamp(
+            cfg.thread_count,
+            cfg.t0,
+            cfg.genesis_timestamp,
+            now,
+        ) {
+            Ok(Some(cur_slot)) if cur_slot.period <= cfg.last_start_period => {
+                Ok(Slot::new(cfg.last_start_period, 0).get_cycle(cfg.periods_per_cycle))
+            }
+            Ok(Some(cur_slot)) => Ok(cur_slot.get_cycle(cfg.periods_per_cycle)),
+            Ok(None) => Ok(0),
+            Err(e) => Err(ApiError::ModelsError(e)),
+        }
+    }
+
+    /// Error for a draw query about a slot whose cycle is out of the selector's computed range.
+    /// The selector reports any missing cycle as unavailable: past cycles were pruned, future ones are not computed yet
+    fn draw_error(&self, err: PosError) -> ApiError {
+        match err {
+            PosError::CycleUnavailable(cycle) => match self.current_cycle() {
+                Ok(current_cycle) if cycle > current_cycle => {
+                    ApiError::BadRequest(format!("draws of cycle {} are not computed yet", cycle))
+                }
+                Ok(_) => ApiError::BadRequest(format!(
+                    "draws of cycle {} are not in the retained history",
+                    cycle
+                )),
+                Err(e) => e,
+            },
+            PosError::CycleUnfinished(cycle) => {
+                ApiError::BadRequest(format!("draws of cycle {} are not computed yet", cycle))
+            }
+            e => ApiError::InternalServerError(e.to_string()),
+        }
+    }
+
+    /// Reject a slot whose thread does not exist
+    fn check_slot_thread(&self, slot: Slot) -> Result<(), ApiError> {
+        if slot.thread >= self.0.api_settings.thread_count {
+            return Err(ApiError::BadRequest(format!(
+                "thread {} is out of range, thread count is {}",
+                slot.thread, self.0.api_settings.thread_count
+            )));
+        }
+        Ok(())
+    }
+
+    /// range of slots from the current one up to the draw lookahead horizon
+    fn draw_lookahead_range(&self) -> Result<RangeInclusive<Slot>, ApiError> {
+        let cur_slot = timeslots::get_current_latest_block_slot(
+            self.0.api_settings.thread_count,
+            self.0.api_settings.t0,
+            self.0.api_settings.genesis_timestamp,
+        )
+        .map_err(|e| {
+            ApiError::InconsistencyError(format!("could not get the current slot: {}", e))
+        })?
+        .unwrap_or_else(|| Slot::new(0, 0));
+        let slot_end = Slot::new(
+            cur_slot
+                .period
+                .saturating_add(self.0.api_settings.draw_lookahead_period_count),
+            cur_slot.thread,
+        );
+
+        // the selector may not have computed the draws of the current cycle yet (e.g. right after startup),
+        // in which case it would silently return no selections
+        if let Err(PosError::CycleUnavailable(cycle) | PosError::CycleUnfinished(cycle)) =
+            self.0.selector_controller.get_selection(cur_slot)
+        {
+            return Err(ApiError::SelectorNotReady(format!(
+                "draws of cycle {} are not available yet",
+                cycle
+            )));
+        }
+
+        Ok(cur_slot..=slot_end)
+    }
+
+    /// Admit a request to `method`: check its rate limit, then take one of the in-flight request permits.
+    /// The permit is released when dropped, at the end of the request.
+    fn admit_request(&self, method: &str) -> Result<OwnedSemaphorePermit, ApiError> {
+        self.0
+            .admission
+            .admit(method, &self.0.api_settings.rate_limits)
+    }
+
+    /// Run a read-only execution request, giving up on it once `read_only_deadline` is exceeded.
+    /// The execution holds `permit` until it completes, so that executions running past the deadline
+    /// still count against `max_concurrent_requests`.
+    /// Errors are returned as the message reported to the client, with the trace recorded up to the failure.
+    async fn run_read_only_request(
+        &self,
+        req: ReadOnlyExecutionRequest,
+        permit: &Arc<OwnedSemaphorePermit>,
+    ) -> Result<ReadOnlyExecutionOutput, ReadOnlyFailure> {
+        let deadline = self.0.api_settings.read_only_deadline.to_duration();
+        if deadline.is_zero() {
+            return self
+                .0
+                .execution_controller
+                .execute_readonly_request(req)
+                .map_err(ReadOnlyFailure::from);
+        }
+
+        // a running execution cannot be interrupted: past the deadline it completes in the background,
+        // bounded by its gas and keeping the request permit, but the client gets its answer right away
+        let execution_controller = self.0.execution_controller.clone();
+        let permit = permit.clone();
+        let execution = tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            execution_controller.execute_readonly_request(req)
+        });
+        match tokio::time::timeout(deadline, execution).await {
+            Ok(Ok(result)) => result.map_err(ReadOnlyFailure::from),
+            Ok(Err(err)) => Err(ReadOnlyFailure::new(format!(
+                "readonly call failed: {}",
+                err
+            ))),
+            Err(_) => Err(ReadOnlyFailure::new("deadline exceeded".to_string())),
+        }
+    }
+
+    /// Simulate a transaction on the candidate balances: the sender pays the amount and the fee,
+    /// the recipient receives the amount. Transactions are not executed by the VM, so no slot or gas is reported.
+    fn simulate_transaction(
+        &self,
+        op: &SecureShareOperation,
+        recipient_address: Address,
+        amount: Amount,
+    ) -> OperationSimulation {
+        let sender_address = op.content_creator_address;
+        let balances = self
+            .0
+            .execution_controller
+            .get_final_and_candidate_balance(&[sender_address, recipient_address]);
+        let candidate_balance =
+            |index: usize| balances.get(index).and_then(|(_, candidate)| *candidate);
+
+        let mut simulation = OperationSimulation {
+            id: op.id,
+            executed_at: None,
+            result: ReadOnlyResult::Ok(Vec::new()),
+            gas_cost: 0,
+            balance_changes: Vec::new(),
+            datastore_changes: Vec::new(),
+        };
+        let sender_balance = amount
+            .checked_add(op.content.fee)
+            .and_then(|spent| candidate_balance(0)?.checked_sub(spent));
+        let Some(sender_balance) = sender_balance else {
+            simulation.result = ReadOnlyResult::Error(format!(
+                "insufficient balance of {} to pay amount {} and fee {}",
+                sender_address, amount, op.content.fee
+            ));
+            return simulation;
+        };
+        if recipient_address == sender_address {
+            simulation.balance_changes =
+                vec![(sender_address, sender_balance.saturating_add(amount))];
+            return simulation;
+        }
+        let Some(recipient_balance) = candidate_balance(1).unwrap_or_default().checked_add(amount)
+        else {
+            simulation.result = ReadOnlyResult::Error(format!(
+                "balance of {} overflows when receiving amount {}",
+                recipient_address, amount
+            ));
+            return simulation;
+        };
+        simulation.balance_changes = vec![
+            (sender_address, sender_balance),
+            (recipient_address, recipient_balance),
+        ];
+        simulation
+            .balance_changes
+            .sort_by_key(|(address, _)| *address);
+        simulation
     }
 }
 
@@ -125,34 +849,46 @@ impl MassaRpcServer for API<Public> {
         &self,
         reqs: Vec<ReadOnlyBytecodeExecution>,
     ) -> RpcResult<Vec<ExecuteReadOnlyResponse>> {
+        let permit = Arc::new(self.admit_request("execute_read_only_bytecode")?);
         if reqs.len() as u64 > self.0.api_settings.max_arguments {
             return Err(ApiError::BadRequest("too many arguments".into()).into());
         }
 
+        let request_bytes: usize = reqs
+            .iter()
+            .map(|req| req.bytecode.len() + req.operation_datastore.as_ref().map_or(0, |v| v.len()))
+            .sum();
+        self.check_request_bytes(request_bytes)?;
+
         let mut res: Vec<ExecuteReadOnlyResponse> = Vec::with_capacity(reqs.len());
         for ReadOnlyBytecodeExecution {
             max_gas,
             address,
             bytecode,
+            from_address,
             operation_datastore,
             is_final,
             fee,
+            label,
         } in reqs
         {
-            let address = if let Some(addr) = address {
-                addr
-            } else {
-                let now = MassaTime::now().map_err(|e| {
-                    ApiError::InconsistencyError(format!("Unable to get current time: {}", e))
-                })?;
-                let keypair = self
-                    .0
-                    .keypair_factory
-                    .create(&(), FactoryStrategy::At(now))
-                    .map_err(ApiError::from)?;
-                Address::from_public_key(&keypair.get_public_key())
+            crate::check_read_only_costs(&self.0.api_settings, max_gas, fee, None)?;
+
+            let bytecode = match from_address {
+                Some(from_address) => {
+                    if !bytecode.is_empty() {
+                        return Err(ApiError::BadRequest(
+                            "bytecode and from_address cannot be both set".to_string(),
+                        )
+                        .into());
+                    }
+                    self.get_address_bytecode(from_address, is_final)?
+                }
+                None => bytecode,
             };
 
+            let address = address.unwrap_or(self.0.api_settings.read_only_default_caller);
+
             let op_datastore = match operation_datastore {
                 Some(v) => {
                     let deserializer = DatastoreDeserializer::new(
@@ -175,7 +911,6 @@ impl MassaRpcServer for API<Public> {
             };
 
             // TODO:
-            // * set a maximum gas value for read-only executions to prevent attacks
             // * stop mapping request and result, reuse execution's structures
             // * remove async stuff
 
@@ -192,10 +927,13 @@ impl MassaRpcServer for API<Public> {
                 is_final,
                 coins: None,
                 fee,
+                label: label.clone(),
+                trace: false,
+                event_sender: None,
             };
 
             // run
-            let result = self.0.execution_controller.execute_readonly_request(req);
+            let result = self.run_read_only_request(req, &permit).await;
 
             // map result
             let result = ExecuteReadOnlyResponse {
@@ -203,13 +941,17 @@ impl MassaRpcServer for API<Public> {
                     .as_ref()
                     .map_or_else(|_| Slot::new(0, 0), |v| v.out.slot),
                 result: result.as_ref().map_or_else(
-                    |err| ReadOnlyResult::Error(format!("readonly call failed: {}", err)),
+                    |err| ReadOnlyResult::Error(err.message.clone()),
                     |res| ReadOnlyResult::Ok(res.call_result.clone()),
                 ),
                 gas_cost: result.as_ref().map_or_else(|_| 0, |v| v.gas_cost),
                 output_events: result
                     .as_ref()
                     .map_or_else(|_| Default::default(), |v| v.out.events.clone().0),
+                trace: result
+                    .as_ref()
+                    .map_or_else(|err| err.trace.clone(), |v| v.trace.clone()),
+                label,
                 state_changes: result.map_or_else(|_| Default::default(), |v| v.out.state_changes),
             };
 
@@ -225,6 +967,7 @@ impl MassaRpcServer for API<Public> {
         &self,
         reqs: Vec<ReadOnlyCall>,
     ) -> RpcResult<Vec<ExecuteReadOnlyResponse>> {
+        let permit = Arc::new(self.admit_request("execute_read_only_call")?);
         if reqs.len() as u64 > self.0.api_settings.max_arguments {
             return Err(ApiError::BadRequest("too many arguments".into()).into());
         }
@@ -239,24 +982,16 @@ impl MassaRpcServer for API<Public> {
             is_final,
             coins,
             fee,
+            label,
+            trace,
         } in reqs
         {
-            let caller_address = if let Some(addr) = caller_address {
-                addr
-            } else {
-                let now = MassaTime::now().map_err(|e| {
-                    ApiError::InconsistencyError(format!("Unable to get current time: {}", e))
-                })?;
-                let keypair = self
-                    .0
-                    .keypair_factory
-                    .create(&(), FactoryStrategy::At(now))
-                    .map_err(ApiError::from)?;
-                Address::from_public_key(&keypair.get_public_key())
-            };
+            crate::check_read_only_costs(&self.0.api_settings, max_gas, fee, coins)?;
+
+            let caller_address =
+                caller_address.unwrap_or(self.0.api_settings.read_only_default_caller);
 
             // TODO:
-            // * set a maximum gas value for read-only executions to prevent attacks
             // * stop mapping request and result, reuse execution's structures
             // * remove async stuff
 
@@ -285,10 +1020,13 @@ impl MassaRpcServer for API<Public> {
                 is_final,
                 coins,
                 fee,
+                label: label.clone(),
+                trace,
+                event_sender: None,
             };
 
             // run
-            let result = self.0.execution_controller.execute_readonly_request(req);
+            let result = self.run_read_only_request(req, &permit).await;
 
             // map result
             let result = ExecuteReadOnlyResponse {
@@ -296,13 +1034,17 @@ impl MassaRpcServer for API<Public> {
                     .as_ref()
                     .map_or_else(|_| Slot::new(0, 0), |v| v.out.slot),
                 result: result.as_ref().map_or_else(
-                    |err| ReadOnlyResult::Error(format!("readonly call failed: {}", err)),
+                    |err| ReadOnlyResult::Error(err.message.clone()),
                     |res| ReadOnlyResult::Ok(res.call_result.clone()),
                 ),
                 gas_cost: result.as_ref().map_or_else(|_| 0, |v| v.gas_cost),
                 output_events: result
                     .as_ref()
                     .map_or_else(|_| Default::default(), |v| v.out.events.clone().0),
+                trace: result
+                    .as_ref()
+                    .map_or_else(|err| err.trace.clone(), |v| v.trace.clone()),
+                label,
                 state_changes: result.map_or_else(|_| Default::default(), |v| v.out.state_changes),
             };
 
@@ -313,6 +1055,51 @@ impl MassaRpcServer for API<Public> {
         Ok(res)
     }
 
+    /// execute read-only bytecode, binary-encoded responses
+    async fn execute_read_only_bytecode_binary(
+        &self,
+        reqs: Vec<ReadOnlyBytecodeExecution>,
+    ) -> RpcResult<Vec<String>> {
+        // rate limited as `execute_read_only_bytecode`
+        let responses = self.execute_read_only_bytecode(reqs).await?;
+        encode_read_only_responses(&responses)
+    }
+
+    /// execute read-only calls sharing the same caller context
+    async fn execute_read_only_bulk_call(
+        &self,
+        req: ReadOnlyBulkCall,
+    ) -> RpcResult<Vec<ExecuteReadOnlyResponse>> {
+        // rate limited as `execute_read_only_call`
+        let reqs = req
+            .calls
+            .into_iter()
+            .map(|call| ReadOnlyCall {
+                max_gas: req.max_gas,
+                target_address: call.target_address,
+                target_function: call.target_function,
+                parameter: call.parameter,
+                caller_address: req.caller_address,
+                coins: None,
+                fee: req.fee,
+                is_final: req.is_final,
+                label: req.label.clone(),
+                trace: false,
+            })
+            .collect();
+        self.execute_read_only_call(reqs).await
+    }
+
+    /// execute read-only calls, binary-encoded responses
+    async fn execute_read_only_call_binary(
+        &self,
+        reqs: Vec<ReadOnlyCall>,
+    ) -> RpcResult<Vec<String>> {
+        // rate limited as `execute_read_only_call`
+        let responses = self.execute_read_only_call(reqs).await?;
+        encode_read_only_responses(&responses)
+    }
+
     async fn remove_staking_addresses(&self, _: Vec<Address>) -> RpcResult<()> {
         crate::wrong_api::<()>()
     }
@@ -321,6 +1108,21 @@ impl MassaRpcServer for API<Public> {
         crate::wrong_api::<PreHashSet<Address>>()
     }
 
+    async fn export_block_graph_dot(&self, _: Option<Slot>, _: Option<Slot>) -> RpcResult<String> {
+        crate::wrong_api::<String>()
+    }
+
+    async fn get_active_staking_addresses(&self) -> RpcResult<PreHashSet<Address>> {
+        let _permit = self.admit_request("get_active_staking_addresses")?;
+        if !self.0.api_settings.expose_staking_addresses {
+            return Err(ApiError::BadRequest(
+                "staking addresses are not exposed by this node".to_string(),
+            )
+            .into());
+        }
+        Ok(self.0.node_wallet.read().get_wallet_address_list())
+    }
+
     async fn node_ban_by_ip(&self, _: Vec<IpAddr>) -> RpcResult<()> {
         crate::wrong_api::<()>()
     }
@@ -337,250 +1139,634 @@ impl MassaRpcServer for API<Public> {
         crate::wrong_api::<()>()
     }
 
+    async fn get_banned_peers(&self) -> RpcResult<Vec<BannedPeerInfo>> {
+        crate::wrong_api::<Vec<BannedPeerInfo>>()
+    }
+
     /// get status
     async fn get_status(&self) -> RpcResult<NodeStatus> {
-        let version = self.0.version;
-        let api_settings = self.0.api_settings.clone();
-        let protocol_config = self.0.protocol_config.clone();
-        let node_id = self.0.node_id;
-        let config = CompactConfig::default();
-        let now = match MassaTime::now() {
-            Ok(now) => now,
-            Err(e) => return Err(ApiError::TimeError(e).into()),
-        };
+        let _permit = self.admit_request("get_status")?;
+        let ttl = self.0.api_settings.status_cache_ttl.to_duration();
+        if ttl.is_zero() {
+            return Ok(self.compute_status().await?);
+        }
+        if let Some((computed_at, status)) = self.0.status_cache.read().await.as_ref() {
+            if computed_at.elapsed() < ttl {
+                return Ok(status.clone());
+            }
+        }
+        let mut cache = self.0.status_cache.write().await;
+        // another caller may have refreshed the status while we were waiting for the lock
+        if let Some((computed_at, status)) = cache.as_ref() {
+            if computed_at.elapsed() < ttl {
+                return Ok(status.clone());
+            }
+        }
+        let status = self.compute_status().await?;
+        *cache = Some((Instant::now(), status.clone()));
+        Ok(status)
+    }
 
-        let last_slot_result = get_latest_block_slot_at_timestamp(
+    /// get the node health from the execution lag only, without querying the other components
+    async fn get_health(&self) -> RpcResult<NodeHealth> {
+        let _permit = self.admit_request("get_health")?;
+        let api_settings = &self.0.api_settings;
+        let cur_slot = timeslots::get_current_latest_block_slot(
             api_settings.thread_count,
             api_settings.t0,
             api_settings.genesis_timestamp,
-            now,
-        );
-        let last_slot = match last_slot_result {
-            Ok(last_slot) => last_slot,
-            Err(e) => return Err(ApiError::ModelsError(e).into()),
-        };
+        )
+        .map_err(ApiError::ModelsError)?;
+        let active_cursor = self.0.execution_controller.get_stats().active_cursor;
+
+        // before genesis, or with the execution ahead of the clock, there is no lag
+        let lag = cur_slot
+            .and_then(|cur_slot| {
+                timeslots::slot_count_in_range(active_cursor, cur_slot, api_settings.thread_count)
+                    .ok()
+            })
+            .unwrap_or(0);
+        let syncing = lag > api_settings.thread_count as u64;
+        Ok(NodeHealth {
+            ready: !syncing,
+            syncing,
+        })
+    }
 
+    /// get the node metrics in the Prometheus text format
+    async fn get_metrics_text(&self) -> RpcResult<String> {
+        let _permit = self.admit_request("get_metrics_text")?;
+        let network_stats = self
+            .0
+            .protocol_controller
+            .get_stats()
+            .map_err(|e| ApiError::ProtocolError(e.to_string()))?
+            .0;
         let execution_stats = self.0.execution_controller.get_stats();
-        let consensus_stats_result = self.0.consensus_controller.get_stats();
-        let consensus_stats = match consensus_stats_result {
-            Ok(consensus_stats) => consensus_stats,
-            Err(e) => return Err(ApiError::ConsensusError(e.to_string()).into()),
-        };
 
-        let (network_stats, peers) = match self.0.protocol_controller.get_stats() {
-            Ok((stats, peers)) => (stats, peers),
-            Err(e) => return Err(ApiError::ProtocolError(e.to_string()).into()),
-        };
+        let mut out = String::new();
+        let gauges: [(&str, &str, u64); 9] = [
+            (
+                "massa_network_in_connections",
+                "Number of incoming peer connections",
+                network_stats.in_connection_count,
+            ),
+            (
+                "massa_network_out_connections",
+                "Number of outgoing peer connections",
+                network_stats.out_connection_count,
+            ),
+            (
+                "massa_network_known_peers",
+                "Number of known peers",
+                network_stats.known_peer_count,
+            ),
+            (
+                "massa_network_banned_peers",
+                "Number of banned peers",
+                network_stats.banned_peer_count,
+            ),
+            (
+                "massa_pool_operations",
+                "Number of operations in the pool",
+                self.0.pool_command_sender.get_operation_count() as u64,
+            ),
+            (
+                "massa_pool_endorsements",
+                "Number of endorsements in the pool",
+                self.0.pool_command_sender.get_endorsement_count() as u64,
+            ),
+            (
+                "massa_execution_final_period",
+                "Period of the last final executed slot",
+                execution_stats.final_cursor.period,
+            ),
+            (
+                "massa_execution_final_thread",
+                "Thread of the last final executed slot",
+                execution_stats.final_cursor.thread as u64,
+            ),
+            (
+                "massa_execution_active_period",
+                "Period of the last candidate executed slot",
+                execution_stats.active_cursor.period,
+            ),
+        ];
+        for (name, help, value) in gauges {
+            write_metric_header(&mut out, name, help, "gauge");
+            out.push_str(&format!("{} {}\n", name, value));
+        }
 
-        let pool_stats = (
-            self.0.pool_command_sender.get_operation_count(),
-            self.0.pool_command_sender.get_endorsement_count(),
+        // snapshot of the counters, this request included
+        let request_counts = self.0.admission.request_counts();
+        write_metric_header(
+            &mut out,
+            "massa_api_requests_total",
+            "Number of requests received by each API method",
+            "counter",
         );
+        for (method, count) in request_counts {
+            out.push_str(&format!(
+                "massa_api_requests_total{{method=\"{}\"}} {}\n",
+                method, count
+            ));
+        }
+        Ok(out)
+    }
 
-        let next_slot_result = last_slot
-            .unwrap_or_else(|| Slot::new(0, 0))
-            .get_next_slot(api_settings.thread_count);
+    /// get the fee and gas limits of the node
+    async fn get_fee_parameters(&self) -> RpcResult<FeeParameters> {
+        let _permit = self.admit_request("get_fee_parameters")?;
+        Ok(FeeParameters {
+            minimal_fee: self.0.api_settings.minimal_fees,
+            max_gas_per_block: self.0.api_settings.max_gas_per_block,
+            max_read_only_gas: self.0.api_settings.max_read_only_gas,
+        })
+    }
 
-        let next_slot = match next_slot_result {
-            Ok(next_slot) => next_slot,
-            Err(e) => return Err(ApiError::ModelsError(e).into()),
-        };
+    /// get the minimal amount of coins creating a ledger entry
+    async fn get_minimal_entry_balance(&self) -> RpcResult<Amount> {
+        let _permit = self.admit_request("get_minimal_entry_balance")?;
+        Ok(self.0.api_settings.ledger_entry_base_cost)
+    }
 
-        let connected_nodes = peers
-            .iter()
-            .map(|(id, peer)| {
-                let is_outgoing = match peer.1 {
-                    PeerConnectionType::IN => false,
-                    PeerConnectionType::OUT => true,
-                };
-                (NodeId::new(id.get_public_key()), (peer.0.ip(), is_outgoing))
-            })
-            .collect::<BTreeMap<_, _>>();
+    /// get the node version and the network versions used in block headers
+    async fn get_version_info(&self) -> RpcResult<VersionInfo> {
+        let _permit = self.admit_request("get_version_info")?;
+        let mip_store = &self.0.keypair_factory.mip_store;
+        Ok(VersionInfo {
+            version: self.0.version,
+            current_network_version: mip_store.get_network_version_current(),
+            announced_network_version: mip_store.get_network_version_to_announce(),
+        })
+    }
 
-        let current_cycle = last_slot
-            .unwrap_or_else(|| Slot::new(0, 0))
-            .get_cycle(api_settings.periods_per_cycle);
+    /// get the size counters of the final ledger
+    async fn get_ledger_stats(&self) -> RpcResult<LedgerStats> {
+        let _permit = self.admit_request("get_ledger_stats")?;
+        Ok(self.0.execution_controller.get_ledger_stats())
+    }
 
-        let cycle_duration = match api_settings.t0.checked_mul(api_settings.periods_per_cycle) {
-            Ok(cycle_duration) => cycle_duration,
-            Err(e) => return Err(ApiError::TimeError(e).into()),
-        };
+    /// get the past consensus stats snapshots and the current stats
+    async fn get_consensus_stats_history(
+        &self,
+        duration: MassaTime,
+    ) -> RpcResult<Vec<ConsensusStats>> {
+        let _permit = self.admit_request("get_consensus_stats_history")?;
+        let mut history = self
+            .0
+            .consensus_controller
+            .get_stats_history(duration)
+            .map_err(|e| ApiError::ConsensusError(e.to_string()))?;
+        // keep the most recent snapshots, the current stats being the last one
+        let max_len = self.0.api_settings.max_arguments.saturating_add(1) as usize;
+        if history.len() > max_len {
+            history.drain(..history.len() - max_len);
+        }
+        Ok(history)
+    }
 
-        let current_cycle_time_result = if current_cycle == 0 {
-            Ok(api_settings.genesis_timestamp)
-        } else {
-            cycle_duration.checked_mul(current_cycle).and_then(
-                |elapsed_time_before_current_cycle| {
-                    api_settings
-                        .genesis_timestamp
-                        .checked_add(elapsed_time_before_current_cycle)
-                },
-            )
-        };
+    /// get the final and active execution cursors
+    async fn get_execution_slots(&self) -> RpcResult<ExecutionSlots> {
+        let _permit = self.admit_request("get_execution_slots")?;
+        let execution_stats = self.0.execution_controller.get_stats();
+        Ok(ExecutionSlots {
+            final_slot: execution_stats.final_cursor,
+            active_slot: execution_stats.active_cursor,
+        })
+    }
 
-        let current_cycle_time = match current_cycle_time_result {
-            Ok(current_cycle_time) => current_cycle_time,
-            Err(e) => return Err(ApiError::TimeError(e).into()),
-        };
+    /// get the final and active execution cursors of each thread
+    async fn get_execution_status(&self) -> RpcResult<Vec<ThreadExecutionSlots>> {
+        let _permit = self.admit_request("get_execution_status")?;
+        let execution_stats = self.0.execution_controller.get_stats();
+        Ok((0..self.0.api_settings.thread_count)
+            .map(|thread| ThreadExecutionSlots {
+                thread,
+                final_slot: last_slot_of_thread(execution_stats.final_cursor, thread),
+                active_slot: last_slot_of_thread(execution_stats.active_cursor, thread),
+            })
+            .collect())
+    }
 
-        let next_cycle_time = match current_cycle_time.checked_add(cycle_duration) {
-            Ok(next_cycle_time) => next_cycle_time,
-            Err(e) => return Err(ApiError::TimeError(e).into()),
-        };
+    /// get the timestamp of a slot
+    async fn get_slot_timestamp(&self, slot: Slot) -> RpcResult<MassaTime> {
+        let _permit = self.admit_request("get_slot_timestamp")?;
+        self.check_slot_thread(slot)?;
+        let api_settings = &self.0.api_settings;
+        timeslots::get_block_slot_timestamp(
+            api_settings.thread_count,
+            api_settings.t0,
+            api_settings.genesis_timestamp,
+            slot,
+        )
+        .map_err(|e| ApiError::ModelsError(e).into())
+    }
 
-        Ok(NodeStatus {
-            node_id,
-            node_ip: protocol_config.routable_ip,
-            version,
-            current_time: now,
-            current_cycle_time,
-            next_cycle_time,
-            connected_nodes,
-            last_slot,
-            next_slot,
-            execution_stats,
-            consensus_stats,
-            network_stats,
-            pool_stats,
-            config,
-            current_cycle,
-        })
+    /// get the latest slot at a timestamp
+    async fn get_timestamp_slot(&self, timestamp: MassaTime) -> RpcResult<Slot> {
+        let _permit = self.admit_request("get_timestamp_slot")?;
+        let api_settings = &self.0.api_settings;
+        get_latest_block_slot_at_timestamp(
+            api_settings.thread_count,
+            api_settings.t0,
+            api_settings.genesis_timestamp,
+            timestamp,
+        )
+        .map_err(ApiError::ModelsError)?
+        .ok_or_else(|| ApiError::BadRequest("timestamp is before genesis".to_string()).into())
     }
 
     /// get cliques
     async fn get_cliques(&self) -> RpcResult<Vec<Clique>> {
+        let _permit = self.admit_request("get_cliques")?;
         Ok(self.0.consensus_controller.get_cliques())
     }
 
+    /// get the blocks competing at a slot
+    async fn get_slot_fork_choice(&self, slot: Slot) -> RpcResult<Vec<SlotForkChoiceBlock>> {
+        let _permit = self.admit_request("get_slot_fork_choice")?;
+        let next_slot = slot
+            .get_next_slot(self.0.api_settings.thread_count)
+            .map_err(ApiError::ModelsError)?;
+
+        // the cliques come with the graph extract so that memberships are consistent with it
+        let graph = self
+            .0
+            .consensus_controller
+            .get_block_graph_status(Some(slot), Some(next_slot))
+            .map_err(|e| ApiError::ConsensusError(e.to_string()))?;
+
+        let mut res: Vec<SlotForkChoiceBlock> = graph
+            .active_blocks
+            .into_iter()
+            .map(|(id, exported_block)| {
+                let cliques: Vec<CliqueMembership> = graph
+                    .max_cliques
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, clique)| clique.block_ids.contains(&id))
+                    .map(|(index, clique)| CliqueMembership {
+                        index,
+                        fitness: clique.fitness,
+                        is_blockclique: clique.is_blockclique,
+                    })
+                    .collect();
+                // final blocks are removed from the cliques: a final block is the chosen one
+                SlotForkChoiceBlock {
+                    id,
+                    creator: exported_block.header.content_creator_address,
+                    fitness: exported_block.header.get_fitness(),
+                    is_final: exported_block.is_final,
+                    is_in_blockclique: exported_block.is_final
+                        || cliques.iter().any(|clique| clique.is_blockclique),
+                    cliques,
+                }
+            })
+            .collect();
+        // chosen block first, then by decreasing best clique fitness
+        res.sort_by_key(|block| {
+            (
+                std::cmp::Reverse(block.is_in_blockclique),
+                std::cmp::Reverse(block.cliques.iter().map(|c| c.fitness).max()),
+                block.id,
+            )
+        });
+        Ok(res)
+    }
+
+    /// get the maximal cliques with the summaries of their blocks
+    async fn get_graph_cliques_detailed(
+        &self,
+        start_slot: Option<Slot>,
+        end_slot: Option<Slot>,
+    ) -> RpcResult<Vec<DetailedClique>> {
+        let _permit = self.admit_request("get_graph_cliques_detailed")?;
+        let start_slot = self.bound_graph_range(start_slot, end_slot)?;
+
+        // cliques and blocks come from the same graph extract so that they are consistent
+        let graph = self
+            .0
+            .consensus_controller
+            .get_block_graph_status(start_slot, end_slot)
+            .map_err(|e| ApiError::ConsensusError(e.to_string()))?;
+        let blockclique = graph
+            .max_cliques
+            .iter()
+            .find(|clique| clique.is_blockclique);
+
+        Ok(graph
+            .max_cliques
+            .iter()
+            .enumerate()
+            .map(|(index, clique)| {
+                let mut blocks: Vec<BlockSummary> = graph
+                    .active_blocks
+                    .iter()
+                    .filter(|(id, _)| clique.block_ids.contains(id))
+                    .map(|(id, exported_block)| BlockSummary {
+                        id: *id,
+                        is_final: exported_block.is_final,
+                        is_stale: false,
+                        is_in_blockclique: blockclique
+                            .map_or(false, |blockclique| blockclique.block_ids.contains(id)),
+                        slot: exported_block.header.content.slot,
+                        creator: exported_block.header.content_creator_address,
+                        parents: exported_block.header.content.parents.clone(),
+                        current_version: Some(exported_block.header.content.current_version),
+                        announced_version: exported_block.header.content.announced_version,
+                    })
+                    .collect();
+                blocks.sort_by_key(|block| (block.slot, block.id));
+                DetailedClique {
+                    index,
+                    fitness: clique.fitness,
+                    is_blockclique: clique.is_blockclique,
+                    blocks,
+                }
+            })
+            .collect())
+    }
+
     /// get stakers
     async fn get_stakers(
         &self,
         page_request: Option<PageRequest>,
     ) -> RpcResult<PagedVec<(Address, u64)>> {
-        let cfg = self.0.api_settings.clone();
+        let _permit = self.admit_request("get_stakers")?;
+        let curr_cycle = self.current_cycle()?;
 
-        let now = match MassaTime::now() {
-            Ok(now) => now,
-            Err(e) => return Err(ApiError::TimeError(e).into()),
-        };
+        let mut staker_vec = self
+            .0
+            .execution_controller
+            .get_cycle_active_rolls(curr_cycle)
+            .into_iter()
+            .collect::<Vec<(Address, u64)>>();
 
-        let latest_block_slot_at_timestamp_result = get_latest_block_slot_at_timestamp(
-            cfg.thread_count,
-            cfg.t0,
-            cfg.genesis_timestamp,
-            now,
-        );
+        staker_vec
+            .sort_by(|&(_, roll_counts_a), &(_, roll_counts_b)| roll_counts_b.cmp(&roll_counts_a));
 
-        let curr_cycle = match latest_block_slot_at_timestamp_result {
-            Ok(Some(cur_slot)) if cur_slot.period <= self.0.api_settings.last_start_period => {
-                Slot::new(self.0.api_settings.last_start_period, 0).get_cycle(cfg.periods_per_cycle)
-            }
-            Ok(Some(cur_slot)) => cur_slot.get_cycle(cfg.periods_per_cycle),
-            Ok(None) => 0,
-            Err(e) => return Err(ApiError::ModelsError(e).into()),
+        let paged_vec = PagedVec::new(staker_vec, page_request);
+
+        Ok(paged_vec)
+    }
+
+    async fn get_total_active_rolls(&self, cycle: Option<u64>) -> RpcResult<u64> {
+        let _permit = self.admit_request("get_total_active_rolls")?;
+        let cycle = match cycle {
+            Some(cycle) => cycle,
+            None => self.current_cycle()?,
         };
 
-        let mut staker_vec = self
-            .0
-            .execution_controller
-            .get_cycle_active_rolls(curr_cycle)
-            .into_iter()
-            .collect::<Vec<(Address, u64)>>();
+        Ok(self
+            .0
+            .execution_controller
+            .get_cycle_active_rolls(cycle)
+            .values()
+            .fold(0u64, |total, rolls| total.saturating_add(*rolls)))
+    }
+
+    async fn get_multi_cycle_rolls(
+        &self,
+        address: Address,
+        cycles: Vec<u64>,
+    ) -> RpcResult<Vec<(u64, u64)>> {
+        let _permit = self.admit_request("get_multi_cycle_rolls")?;
+        if cycles.len() as u64 > self.0.api_settings.max_arguments {
+            return Err(ApiError::BadRequest("too many arguments".into()).into());
+        }
+        if cycles.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // the cycle infos of the address cover the whole retained cycle history
+        let cycle_infos = self
+            .0
+            .execution_controller
+            .get_addresses_infos(&[address])
+            .pop()
+            .map(|info| info.cycle_infos)
+            .unwrap_or_default();
+
+        cycles
+            .into_iter()
+            .map(|cycle| {
+                match cycle_infos.iter().find(|info| info.cycle == cycle) {
+                    // no active rolls recorded means the address had none
+                    Some(info) => Ok((cycle, info.active_rolls.unwrap_or(0))),
+                    None => Err(ApiError::BadRequest(format!(
+                        "cycle {} is not in the retained history",
+                        cycle
+                    ))
+                    .into()),
+                }
+            })
+            .collect()
+    }
+
+    async fn get_cycle_draw_seed(&self, cycle: u64) -> RpcResult<Vec<u8>> {
+        let _permit = self.admit_request("get_cycle_draw_seed")?;
+        match self.0.selector_controller.get_cycle_draw_seed(cycle) {
+            Ok(seed) => Ok(seed.to_bytes().to_vec()),
+            Err(PosError::CycleUnavailable(cycle)) => Err(ApiError::BadRequest(format!(
+                "draws of cycle {} are not in the retained history",
+                cycle
+            ))
+            .into()),
+            Err(e) => Err(ApiError::InternalServerError(e.to_string()).into()),
+        }
+    }
+
+    /// get the endorsers drawn for a slot
+    async fn get_endorsement_draws(&self, slot: Slot) -> RpcResult<Vec<(u64, Address)>> {
+        let _permit = self.admit_request("get_endorsement_draws")?;
+        self.check_slot_thread(slot)?;
+        match self.0.selector_controller.get_selection(slot) {
+            Ok(selection) => Ok(selection
+                .endorsements
+                .into_iter()
+                .enumerate()
+                .map(|(index, address)| (index as u64, address))
+                .collect()),
+            Err(e) => Err(self.draw_error(e).into()),
+        }
+    }
 
-        staker_vec
-            .sort_by(|&(_, roll_counts_a), &(_, roll_counts_b)| roll_counts_b.cmp(&roll_counts_a));
+    /// get the address drawn to produce the block of a slot
+    async fn get_block_producer(&self, slot: Slot) -> RpcResult<Address> {
+        let _permit = self.admit_request("get_block_producer")?;
+        self.check_slot_thread(slot)?;
+        self.0
+            .selector_controller
+            .get_producer(slot)
+            .map_err(|e| self.draw_error(e).into())
+    }
 
-        let paged_vec = PagedVec::new(staker_vec, page_request);
+    /// find retained operations by ID prefix
+    async fn find_operations_by_prefix(
+        &self,
+        prefix: String,
+        limit: u64,
+    ) -> RpcResult<Vec<OperationId>> {
+        let _permit = self.admit_request("find_operations_by_prefix")?;
+        if prefix.is_empty() {
+            return Err(ApiError::BadRequest("empty prefix".into()).into());
+        }
+        let limit = std::cmp::min(limit, self.0.api_settings.max_arguments) as usize;
 
-        Ok(paged_vec)
+        // the operations held in storage are sorted by ID string, only the matching ones are visited
+        Ok(self
+            .0
+            .storage
+            .read_operations()
+            .get_operations_by_id_string_prefix(&prefix)
+            .take(limit)
+            .copied()
+            .collect())
     }
 
     /// get operations
     async fn get_operations(
         &self,
         operations_ids: Vec<OperationId>,
+        only_final: Option<bool>,
     ) -> RpcResult<Vec<OperationInfo>> {
-        // get the operations and the list of blocks that contain them from storage
-        let secure_share_operations: Vec<SecureShareOperation> = {
-            let read_ops = self.0.storage.read_operations();
-            operations_ids
-                .iter()
-                .filter_map(|id| read_ops.get(id).cloned())
-                .collect()
-        };
+        let _permit = self.admit_request("get_operations")?;
+        Ok(self.get_operations_info(operations_ids, only_final.unwrap_or(false))?)
+    }
 
-        let storage_info: Vec<(SecureShareOperation, PreHashSet<BlockId>)> = {
+    /// get a single operation
+    async fn get_operation(&self, operation_id: OperationId) -> RpcResult<Option<OperationInfo>> {
+        let _permit = self.admit_request("get_operation")?;
+        Ok(self.get_operations_info(vec![operation_id], false)?.pop())
+    }
+
+    /// get the blocks including each operation, with their finality
+    async fn get_operation_inclusions(
+        &self,
+        operation_ids: Vec<OperationId>,
+    ) -> RpcResult<PreHashMap<OperationId, Vec<(BlockId, bool)>>> {
+        let _permit = self.admit_request("get_operation_inclusions")?;
+        if operation_ids.len() as u64 > self.0.api_settings.max_arguments {
+            return Err(ApiError::BadRequest("too many arguments".into()).into());
+        }
+
+        // blocks known to include each operation, operations in no block get an empty list
+        let inclusions: PreHashMap<OperationId, Vec<BlockId>> = {
             let read_blocks = self.0.storage.read_blocks();
-            secure_share_operations
+            operation_ids
                 .into_iter()
-                .map(|secure_share_operation| {
-                    let op_id = secure_share_operation.id;
-                    (
-                        secure_share_operation,
-                        read_blocks
-                            .get_blocks_by_operation(&op_id)
-                            .cloned()
-                            .unwrap_or_default(),
-                    )
+                .map(|op_id| {
+                    let block_ids = read_blocks
+                        .get_blocks_by_operation(&op_id)
+                        .map(|ids| ids.iter().copied().collect())
+                        .unwrap_or_default();
+                    (op_id, block_ids)
                 })
                 .collect()
         };
 
-        // keep only the ops id (found in storage)
-        let ops: Vec<OperationId> = storage_info.iter().map(|(op, _)| op.id).collect();
+        let involved_blocks: Vec<BlockId> =
+            inclusions.values().flatten().unique().copied().collect();
+        let block_statuses: PreHashMap<BlockId, BlockGraphStatus> = involved_blocks
+            .iter()
+            .copied()
+            .zip(
+                self.0
+                    .consensus_controller
+                    .get_block_statuses(&involved_blocks),
+            )
+            .collect();
 
-        let api_cfg = self.0.api_settings.clone();
-        if ops.len() as u64 > api_cfg.max_arguments {
+        // only keep the final and candidate blocks
+        Ok(inclusions
+            .into_iter()
+            .map(|(op_id, block_ids)| {
+                let blocks = block_ids
+                    .into_iter()
+                    .filter_map(|block_id| match block_statuses.get(&block_id) {
+                        Some(BlockGraphStatus::Final) => Some((block_id, true)),
+                        Some(
+                            BlockGraphStatus::ActiveInBlockclique
+                            | BlockGraphStatus::ActiveInAlternativeCliques,
+                        ) => Some((block_id, false)),
+                        _ => None,
+                    })
+                    .collect();
+                (op_id, blocks)
+            })
+            .collect())
+    }
+
+    /// only ask the pool, without building the full operation infos
+    async fn is_operation_in_pool(&self, ops: Vec<OperationId>) -> RpcResult<Vec<bool>> {
+        let _permit = self.admit_request("is_operation_in_pool")?;
+        if ops.len() as u64 > self.0.api_settings.max_arguments {
             return Err(ApiError::BadRequest("too many arguments".into()).into());
         }
+        if ops.is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(self.0.pool_command_sender.contains_operations(&ops))
+    }
 
-        // ask pool whether it carries the operations
-        let in_pool = self.0.pool_command_sender.contains_operations(&ops);
+    async fn get_pool_operation_ids(&self, limit: u64) -> RpcResult<Vec<OperationId>> {
+        let _permit = self.admit_request("get_pool_operation_ids")?;
+        if !self.0.api_settings.expose_pool_operations {
+            return Err(ApiError::BadRequest(
+                "pool operations are not exposed by this node".to_string(),
+            )
+            .into());
+        }
+        let limit = limit.min(self.0.api_settings.max_arguments);
+        Ok(self.0.pool_command_sender.get_operation_ids(limit as usize))
+    }
 
-        let op_exec_statuses = self.0.execution_controller.get_ops_exec_status(&ops);
+    /// get the denunciations of the pool that are not executed yet
+    async fn get_pending_denunciations(&self) -> RpcResult<Vec<PendingDenunciation>> {
+        let _permit = self.admit_request("get_pending_denunciations")?;
+        Ok(self
+            .0
+            .pool_command_sender
+            .get_pending_denunciations(self.0.api_settings.max_arguments as usize)
+            .into_iter()
+            .map(|denunciation| PendingDenunciation {
+                address: Address::from_public_key(denunciation.get_public_key()),
+                slot: *denunciation.get_slot(),
+                index: denunciation.get_index().copied(),
+            })
+            .collect())
+    }
 
-        // compute operation finality and operation execution status from *_op_exec_statuses
-        let (is_operation_final, statuses): (Vec<Option<bool>>, Vec<Option<bool>>) =
-            op_exec_statuses
-                .into_iter()
-                .map(|(spec_exec, final_exec)| match (spec_exec, final_exec) {
-                    (Some(true), Some(true)) => (Some(true), Some(true)),
-                    (Some(false), Some(false)) => (Some(true), Some(false)),
-                    (Some(true), None) => (Some(false), Some(true)),
-                    (Some(false), None) => (Some(false), Some(false)),
-                    _ => (None, None),
-                })
-                .collect::<Vec<(Option<bool>, Option<bool>)>>()
-                .into_iter()
-                .unzip();
+    /// get the inclusion proof of an operation in a block
+    async fn get_operation_proof(
+        &self,
+        block_id: BlockId,
+        operation_id: OperationId,
+    ) -> RpcResult<Option<OperationInclusionProof>> {
+        let _permit = self.admit_request("get_operation_proof")?;
+        let read_blocks = self.0.storage.read_blocks();
+        let block = match read_blocks.get(&block_id) {
+            Some(block) => block,
+            None => return Ok(None),
+        };
 
-        // gather all values into a vector of OperationInfo instances
-        let mut res: Vec<OperationInfo> = Vec::with_capacity(ops.len());
-        let zipped_iterator = izip!(
-            ops.into_iter(),
-            storage_info.into_iter(),
-            in_pool.into_iter(),
-            is_operation_final.into_iter(),
-            statuses.into_iter(),
-        );
-        for (id, (operation, in_blocks), in_pool, is_operation_final, op_exec_status) in
-            zipped_iterator
+        let index = match block
+            .content
+            .operations
+            .iter()
+            .position(|id| id == &operation_id)
         {
-            res.push(OperationInfo {
-                id,
-                in_pool,
-                is_operation_final,
-                thread: operation
-                    .content_creator_address
-                    .get_thread(api_cfg.thread_count),
-                operation,
-                in_blocks: in_blocks.into_iter().collect(),
-                op_exec_status,
-            });
-        }
+            Some(index) => index,
+            None => return Ok(None),
+        };
 
-        // return values in the right order
-        Ok(res)
+        Ok(Some(OperationInclusionProof {
+            block_id,
+            header: block.content.header.clone(),
+            index,
+            operation_ids: block.content.operations.clone(),
+        }))
     }
 
     /// get endorsements
@@ -588,6 +1774,7 @@ impl MassaRpcServer for API<Public> {
         &self,
         mut endorsement_ids: Vec<EndorsementId>,
     ) -> RpcResult<Vec<EndorsementInfo>> {
+        let _permit = self.admit_request("get_endorsements")?;
         if endorsement_ids.len() as u64 > self.0.api_settings.max_arguments {
             return Err(ApiError::BadRequest("too many arguments".into()).into());
         }
@@ -679,39 +1866,68 @@ impl MassaRpcServer for API<Public> {
 
     /// get blocks
     /// Returns only active blocks are returned
-    async fn get_blocks(&self, mut ids: Vec<BlockId>) -> RpcResult<Vec<BlockInfo>> {
-        let mut blocks: Vec<Block> = Vec::with_capacity(ids.len());
+    async fn get_blocks(&self, ids: Vec<BlockId>) -> RpcResult<Vec<BlockInfo>> {
+        let _permit = self.admit_request("get_blocks")?;
+        Ok(self.get_block_infos(ids))
+    }
+
+    /// get the header of a block, without transferring its operations
+    async fn get_block_header(&self, id: BlockId) -> RpcResult<Option<SecuredHeader>> {
+        let _permit = self.admit_request("get_block_header")?;
+        Ok(self
+            .0
+            .storage
+            .read_blocks()
+            .get(&id)
+            .map(|block| block.content.header.clone()))
+    }
+
+    /// get the blockclique block at a given slot
+    async fn get_block_at_slot(&self, slot: Slot) -> RpcResult<Option<BlockInfo>> {
+        let _permit = self.admit_request("get_block_at_slot")?;
+        self.check_slot_thread(slot)?;
+
+        let block_id = match self
+            .0
+            .consensus_controller
+            .get_blockclique_block_at_slot(slot)
         {
-            let block_storage_lock = self.0.storage.read_blocks();
-            ids.retain(|id| {
-                if let Some(wrapped_block) = block_storage_lock.get(id) {
-                    blocks.push(wrapped_block.content.clone());
-                    return true;
-                };
-                false
-            });
-        }
-        let block_statuses = self.0.consensus_controller.get_block_statuses(&ids);
-        let res = ids
-            .into_iter()
-            .zip(blocks)
-            .zip(block_statuses)
-            .map(|((id, content), graph_status)| BlockInfo {
-                id,
-                content: Some(BlockInfoContent {
-                    is_final: graph_status == BlockGraphStatus::Final,
-                    is_in_blockclique: graph_status == BlockGraphStatus::ActiveInBlockclique,
-                    is_candidate: graph_status == BlockGraphStatus::ActiveInBlockclique
-                        || graph_status == BlockGraphStatus::ActiveInAlternativeCliques,
-                    is_discarded: graph_status == BlockGraphStatus::Discarded,
-                    block: content,
-                }),
+            Some(id) => id,
+            None => return Ok(None),
+        };
+
+        Ok(self.get_block_infos(vec![block_id]).pop())
+    }
+
+    async fn get_blocks_at_slot(&self, slot: Slot) -> RpcResult<SlotBlocks> {
+        let _permit = self.admit_request("get_blocks_at_slot")?;
+        self.check_slot_thread(slot)?;
+
+        // final blocks are removed from the cliques, so consensus falls back to the final block of the slot
+        // when the blockclique has none. A blockclique block is compatible with the final blocks and cannot
+        // share their slot: if the slot has a final block, it is the one returned.
+        let candidate_block = self
+            .0
+            .consensus_controller
+            .get_blockclique_block_at_slot(slot)
+            .and_then(|block_id| self.get_block_infos(vec![block_id]).pop());
+        let final_block = candidate_block
+            .as_ref()
+            .filter(|block| {
+                block
+                    .content
+                    .as_ref()
+                    .map_or(false, |content| content.is_final)
             })
-            .collect();
-        Ok(res)
+            .cloned();
+        Ok(SlotBlocks {
+            final_block,
+            candidate_block,
+        })
     }
 
     async fn get_blockclique_block_by_slot(&self, slot: Slot) -> RpcResult<Option<Block>> {
+        let _permit = self.admit_request("get_blockclique_block_by_slot")?;
         let block_id_option = self
             .0
             .consensus_controller
@@ -733,7 +1949,13 @@ impl MassaRpcServer for API<Public> {
 
     /// gets an interval of the block graph from consensus, with time filtering
     /// time filtering is done consensus-side to prevent communication overhead
-    async fn get_graph_interval(&self, time: TimeInterval) -> RpcResult<Vec<BlockSummary>> {
+    async fn get_graph_interval(
+        &self,
+        time: TimeInterval,
+        only_blockclique: Option<bool>,
+    ) -> RpcResult<Vec<BlockSummary>> {
+        let _permit = self.admit_request("get_graph_interval")?;
+        let only_blockclique = only_blockclique.unwrap_or(false);
         let api_settings = self.0.api_settings.clone();
 
         // filter blocks from graph_export
@@ -750,34 +1972,55 @@ impl MassaRpcServer for API<Public> {
             Err(e) => return Err(ApiError::ModelsError(e).into()),
         };
 
-        let graph = match self
-            .0
-            .consensus_controller
-            .get_block_graph_status(start_slot, end_slot)
-        {
-            Ok(graph) => graph,
-            Err(e) => return Err(ApiError::ConsensusError(e.to_string()).into()),
+        let start_slot = self.bound_graph_range(start_slot, end_slot)?;
+
+        // the blockclique can briefly be missing while consensus is switching cliques:
+        // fetch the graph again once before reporting a transient error
+        let mut attempts_left = 2;
+        let (graph, blockclique_index) = loop {
+            let graph = match self
+                .0
+                .consensus_controller
+                .get_block_graph_status(start_slot, end_slot)
+            {
+                Ok(graph) => graph,
+                Err(e) => return Err(ApiError::ConsensusError(e.to_string()).into()),
+            };
+            if let Some(index) = graph
+                .max_cliques
+                .iter()
+                .position(|clique| clique.is_blockclique)
+            {
+                break (graph, index);
+            }
+            attempts_left -= 1;
+            if attempts_left == 0 {
+                return Err(ApiError::ServiceUnavailable("missing blockclique".to_string()).into());
+            }
         };
 
         let mut res = Vec::with_capacity(graph.active_blocks.len());
-        let blockclique = graph
-            .max_cliques
-            .iter()
-            .find(|clique| clique.is_blockclique)
-            .ok_or_else(|| ApiError::InconsistencyError("missing blockclique".to_string()))?;
+        let blockclique = &graph.max_cliques[blockclique_index];
         for (id, exported_block) in graph.active_blocks.into_iter() {
+            let is_in_blockclique = blockclique.block_ids.contains(&id);
+            // final blocks are removed from the cliques but belong to the canonical chain
+            if only_blockclique && !exported_block.is_final && !is_in_blockclique {
+                continue;
+            }
             res.push(BlockSummary {
                 id,
                 is_final: exported_block.is_final,
                 is_stale: false,
-                is_in_blockclique: blockclique.block_ids.contains(&id),
+                is_in_blockclique,
                 slot: exported_block.header.content.slot,
                 creator: exported_block.header.content_creator_address,
+                current_version: Some(exported_block.header.content.current_version),
+                announced_version: exported_block.header.content.announced_version,
                 parents: exported_block.header.content.parents,
             });
         }
         for (id, (reason, (slot, creator, parents))) in graph.discarded_blocks.into_iter() {
-            if reason == DiscardReason::Stale {
+            if reason == DiscardReason::Stale && !only_blockclique {
                 res.push(BlockSummary {
                     id,
                     is_final: false,
@@ -786,28 +2029,124 @@ impl MassaRpcServer for API<Public> {
                     slot,
                     creator,
                     parents,
+                    current_version: None,
+                    announced_version: None,
                 });
             }
         }
         Ok(res)
     }
 
+    async fn get_recent_stale_blocks(&self, limit: u64) -> RpcResult<Vec<BlockSummary>> {
+        let _permit = self.admit_request("get_recent_stale_blocks")?;
+        let limit = std::cmp::min(limit, self.0.api_settings.max_arguments);
+        Ok(self
+            .0
+            .consensus_controller
+            .get_recent_stale_blocks(limit as usize)
+            .into_iter()
+            .map(|(id, (slot, creator, parents))| BlockSummary {
+                id,
+                is_final: false,
+                is_stale: true,
+                is_in_blockclique: false,
+                slot,
+                creator,
+                parents,
+                current_version: None,
+                announced_version: None,
+            })
+            .collect())
+    }
+
+    async fn get_recent_block_intervals(&self, count: u64) -> RpcResult<Vec<BlockTimestamp>> {
+        let _permit = self.admit_request("get_recent_block_intervals")?;
+        let api_settings = &self.0.api_settings;
+        let count = std::cmp::min(count, api_settings.max_arguments);
+        self.0
+            .consensus_controller
+            .get_recent_blockclique_blocks(count as usize)
+            .into_iter()
+            .map(|(id, slot, received_at)| {
+                let timestamp = timeslots::get_block_slot_timestamp(
+                    api_settings.thread_count,
+                    api_settings.t0,
+                    api_settings.genesis_timestamp,
+                    slot,
+                )
+                .map_err(ApiError::ModelsError)?;
+                Ok(BlockTimestamp {
+                    id,
+                    slot,
+                    timestamp,
+                    received_at,
+                })
+            })
+            .collect()
+    }
+
     /// get datastore entries
     async fn get_datastore_entries(
         &self,
         entries: Vec<DatastoreEntryInput>,
     ) -> RpcResult<Vec<DatastoreEntryOutput>> {
-        Ok(self
+        let _permit = self.admit_request("get_datastore_entries")?;
+
+        let max_arguments = self.0.api_settings.max_arguments;
+        if entries.len() as u64 > max_arguments {
+            return Err(ApiError::BadRequest(format!(
+                "too many datastore entries requested: {}, max is {}",
+                entries.len(),
+                max_arguments
+            ))
+            .into());
+        }
+
+        // an empty key is expanded to the final and candidate keys of the address, in order,
+        // within what is left of max_arguments once the explicit keys are counted
+        let all_entries_addresses: Vec<Address> = entries
+            .iter()
+            .filter(|input| input.key.is_empty())
+            .map(|input| input.address)
+            .collect();
+        let max_listed_keys =
+            max_arguments as usize - (entries.len() - all_entries_addresses.len());
+        let mut listed_keys = if all_entries_addresses.is_empty() {
+            Vec::new()
+        } else {
+            self.0
+                .execution_controller
+                .get_bounded_datastore_keys(&all_entries_addresses, max_listed_keys)
+                .ok_or_else(|| {
+                    ApiError::BadRequest(format!(
+                        "too many datastore entries requested, max is {}",
+                        max_arguments
+                    ))
+                })?
+        }
+        .into_iter();
+        let mut queries: Vec<(Address, Vec<u8>)> = Vec::with_capacity(entries.len());
+        for input in entries {
+            if input.key.is_empty() {
+                let keys = listed_keys.next().ok_or_else(|| {
+                    ApiError::ServiceUnavailable("execution info not available".to_string())
+                })?;
+                queries.extend(keys.into_iter().map(|key| (input.address, key)));
+            } else {
+                queries.push((input.address, input.key));
+            }
+        }
+
+        let values = self
             .0
             .execution_controller
-            .get_final_and_active_data_entry(
-                entries
-                    .into_iter()
-                    .map(|input| (input.address, input.key))
-                    .collect::<Vec<_>>(),
-            )
+            .get_final_and_active_data_entry(queries.clone());
+        Ok(queries
             .into_iter()
-            .map(|output| DatastoreEntryOutput {
+            .zip(values)
+            .map(|((address, key), output)| DatastoreEntryOutput {
+                address,
+                key,
                 final_value: output.0,
                 candidate_value: output.1,
             })
@@ -815,7 +2154,22 @@ impl MassaRpcServer for API<Public> {
     }
 
     /// get addresses
-    async fn get_addresses(&self, addresses: Vec<Address>) -> RpcResult<Vec<AddressInfo>> {
+    async fn get_addresses(
+        &self,
+        addresses: Vec<Address>,
+        label: Option<String>,
+        best_effort: Option<bool>,
+    ) -> RpcResult<Vec<AddressInfo>> {
+        let _permit = self.admit_request("get_addresses")?;
+        // Tag the logs of the request with its label, if any
+        let span = info_span!("get_addresses", label = label.as_deref());
+        let _enter = span.enter();
+        debug!("getting the info of {} addresses", addresses.len());
+
+        // in best-effort mode, failing sub-queries are reported here instead of failing the request
+        let best_effort = best_effort.unwrap_or(false);
+        let mut unavailable: BTreeMap<String, String> = BTreeMap::new();
+
         // get info from storage about which blocks the addresses have created
         let created_blocks: Vec<PreHashSet<BlockId>> = {
             let lck = self.0.storage.read_blocks();
@@ -842,6 +2196,29 @@ impl MassaRpcServer for API<Public> {
                 .collect()
         };
 
+        // ask the pool which of the created operations are still waiting for inclusion
+        let pool_operations: Vec<Vec<OperationId>> = {
+            let op_ids: Vec<OperationId> = created_operations
+                .iter()
+                .flat_map(|ops| ops.iter().copied())
+                .collect();
+            let mut in_pool = if op_ids.is_empty() {
+                Vec::new()
+            } else {
+                self.0.pool_command_sender.contains_operations(&op_ids)
+            }
+            .into_iter();
+            created_operations
+                .iter()
+                .map(|ops| {
+                    ops.iter()
+                        .filter(|_| in_pool.next().unwrap_or(false))
+                        .copied()
+                        .collect()
+                })
+                .collect()
+        };
+
         // get info from storage about which endorsements the addresses have created
         let created_endorsements: Vec<PreHashSet<EndorsementId>> = {
             let lck = self.0.storage.read_endorsements();
@@ -856,31 +2233,55 @@ impl MassaRpcServer for API<Public> {
         };
 
         // get execution info
-        let execution_infos = self.0.execution_controller.get_addresses_infos(&addresses);
+        let mut execution_infos = self.0.execution_controller.get_addresses_infos(&addresses);
+        let execution_available = execution_infos.len() == addresses.len();
+        if !execution_available {
+            // the execution module may not be ready yet: fail instead of returning truncated results
+            let error = ApiError::ServiceUnavailable(format!(
+                "execution returned info for {} out of {} addresses",
+                execution_infos.len(),
+                addresses.len()
+            ));
+            if !best_effort {
+                return Err(error.into());
+            }
+            unavailable.insert("execution".to_string(), error.to_string());
+            execution_infos = addresses
+                .iter()
+                .map(|_| ExecutionAddressInfo {
+                    candidate_balance: Amount::default(),
+                    final_balance: Amount::default(),
+                    final_roll_count: 0,
+                    final_datastore_keys: Default::default(),
+                    candidate_roll_count: 0,
+                    candidate_datastore_keys: Default::default(),
+                    has_bytecode: false,
+                    future_deferred_credits: Default::default(),
+                    cycle_infos: Vec::new(),
+                    roll_schedule: Vec::new(),
+                })
+                .collect();
+        }
 
         // get future draws from selector
         let selection_draws = {
-            let cur_slot = timeslots::get_current_latest_block_slot(
-                self.0.api_settings.thread_count,
-                self.0.api_settings.t0,
-                self.0.api_settings.genesis_timestamp,
-            )
-            .expect("could not get latest current slot")
-            .unwrap_or_else(|| Slot::new(0, 0));
-            let slot_end = Slot::new(
-                cur_slot
-                    .period
-                    .saturating_add(self.0.api_settings.draw_lookahead_period_count),
-                cur_slot.thread,
-            );
-            let selections = self
-                .0
-                .selector_controller
-                .get_available_selections_in_range(
-                    cur_slot..=slot_end,
-                    Some(&addresses.iter().copied().collect()),
-                )
-                .unwrap_or_default();
+            let selections = self.draw_lookahead_range().and_then(|range| {
+                self.0
+                    .selector_controller
+                    .get_available_selections_in_range(
+                        range,
+                        Some(&addresses.iter().copied().collect()),
+                    )
+                    .map_err(|e| self.draw_error(e))
+            });
+            let selections = match selections {
+                Ok(selections) => selections,
+                Err(e) if best_effort => {
+                    unavailable.insert("selector".to_string(), e.to_string());
+                    Default::default()
+                }
+                Err(e) => return Err(e.into()),
+            };
 
             addresses
                 .iter()
@@ -911,6 +2312,7 @@ impl MassaRpcServer for API<Public> {
             addresses.into_iter(),
             created_blocks.into_iter(),
             created_operations.into_iter(),
+            pool_operations.into_iter(),
             created_endorsements.into_iter(),
             execution_infos.into_iter(),
             selection_draws.into_iter(),
@@ -919,6 +2321,7 @@ impl MassaRpcServer for API<Public> {
             address,
             created_blocks,
             created_operations,
+            pool_operations,
             created_endorsements,
             execution_infos,
             (next_block_draws, next_endorsement_draws),
@@ -928,18 +2331,20 @@ impl MassaRpcServer for API<Public> {
                 // general address info
                 address,
                 thread: address.get_thread(self.0.api_settings.thread_count),
+                is_contract: execution_infos.has_bytecode,
 
                 // final execution info
-                final_balance: execution_infos.final_balance,
-                final_roll_count: execution_infos.final_roll_count,
+                final_balance: execution_available.then_some(execution_infos.final_balance),
+                final_roll_count: execution_available.then_some(execution_infos.final_roll_count),
                 final_datastore_keys: execution_infos
                     .final_datastore_keys
                     .into_iter()
                     .collect::<Vec<_>>(),
 
                 // candidate execution info
-                candidate_balance: execution_infos.candidate_balance,
-                candidate_roll_count: execution_infos.candidate_roll_count,
+                candidate_balance: execution_available.then_some(execution_infos.candidate_balance),
+                candidate_roll_count: execution_available
+                    .then_some(execution_infos.candidate_roll_count),
                 candidate_datastore_keys: execution_infos
                     .candidate_datastore_keys
                     .into_iter()
@@ -961,16 +2366,75 @@ impl MassaRpcServer for API<Public> {
                 created_endorsements: created_endorsements.into_iter().collect::<Vec<_>>(),
                 created_operations: created_operations.into_iter().collect::<Vec<_>>(),
 
-                // cycle infos
-                cycle_infos: execution_infos.cycle_infos,
-            });
+                // operations waiting in the pool
+                pool_operations,
+
+                // cycle infos
+                cycle_infos: execution_infos.cycle_infos,
+
+                // scheduled roll changes
+                roll_schedule: execution_infos.roll_schedule,
+
+                // sub-queries that failed in best-effort mode
+                unavailable: unavailable.clone(),
+
+                // label of the request
+                label: label.clone(),
+            });
+        }
+
+        Ok(res)
+    }
+
+    /// get the soonest slot at which the address is drawn to produce a block
+    async fn get_next_block_draw(&self, address: Address) -> RpcResult<Option<Slot>> {
+        let _permit = self.admit_request("get_next_block_draw")?;
+        let selections = self
+            .0
+            .selector_controller
+            .get_available_selections_in_range(
+                self.draw_lookahead_range()?,
+                Some(&PreHashSet::from_iter([address])),
+            )
+            .map_err(|e| self.draw_error(e))?;
+
+        // selections are sorted by slot, and also contain the slots where the address only endorses
+        Ok(selections
+            .into_iter()
+            .find(|(_slot, selection)| selection.producer == address)
+            .map(|(slot, _selection)| slot))
+    }
+
+    /// get the thread of each address
+    async fn get_address_threads(&self, addresses: Vec<Address>) -> RpcResult<Vec<u8>> {
+        let _permit = self.admit_request("get_address_threads")?;
+        if addresses.len() as u64 > self.0.api_settings.max_arguments {
+            return Err(ApiError::BadRequest("too many arguments".into()).into());
         }
+        Ok(addresses
+            .iter()
+            .map(|address| address.get_thread(self.0.api_settings.thread_count))
+            .collect())
+    }
 
-        Ok(res)
+    /// parse an address string
+    async fn parse_address(&self, address: String) -> RpcResult<ParsedAddress> {
+        let _permit = self.admit_request("parse_address")?;
+        let address = Address::from_str(&address)
+            .map_err(|e| ApiError::BadRequest(format!("invalid address {}: {}", address, e)))?;
+        Ok(ParsedAddress {
+            address,
+            thread: address.get_thread(self.0.api_settings.thread_count),
+            category: match address {
+                Address::User(_) => AddressCategory::UserAddress,
+                Address::SC(_) => AddressCategory::SCAddress,
+            },
+        })
     }
 
     /// get addresses bytecode
     async fn get_addresses_bytecode(&self, args: Vec<AddressFilter>) -> RpcResult<Vec<Vec<u8>>> {
+        let _permit = self.admit_request("get_addresses_bytecode")?;
         let queries = args
             .into_iter()
             .map(|arg| {
@@ -1012,8 +2476,221 @@ impl MassaRpcServer for API<Public> {
         Ok(res?)
     }
 
+    /// get the final and candidate bytecode hashes of addresses
+    async fn get_bytecode_hash(
+        &self,
+        addresses: Vec<Address>,
+    ) -> RpcResult<Vec<AddressBytecodeHash>> {
+        let _permit = self.admit_request("get_bytecode_hash")?;
+        if addresses.is_empty() {
+            return Err(ApiError::BadRequest("no arguments specified".to_string()).into());
+        }
+
+        if addresses.len() as u64 > self.0.api_settings.max_arguments {
+            return Err(ApiError::BadRequest(format!("too many arguments received. Only a maximum of {} arguments are accepted per request", self.0.api_settings.max_arguments)).into());
+        }
+
+        // query the final and candidate bytecode of each address, in that order
+        let queries = addresses
+            .iter()
+            .flat_map(|address| {
+                [
+                    ExecutionQueryRequestItem::AddressBytecodeFinal(*address),
+                    ExecutionQueryRequestItem::AddressBytecodeCandidate(*address),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let responses = self
+            .0
+            .execution_controller
+            .query_state(ExecutionQueryRequest { requests: queries })
+            .responses;
+
+        // unknown addresses and addresses with empty bytecode are both reported as `None`
+        let hashes: Vec<Option<Hash>> = responses
+            .into_iter()
+            .map(|value| match value {
+                Ok(ExecutionQueryResponseItem::Bytecode(bytecode)) if bytecode.0.is_empty() => {
+                    Ok(None)
+                }
+                Ok(ExecutionQueryResponseItem::Bytecode(bytecode)) => {
+                    Ok(Some(Hash::compute_from(&bytecode.0)))
+                }
+                Ok(_) => Err(ApiError::InternalServerError(
+                    "unexpected response type".to_string(),
+                )),
+                Err(ExecutionQueryError::NotFound(_)) => Ok(None),
+                Err(ExecutionQueryError::SlotUnavailable(e)) => {
+                    Err(ApiError::InternalServerError(e))
+                }
+            })
+            .collect::<Result<_, ApiError>>()?;
+
+        if hashes.len() != 2 * addresses.len() {
+            return Err(ApiError::InternalServerError(
+                "unexpected number of responses".to_string(),
+            )
+            .into());
+        }
+
+        Ok(addresses
+            .into_iter()
+            .zip(hashes.chunks_exact(2))
+            .map(|(address, hashes)| AddressBytecodeHash {
+                address,
+                final_hash: hashes[0],
+                candidate_hash: hashes[1],
+            })
+            .collect())
+    }
+
+    /// get the final and candidate datastore keys of addresses
+    async fn get_datastore_keys(
+        &self,
+        addresses: Vec<Address>,
+    ) -> RpcResult<Vec<(Address, BTreeSet<Vec<u8>>, BTreeSet<Vec<u8>>)>> {
+        let _permit = self.admit_request("get_datastore_keys")?;
+        if addresses.len() as u64 > self.0.api_settings.max_arguments {
+            return Err(ApiError::BadRequest("too many arguments".into()).into());
+        }
+
+        // query the final and candidate keys of each address, in that order
+        let queries = addresses
+            .iter()
+            .flat_map(|address| {
+                [
+                    ExecutionQueryRequestItem::AddressDatastoreKeysFinal {
+                        addr: *address,
+                        prefix: Vec::new(),
+                    },
+                    ExecutionQueryRequestItem::AddressDatastoreKeysCandidate {
+                        addr: *address,
+                        prefix: Vec::new(),
+                    },
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let responses = self
+            .0
+            .execution_controller
+            .query_state(ExecutionQueryRequest { requests: queries })
+            .responses;
+
+        // unknown addresses have no keys
+        let keys: Vec<BTreeSet<Vec<u8>>> = responses
+            .into_iter()
+            .map(|value| match value {
+                Ok(ExecutionQueryResponseItem::KeyList(keys)) => Ok(keys),
+                Ok(_) => Err(ApiError::InternalServerError(
+                    "unexpected response type".to_string(),
+                )),
+                Err(ExecutionQueryError::NotFound(_)) => Ok(BTreeSet::new()),
+                Err(ExecutionQueryError::SlotUnavailable(e)) => {
+                    Err(ApiError::InternalServerError(e))
+                }
+            })
+            .collect::<Result<_, ApiError>>()?;
+
+        if keys.len() != 2 * addresses.len() {
+            return Err(ApiError::InternalServerError(
+                "unexpected number of responses".to_string(),
+            )
+            .into());
+        }
+
+        let mut keys = keys.into_iter();
+        Ok(addresses
+            .into_iter()
+            .map(|address| {
+                let final_keys = keys.next().unwrap_or_default();
+                let candidate_keys = keys.next().unwrap_or_default();
+                (address, final_keys, candidate_keys)
+            })
+            .collect())
+    }
+
+    /// get addresses deferred credits
+    async fn get_deferred_credits(
+        &self,
+        args: Vec<AddressFilter>,
+    ) -> RpcResult<Vec<Vec<SlotAmount>>> {
+        let _permit = self.admit_request("get_deferred_credits")?;
+        let queries = args
+            .into_iter()
+            .map(|arg| {
+                if arg.is_final {
+                    ExecutionQueryRequestItem::AddressDeferredCreditsFinal(arg.address)
+                } else {
+                    ExecutionQueryRequestItem::AddressDeferredCreditsCandidate(arg.address)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if queries.is_empty() {
+            return Err(ApiError::BadRequest("no arguments specified".to_string()).into());
+        }
+
+        if queries.len() as u64 > self.0.api_settings.max_arguments {
+            return Err(ApiError::BadRequest(format!("too many arguments received. Only a maximum of {} arguments are accepted per request", self.0.api_settings.max_arguments)).into());
+        }
+
+        let responses = self
+            .0
+            .execution_controller
+            .query_state(ExecutionQueryRequest { requests: queries })
+            .responses;
+
+        let res: Result<Vec<Vec<SlotAmount>>, ApiError> = responses
+            .into_iter()
+            .map(|value| match value {
+                Ok(ExecutionQueryResponseItem::DeferredCredits(credits)) => Ok(credits
+                    .into_iter()
+                    .map(|(slot, amount)| SlotAmount { slot, amount })
+                    .collect()),
+                Ok(_) => Err(ApiError::InternalServerError(
+                    "unexpected response type".to_string(),
+                )),
+                Err(err) => Err(ApiError::InternalServerError(err.to_string())),
+            })
+            .collect();
+
+        Ok(res?)
+    }
+
+    /// get the balance of an address at a past slot
+    async fn get_balance_at_slot(&self, address: Address, slot: Slot) -> RpcResult<Amount> {
+        let _permit = self.admit_request("get_balance_at_slot")?;
+        let response = self
+            .0
+            .execution_controller
+            .query_state(ExecutionQueryRequest {
+                requests: vec![ExecutionQueryRequestItem::AddressBalanceAtSlot {
+                    addr: address,
+                    slot,
+                }],
+            })
+            .responses
+            .pop();
+
+        match response {
+            Some(Ok(ExecutionQueryResponseItem::Amount(balance))) => Ok(balance),
+            // like get_addresses, an address without ledger entry has a zero balance
+            Some(Err(ExecutionQueryError::NotFound(_))) => Ok(Amount::zero()),
+            Some(Err(ExecutionQueryError::SlotUnavailable(e))) => {
+                Err(ApiError::BadRequest(e).into())
+            }
+            _ => Err(ApiError::InternalServerError("unexpected response type".to_string()).into()),
+        }
+    }
+
     /// send operations
-    async fn send_operations(&self, ops: Vec<OperationInput>) -> RpcResult<Vec<OperationId>> {
+    async fn send_operations(
+        &self,
+        ops: Vec<OperationInput>,
+    ) -> RpcResult<Vec<(OperationId, bool)>> {
+        let _permit = self.admit_request("send_operations")?;
         let mut cmd_sender = self.0.pool_command_sender.clone();
         let protocol_sender = self.0.protocol_controller.clone();
         let api_cfg = self.0.api_settings.clone();
@@ -1022,73 +2699,40 @@ impl MassaRpcServer for API<Public> {
         if ops.len() as u64 > api_cfg.max_arguments {
             return Err(ApiError::BadRequest("too many arguments".into()).into());
         }
-        let operation_deserializer = SecureShareDeserializer::new(OperationDeserializer::new(
-            api_cfg.max_datastore_value_length,
-            api_cfg.max_function_name_length,
-            api_cfg.max_parameter_size,
-            api_cfg.max_op_datastore_entry_count,
-            api_cfg.max_op_datastore_key_length,
-            api_cfg.max_op_datastore_value_length,
-        ));
-        let now = MassaTime::now().map_err(|e| {
-            ApiError::InconsistencyError(format!("Unable to get current time: {}", e))
-        })?;
-        let last_slot = get_latest_block_slot_at_timestamp(
-            api_cfg.thread_count,
-            api_cfg.t0,
-            api_cfg.genesis_timestamp,
-            now,
-        )
-        .map_err(ApiError::ModelsError)?;
-        let verified_ops = ops
+        let request_bytes: usize = ops.iter().map(|op| op.serialized_content.len()).sum();
+        self.check_request_bytes(request_bytes)?;
+        let verified_ops = self
+            .check_operation_inputs(ops)
+            .await?
             .into_iter()
-            .map(|op_input| {
-                let mut op_serialized = Vec::new();
-                op_serialized.extend(op_input.signature.to_bytes());
-                op_serialized.extend(op_input.creator_public_key.to_bytes());
-                op_serialized.extend(op_input.serialized_content);
-                let (rest, op): (&[u8], SecureShareOperation) = operation_deserializer
-                    .deserialize::<DeserializeError>(&op_serialized)
-                    .map_err(|err| {
-                        ApiError::ModelsError(ModelsError::DeserializeError(err.to_string()))
-                    })?;
-                match op.content.op {
-                    OperationType::CallSC { max_gas, .. } | OperationType::ExecuteSC { max_gas, .. } => {
-                        if max_gas > api_cfg.max_gas_per_block {
-                            return Err(ApiError::InconsistencyError("Gas limit of the operation is higher than the block gas limit. Your operation will never be included in a block.".into()).into());
-                        }
-                    },
-                    _ => {}
-                };
-                if let Some(slot) = last_slot {
-                    if op.content.expire_period < slot.period {
-                        return Err(ApiError::InconsistencyError("Operation expire_period is lower than the current period of this node. Your operation will never be included in a block.".into()).into());
-                    }
-                }
-                if rest.is_empty() {
-                    Ok(op)
-                } else {
-                    Err(ApiError::ModelsError(ModelsError::DeserializeError(
-                        "There is data left after operation deserialization".to_owned(),
-                    ))
-                    .into())
-                }
-            })
-            .map(|op| match op {
-                Ok(operation) => {
-                    let _verify_signature = match operation.verify_signature() {
-                        Ok(()) => (),
-                        Err(e) => return Err(ApiError::ModelsError(e).into()),
-                    };
-                    Ok(operation)
-                }
-                Err(e) => Err(e),
-            })
-            .collect::<RpcResult<Vec<SecureShareOperation>>>()?;
+            .collect::<Result<Vec<SecureShareOperation>, ApiError>>()?;
         to_send.store_operations(verified_ops.clone());
-        let ids: Vec<OperationId> = verified_ops.iter().map(|op| op.id).collect();
-        cmd_sender.add_operations(to_send.clone());
+        let pool_ops = to_send.clone();
+        // a busy pool worker must not hold the request (and its permit) indefinitely
+        let accepted_ids = tokio::time::timeout(
+            api_cfg.pool_add_timeout.to_duration(),
+            tokio::task::spawn_blocking(move || cmd_sender.add_operations_sync(pool_ops)),
+        )
+        .await
+        .map_err(|_| {
+            ApiError::ServiceUnavailable("the pool did not process the operations in time".into())
+        })?
+        .map_err(|err| ApiError::InternalServerError(err.to_string()))?;
+        let ids: Vec<(OperationId, bool)> = verified_ops
+            .iter()
+            .map(|op| (op.id, accepted_ids.contains(&op.id)))
+            .collect();
 
+        // only the operations accepted by the pool are propagated
+        let rejected_ids: PreHashSet<OperationId> = verified_ops
+            .iter()
+            .map(|op| op.id)
+            .filter(|id| !accepted_ids.contains(id))
+            .collect();
+        to_send.drop_operation_refs(&rejected_ids);
+        if accepted_ids.is_empty() {
+            return Ok(ids);
+        }
         tokio::task::spawn_blocking(move || protocol_sender.propagate_operations(to_send))
             .await
             .map_err(|err| ApiError::InternalServerError(err.to_string()))?
@@ -1098,6 +2742,184 @@ impl MassaRpcServer for API<Public> {
         Ok(ids)
     }
 
+    /// validate operations without submitting them
+    async fn validate_operations(
+        &self,
+        ops: Vec<OperationInput>,
+    ) -> RpcResult<Vec<OperationValidity>> {
+        let _permit = self.admit_request("validate_operations")?;
+        if ops.len() as u64 > self.0.api_settings.max_arguments {
+            return Err(ApiError::BadRequest("too many arguments".into()).into());
+        }
+        let request_bytes: usize = ops.iter().map(|op| op.serialized_content.len()).sum();
+        self.check_request_bytes(request_bytes)?;
+
+        Ok(self
+            .check_operation_inputs(ops)
+            .await?
+            .into_iter()
+            .map(|res| match res {
+                Ok(op) => OperationValidity {
+                    id: Some(op.id),
+                    is_valid: true,
+                    error: None,
+                },
+                Err(err) => OperationValidity {
+                    id: None,
+                    is_valid: false,
+                    error: Some(err.to_string()),
+                },
+            })
+            .collect())
+    }
+
+    /// compute the id of an operation without submitting it
+    async fn compute_operation_id(&self, op: OperationInput) -> RpcResult<OperationId> {
+        let _permit = self.admit_request("compute_operation_id")?;
+        self.check_request_bytes(op.serialized_content.len())?;
+        match self.check_operation_inputs(vec![op]).await?.pop() {
+            Some(checked) => Ok(checked?.id),
+            None => Err(ApiError::InternalServerError(
+                "operation check returned no result".to_string(),
+            )
+            .into()),
+        }
+    }
+
+    /// simulate an operation without submitting it
+    async fn simulate_operation(&self, op: OperationInput) -> RpcResult<OperationSimulation> {
+        let permit = Arc::new(self.admit_request("simulate_operation")?);
+        self.check_request_bytes(op.serialized_content.len())?;
+        let op = match self.check_operation_inputs(vec![op]).await?.pop() {
+            Some(checked) => checked?,
+            None => {
+                return Err(ApiError::InternalServerError(
+                    "operation check returned no result".to_string(),
+                )
+                .into())
+            }
+        };
+
+        if let OperationType::Transaction {
+            recipient_address,
+            amount,
+        } = op.content.op
+        {
+            return Ok(self.simulate_transaction(&op, recipient_address, amount));
+        }
+
+        // the operation is mapped to a read-only execution: its changes are never applied
+        let creator = op.content_creator_address;
+        let (max_gas, target, call_stack, coins) = match op.content.op.clone() {
+            OperationType::ExecuteSC {
+                data,
+                max_gas,
+                datastore,
+                ..
+            } => (
+                max_gas,
+                ReadOnlyExecutionTarget::BytecodeExecution(data),
+                vec![ExecutionStackElement {
+                    address: creator,
+                    coins: Default::default(),
+                    owned_addresses: vec![creator],
+                    operation_datastore: Some(datastore),
+                }],
+                None,
+            ),
+            OperationType::CallSC {
+                target_addr,
+                target_func,
+                param,
+                max_gas,
+                coins,
+            } => (
+                max_gas,
+                ReadOnlyExecutionTarget::FunctionCall {
+                    target_addr,
+                    target_func,
+                    parameter: param,
+                },
+                vec![
+                    ExecutionStackElement {
+                        address: creator,
+                        coins: Default::default(),
+                        owned_addresses: vec![creator],
+                        operation_datastore: None,
+                    },
+                    ExecutionStackElement {
+                        address: target_addr,
+                        coins,
+                        owned_addresses: vec![target_addr],
+                        operation_datastore: None,
+                    },
+                ],
+                Some(coins),
+            ),
+            _ => {
+                return Err(ApiError::BadRequest(
+                    "only transactions, ExecuteSC and CallSC operations can be simulated, roll operations are not supported".to_string(),
+                )
+                .into())
+            }
+        };
+        crate::check_read_only_costs(&self.0.api_settings, max_gas, Some(op.content.fee), coins)?;
+        let req = ReadOnlyExecutionRequest {
+            max_gas,
+            call_stack,
+            target,
+            coins,
+            fee: Some(op.content.fee),
+            is_final: false,
+            label: None,
+            trace: false,
+            event_sender: None,
+        };
+
+        let result = self.run_read_only_request(req, &permit).await;
+        let (balance_changes, datastore_changes) = result.as_ref().map_or_else(
+            |_| Default::default(),
+            |v| ledger_diffs(&v.out.state_changes.ledger_changes),
+        );
+        Ok(OperationSimulation {
+            id: op.id,
+            executed_at: result.as_ref().ok().map(|v| v.out.slot),
+            result: result.as_ref().map_or_else(
+                |err| ReadOnlyResult::Error(err.message.clone()),
+                |res| ReadOnlyResult::Ok(res.call_result.clone()),
+            ),
+            gas_cost: result.map_or_else(|_| 0, |v| v.gas_cost),
+            balance_changes,
+            datastore_changes,
+        })
+    }
+
+    /// decode a serialized operation without submitting it
+    async fn decode_operation(
+        &self,
+        bytes: Vec<u8>,
+        verify_signature: Option<bool>,
+    ) -> RpcResult<DecodedOperation> {
+        let _permit = self.admit_request("decode_operation")?;
+        self.check_request_bytes(bytes.len())?;
+
+        let (rest, operation): (&[u8], SecureShareOperation) = self
+            .operation_deserializer()
+            .deserialize::<DeserializeError>(&bytes)
+            .map_err(|err| {
+                ApiError::DeserializeError(DeserializeErrorDetails::new(&err, bytes.len()))
+            })?;
+        let signature_valid = verify_signature
+            .unwrap_or(false)
+            .then(|| operation.verify_signature().is_ok());
+
+        Ok(DecodedOperation {
+            operation,
+            rest: rest.to_vec(),
+            signature_valid,
+        })
+    }
+
     /// Get events optionally filtered by:
     /// * start slot
     /// * end slot
@@ -1108,6 +2930,7 @@ impl MassaRpcServer for API<Public> {
         &self,
         filter: EventFilter,
     ) -> RpcResult<Vec<SCOutputEvent>> {
+        let _permit = self.admit_request("get_filtered_sc_output_event")?;
         let events = self
             .0
             .execution_controller
@@ -1117,6 +2940,39 @@ impl MassaRpcServer for API<Public> {
         Ok(events)
     }
 
+    /// Get the events matching the filter grouped by originating operation
+    async fn get_filtered_sc_output_event_by_operation(
+        &self,
+        filter: EventFilter,
+    ) -> RpcResult<PreHashMap<OperationId, Vec<SCOutputEvent>>> {
+        let _permit = self.admit_request("get_filtered_sc_output_event_by_operation")?;
+        let events = self
+            .0
+            .execution_controller
+            .get_filtered_sc_output_event(filter);
+
+        let mut grouped: PreHashMap<OperationId, Vec<SCOutputEvent>> = PreHashMap::default();
+        for event in events {
+            if let Some(op_id) = event.context.origin_operation_id {
+                grouped.entry(op_id).or_default().push(event);
+            }
+        }
+        Ok(grouped)
+    }
+
+    async fn export_sc_output_events(&self, _: EventFilter, _: PathBuf) -> RpcResult<u64> {
+        crate::wrong_api::<u64>()
+    }
+
+    async fn find_addresses_with_key_prefix(
+        &self,
+        _: Vec<u8>,
+        _: Option<Address>,
+        _: u64,
+    ) -> RpcResult<KeyPrefixAddresses> {
+        crate::wrong_api::<KeyPrefixAddresses>()
+    }
+
     async fn node_peers_whitelist(&self) -> RpcResult<Vec<IpAddr>> {
         crate::wrong_api::<Vec<IpAddr>>()
     }
@@ -1159,6 +3015,7 @@ impl MassaRpcServer for API<Public> {
 
     /// Get the OpenRPC specification of the node
     async fn get_openrpc_spec(&self) -> RpcResult<Value> {
+        let _permit = self.admit_request("get_openrpc_spec")?;
         let openrpc_spec_path = self.0.api_settings.openrpc_spec_path.clone();
         let openrpc: RpcResult<Value> = std::fs::read_to_string(openrpc_spec_path)
             .map_err(|e| {
@@ -1181,3 +3038,108 @@ impl MassaRpcServer for API<Public> {
         openrpc
     }
 }
+
+/// Write the `HELP` and `TYPE` lines introducing a metric in the Prometheus text format
+fn write_metric_header(out: &mut String, name: &str, help: &str, kind: &str) {
+    out.push_str(&format!(
+        "# HELP {} {}\n# TYPE {} {}\n",
+        name, help, name, kind
+    ));
+}
+
+/// Flattens ledger changes into the new balances and the datastore changes, ordered by address.
+/// A deleted ledger entry is reported as a zero balance.
+#[allow(clippy::type_complexity)]
+fn ledger_diffs(
+    changes: &LedgerChanges,
+) -> (
+    Vec<(Address, Amount)>,
+    Vec<(Address, Vec<u8>, Option<Vec<u8>>)>,
+) {
+    let mut balance_changes = Vec::new();
+    let mut datastore_changes = Vec::new();
+    for (address, change) in changes.0.iter().sorted_by_key(|(address, _)| **address) {
+        match change {
+            SetUpdateOrDelete::Set(entry) => {
+                balance_changes.push((*address, entry.balance));
+                datastore_changes.extend(
+                    entry
+                        .datastore
+                        .iter()
+                        .map(|(key, value)| (*address, key.clone(), Some(value.clone()))),
+                );
+            }
+            SetUpdateOrDelete::Update(update) => {
+                if let SetOrKeep::Set(balance) = update.balance {
+                    balance_changes.push((*address, balance));
+                }
+                datastore_changes.extend(update.datastore.iter().map(|(key, value)| {
+                    let value = match value {
+                        SetOrDelete::Set(value) => Some(value.clone()),
+                        SetOrDelete::Delete => None,
+                    };
+                    (*address, key.clone(), value)
+                }));
+            }
+            SetUpdateOrDelete::Delete => balance_changes.push((*address, Amount::zero())),
+        }
+    }
+    (balance_changes, datastore_changes)
+}
+
+/// a failed read-only execution, as reported to the client
+struct ReadOnlyFailure {
+    /// error message
+    message: String,
+    /// call trace recorded up to the failure, if one was requested
+    trace: Option<Vec<ExecutionTraceStep>>,
+}
+
+impl ReadOnlyFailure {
+    fn new(message: String) -> Self {
+        ReadOnlyFailure {
+            message,
+            trace: None,
+        }
+    }
+}
+
+impl From<ExecutionError> for ReadOnlyFailure {
+    fn from(err: ExecutionError) -> Self {
+        match err {
+            ExecutionError::TracedError { error, trace } => ReadOnlyFailure {
+                message: format!("readonly call failed: {}", error),
+                trace: Some(trace),
+            },
+            err => ReadOnlyFailure::new(format!("readonly call failed: {}", err)),
+        }
+    }
+}
+
+/// serialize read-only responses with the binary serializers and base64-encode them
+fn encode_read_only_responses(responses: &[ExecuteReadOnlyResponse]) -> RpcResult<Vec<String>> {
+    let serializer = ExecuteReadOnlyResponseSerializer::new();
+    responses
+        .iter()
+        .map(|response| {
+            let mut buffer = Vec::new();
+            serializer.serialize(response, &mut buffer).map_err(|e| {
+                ApiError::InternalServerError(format!(
+                    "failed to serialize read-only response: {}",
+                    e
+                ))
+            })?;
+            Ok(STANDARD.encode(buffer))
+        })
+        .collect()
+}
+
+/// Last slot of `thread` at or before `cursor`.
+/// Genesis slots are all processed from the start, so the period never underflows in practice.
+fn last_slot_of_thread(cursor: Slot, thread: u8) -> Slot {
+    if thread <= cursor.thread {
+        Slot::new(cursor.period, thread)
+    } else {
+        Slot::new(cursor.period.saturating_sub(1), thread)
+    }
+}
@@ -0,0 +1,54 @@
+//! Copyright (c) 2022 MASSA LABS <info@massa.net>
+//! Per-method rate limiting of the public API, based on token buckets.
+
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Instant;
+
+/// token bucket of a single method
+struct TokenBucket {
+    /// available tokens
+    tokens: f64,
+    /// last time the bucket was refilled
+    last_refill: Instant,
+}
+
+/// Token buckets keyed by method name.
+/// The limits themselves are read from the API config on each call.
+#[derive(Default)]
+pub(crate) struct RateLimiter {
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl RateLimiter {
+    /// Try to consume a token for `method`.
+    /// `limits` maps method names to their maximum number of calls per second,
+    /// methods that are not listed (or with a limit of 0) are not limited.
+    /// Returns false if the method has exceeded its limit.
+    pub(crate) fn try_acquire(&self, method: &str, limits: &BTreeMap<String, u32>) -> bool {
+        let limit = match limits.get(method) {
+            Some(limit) if *limit > 0 => *limit as f64,
+            _ => return true,
+        };
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock();
+        let bucket = buckets
+            .entry(method.to_string())
+            .or_insert_with(|| TokenBucket {
+                tokens: limit,
+                last_refill: now,
+            });
+
+        // refill proportionally to the elapsed time, up to the limit
+        let elapsed = now.saturating_duration_since(bucket.last_refill);
+        bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * limit).min(limit);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -7,8 +7,9 @@ use jsonrpsee::{
     rpc_params,
     ws_client::WsClientBuilder,
 };
+use massa_api_exports::execution::{ReadOnlyCall, ReadOnlyResult, ReadOnlyStreamItem};
 use massa_consensus_exports::MockConsensusController;
-use massa_execution_exports::MockExecutionController;
+use massa_execution_exports::{ExecutionOutput, MockExecutionController, ReadOnlyExecutionOutput};
 use massa_models::{
     address::Address,
     block::{FilledBlock, SecureShareBlock},
@@ -16,7 +17,9 @@ use massa_models::{
     block_id::BlockId,
     config::VERSION,
     operation::SecureShareOperation,
+    output_event::{EventExecutionContext, SCOutputEvent},
     secure_share::SecureShare,
+    slot::Slot,
 };
 use massa_protocol_exports::test_exports::tools::{
     create_block, create_operation_with_expire_period,
@@ -24,7 +27,7 @@ use massa_protocol_exports::test_exports::tools::{
 use massa_signature::KeyPair;
 use serde_json::Value;
 
-use crate::{tests::mock::get_apiv2_server, ApiServer};
+use crate::{tests::mock::get_apiv2_server, ApiServer, RequestAdmission};
 
 #[tokio::test]
 async fn get_version() {
@@ -333,3 +336,161 @@ async fn subscribe_new_operations() {
 
     api_handle.stop().await;
 }
+
+#[tokio::test]
+async fn subscribe_read_only_call() {
+    let addr: SocketAddr = "[::]:5106".parse().unwrap();
+    let (mut api_server, api_config) = get_apiv2_server(&addr);
+
+    // the execution emits three events before returning
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_clone_box().returning(|| {
+        let mut exec_ctrl = MockExecutionController::new();
+        exec_ctrl
+            .expect_execute_readonly_request()
+            .returning(|req| {
+                let event_sender = req.event_sender.expect("events should be streamed");
+                for index_in_slot in 0..3 {
+                    event_sender
+                        .try_send(SCOutputEvent {
+                            context: EventExecutionContext {
+                                slot: Slot::new(1, 5),
+                                block: None,
+                                read_only: true,
+                                index_in_slot,
+                                call_stack: std::collections::VecDeque::new(),
+                                origin_operation_id: None,
+                                is_final: false,
+                                is_error: false,
+                            },
+                            data: index_in_slot.to_string(),
+                        })
+                        .unwrap();
+                }
+                Ok(ReadOnlyExecutionOutput {
+                    out: ExecutionOutput {
+                        slot: Slot::new(1, 5),
+                        block_info: None,
+                        state_changes: Default::default(),
+                        events: Default::default(),
+                    },
+                    gas_cost: 100,
+                    call_result: vec![1],
+                    trace: None,
+                })
+            });
+        Box::new(exec_ctrl)
+    });
+    api_server.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_handle = api_server
+        .serve(&addr, &api_config)
+        .await
+        .expect("failed to start MASSA API V2");
+
+    let uri = Url::parse(&format!(
+        "ws://localhost:{}",
+        addr.to_string().split(':').last().unwrap()
+    ))
+    .unwrap();
+
+    let client = WsClientBuilder::default().build(&uri).await.unwrap();
+    let call = ReadOnlyCall {
+        max_gas: 1000000,
+        target_address: Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key()),
+        target_function: "run".to_string(),
+        parameter: vec![],
+        caller_address: None,
+        coins: None,
+        fee: None,
+        is_final: false,
+        label: None,
+        trace: false,
+    };
+    let mut sub: Subscription<ReadOnlyStreamItem> = client
+        .subscribe(
+            "subscribe_read_only_call",
+            rpc_params![call],
+            "unsubscribe_read_only_call",
+        )
+        .await
+        .unwrap();
+
+    for index in 0..3 {
+        let item = tokio::time::timeout(Duration::from_secs(4), sub.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        match item {
+            ReadOnlyStreamItem::Event(event) => assert_eq!(event.data, index.to_string()),
+            _ => panic!("expected an event"),
+        }
+    }
+    let item = tokio::time::timeout(Duration::from_secs(4), sub.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    match item {
+        ReadOnlyStreamItem::Done {
+            executed_at,
+            result: ReadOnlyResult::Ok(result),
+            gas_cost,
+            ..
+        } => {
+            assert_eq!(executed_at, Some(Slot::new(1, 5)));
+            assert_eq!(result, vec![1]);
+            assert_eq!(gas_cost, 100);
+        }
+        _ => panic!("expected the successful end of the call"),
+    }
+
+    api_handle.stop().await;
+}
+
+#[tokio::test]
+async fn subscribe_read_only_call_overloaded() {
+    let addr: SocketAddr = "[::]:5109".parse().unwrap();
+    let (mut api_server, api_config) = get_apiv2_server(&addr);
+    // no permit available: the call is rejected before reaching the execution
+    api_server.0.admission = RequestAdmission::new(0);
+
+    let api_handle = api_server
+        .serve(&addr, &api_config)
+        .await
+        .expect("failed to start MASSA API V2");
+
+    let uri = Url::parse(&format!(
+        "ws://localhost:{}",
+        addr.to_string().split(':').last().unwrap()
+    ))
+    .unwrap();
+
+    let client = WsClientBuilder::default().build(&uri).await.unwrap();
+    let call = ReadOnlyCall {
+        max_gas: 1000000,
+        target_address: Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key()),
+        target_function: "run".to_string(),
+        parameter: vec![],
+        caller_address: None,
+        coins: None,
+        fee: None,
+        is_final: false,
+        label: None,
+        trace: false,
+    };
+    let response: Result<Subscription<ReadOnlyStreamItem>, _> = client
+        .subscribe(
+            "subscribe_read_only_call",
+            rpc_params![call],
+            "unsubscribe_read_only_call",
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("too many requests in progress"));
+
+    api_handle.stop().await;
+}
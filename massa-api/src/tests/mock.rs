@@ -2,18 +2,25 @@
 //!
 //!
 
-use std::{collections::HashMap, net::SocketAddr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    net::SocketAddr,
+    str::FromStr,
+    sync::Arc,
+};
 
 use massa_api_exports::config::APIConfig;
 use massa_consensus_exports::{ConsensusBroadcasts, MockConsensusController};
 use massa_execution_exports::MockExecutionController;
 use massa_models::{
+    address::Address,
+    amount::Amount,
     config::{
-        ENDORSEMENT_COUNT, GENESIS_TIMESTAMP, MAX_DATASTORE_VALUE_LENGTH, MAX_FUNCTION_NAME_LENGTH,
-        MAX_GAS_PER_BLOCK, MAX_MESSAGE_SIZE, MAX_OPERATION_DATASTORE_ENTRY_COUNT,
-        MAX_OPERATION_DATASTORE_KEY_LENGTH, MAX_OPERATION_DATASTORE_VALUE_LENGTH,
-        MAX_PARAMETERS_SIZE, MIP_STORE_STATS_BLOCK_CONSIDERED, PERIODS_PER_CYCLE, T0, THREAD_COUNT,
-        VERSION,
+        ENDORSEMENT_COUNT, GENESIS_TIMESTAMP, LEDGER_ENTRY_BASE_COST, MAX_DATASTORE_VALUE_LENGTH,
+        MAX_FUNCTION_NAME_LENGTH, MAX_GAS_PER_BLOCK, MAX_MESSAGE_SIZE,
+        MAX_OPERATION_DATASTORE_ENTRY_COUNT, MAX_OPERATION_DATASTORE_KEY_LENGTH,
+        MAX_OPERATION_DATASTORE_VALUE_LENGTH, MAX_PARAMETERS_SIZE,
+        MIP_STORE_STATS_BLOCK_CONSIDERED, PERIODS_PER_CYCLE, T0, THREAD_COUNT, VERSION,
     },
     node::NodeId,
 };
@@ -23,11 +30,13 @@ use massa_protocol_exports::{MockProtocolController, PeerCategoryInfo, ProtocolC
 use massa_signature::KeyPair;
 use massa_time::MassaTime;
 use massa_versioning::versioning::{MipStatsConfig, MipStore};
+use massa_wallet::Wallet;
 use num::rational::Ratio;
+use parking_lot::RwLock;
 use tempfile::NamedTempFile;
 use tokio::sync::broadcast;
 
-use crate::{ApiV2, Public, API};
+use crate::{ApiV2, Public, RequestAdmission, API};
 
 pub(crate) fn get_apiv2_server(addr: &SocketAddr) -> (API<ApiV2>, APIConfig) {
     let keypair = KeyPair::generate(0).unwrap();
@@ -37,12 +46,28 @@ pub(crate) fn get_apiv2_server(addr: &SocketAddr) -> (API<ApiV2>, APIConfig) {
         bind_api: *addr,
         draw_lookahead_period_count: 10,
         max_arguments: 128,
+        max_request_bytes: 10485760,
+        max_graph_interval_slots: 32000,
+        max_key_prefix_scan: 10000,
+        enable_response_compression: false,
+        compression_min_response_size: 1024,
+        rate_limits: BTreeMap::new(),
+        status_cache_ttl: MassaTime::from_millis(0),
+        status_retry_count: 1,
+        read_only_deadline: MassaTime::from_millis(0),
+        pool_add_timeout: MassaTime::from_millis(5000),
+        read_only_default_caller: Address::from_str("AU1111111111111111111111111111111112m1s9K")
+            .unwrap(),
+        max_concurrent_requests: 1024,
+        expose_staking_addresses: false,
+        expose_pool_operations: true,
         openrpc_spec_path: "base_config/openrpc.json".parse().unwrap(),
         bootstrap_whitelist_path: "base_config/bootstrap_whitelist.json".parse().unwrap(),
         bootstrap_blacklist_path: "base_config/bootstrap_blacklist.json".parse().unwrap(),
         max_request_body_size: 52428800,
         max_response_body_size: 52428800,
         max_connections: 100,
+        worker_threads: 2,
         max_subscriptions_per_connection: 1024,
         max_log_length: 4096,
         allow_hosts: vec![],
@@ -55,6 +80,9 @@ pub(crate) fn get_apiv2_server(addr: &SocketAddr) -> (API<ApiV2>, APIConfig) {
         max_op_datastore_key_length: MAX_OPERATION_DATASTORE_KEY_LENGTH,
         max_op_datastore_value_length: MAX_OPERATION_DATASTORE_VALUE_LENGTH,
         max_gas_per_block: MAX_GAS_PER_BLOCK,
+        max_read_only_gas: 1_000_000_000,
+        ledger_entry_base_cost: LEDGER_ENTRY_BASE_COST,
+        minimal_fees: Amount::zero(),
         max_function_name_length: MAX_FUNCTION_NAME_LENGTH,
         max_parameter_size: MAX_PARAMETERS_SIZE,
         thread_count: THREAD_COUNT,
@@ -95,6 +123,7 @@ pub(crate) fn get_apiv2_server(addr: &SocketAddr) -> (API<ApiV2>, APIConfig) {
         pool_broadcasts,
         api_config.clone(),
         *VERSION,
+        RequestAdmission::new(api_config.max_concurrent_requests),
     );
 
     (api, api_config)
@@ -108,12 +137,28 @@ pub(crate) fn start_public_api(addr: SocketAddr) -> (API<Public>, APIConfig) {
         bind_api: "[::]:0".parse().unwrap(),
         draw_lookahead_period_count: 10,
         max_arguments: 128,
+        max_request_bytes: 10485760,
+        max_graph_interval_slots: 32000,
+        max_key_prefix_scan: 10000,
+        enable_response_compression: false,
+        compression_min_response_size: 1024,
+        rate_limits: BTreeMap::new(),
+        status_cache_ttl: MassaTime::from_millis(0),
+        status_retry_count: 1,
+        read_only_deadline: MassaTime::from_millis(0),
+        pool_add_timeout: MassaTime::from_millis(5000),
+        read_only_default_caller: Address::from_str("AU1111111111111111111111111111111112m1s9K")
+            .unwrap(),
+        max_concurrent_requests: 1024,
+        expose_staking_addresses: false,
+        expose_pool_operations: true,
         openrpc_spec_path: "base_config/openrpc.json".parse().unwrap(),
         bootstrap_whitelist_path: "base_config/bootstrap_whitelist.json".parse().unwrap(),
         bootstrap_blacklist_path: "base_config/bootstrap_blacklist.json".parse().unwrap(),
         max_request_body_size: 52428800,
         max_response_body_size: 52428800,
         max_connections: 100,
+        worker_threads: 2,
         max_subscriptions_per_connection: 1024,
         max_log_length: 4096,
         allow_hosts: vec![],
@@ -126,6 +171,9 @@ pub(crate) fn start_public_api(addr: SocketAddr) -> (API<Public>, APIConfig) {
         max_op_datastore_key_length: MAX_OPERATION_DATASTORE_KEY_LENGTH,
         max_op_datastore_value_length: MAX_OPERATION_DATASTORE_VALUE_LENGTH,
         max_gas_per_block: MAX_GAS_PER_BLOCK,
+        max_read_only_gas: 1_000_000_000,
+        ledger_entry_base_cost: LEDGER_ENTRY_BASE_COST,
+        minimal_fees: Amount::zero(),
         max_function_name_length: MAX_FUNCTION_NAME_LENGTH,
         max_parameter_size: MAX_PARAMETERS_SIZE,
         thread_count: THREAD_COUNT,
@@ -250,6 +298,18 @@ pub(crate) fn start_public_api(addr: SocketAddr) -> (API<Public>, APIConfig) {
         NodeId::new(keypair.get_public_key()),
         shared_storage,
         mip_store.clone(),
+        Arc::new(RwLock::new(
+            Wallet::new(
+                tempfile::tempdir()
+                    .expect("cannot create temp dir")
+                    .path()
+                    .to_path_buf(),
+                "password".to_string(),
+            )
+            .expect("cannot create wallet"),
+        )),
+        None,
+        RequestAdmission::new(api_config.max_concurrent_requests),
     );
 
     (api_public, api_config)
@@ -3,4 +3,5 @@
 //!
 mod apiv2;
 mod mock;
+mod private;
 mod public;
@@ -0,0 +1,49 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use std::{collections::VecDeque, fs::read_to_string};
+
+use massa_models::{
+    output_event::{EventExecutionContext, SCOutputEvent},
+    slot::Slot,
+};
+
+use crate::private::write_events_to_jsonl_file;
+
+#[test]
+fn write_events_to_jsonl_file_round_trip() {
+    let event = |index_in_slot: u64, data: &str| SCOutputEvent {
+        context: EventExecutionContext {
+            slot: Slot::new(1, 0),
+            block: None,
+            read_only: false,
+            index_in_slot,
+            call_stack: VecDeque::new(),
+            origin_operation_id: None,
+            is_final: true,
+            is_error: false,
+        },
+        data: data.to_string(),
+    };
+    let events = vec![event(0, "first"), event(1, "second\nline")];
+    let file = tempfile::NamedTempFile::new().unwrap();
+
+    let count = write_events_to_jsonl_file(file.path(), events.clone().into_iter()).unwrap();
+    assert_eq!(count, 2);
+
+    let content = read_to_string(file.path()).unwrap();
+    let read_events = content
+        .lines()
+        .map(|line| serde_json::from_str::<SCOutputEvent>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(read_events.len(), events.len());
+    for (read, written) in read_events.iter().zip(events.iter()) {
+        assert_eq!(read.context.index_in_slot, written.context.index_in_slot);
+        assert_eq!(read.context.slot, written.context.slot);
+        assert_eq!(read.data, written.data);
+    }
+
+    // exporting again truncates the previous content
+    let count = write_events_to_jsonl_file(file.path(), std::iter::empty()).unwrap();
+    assert_eq!(count, 0);
+    assert!(read_to_string(file.path()).unwrap().is_empty());
+}
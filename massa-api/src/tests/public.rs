@@ -5,55 +5,89 @@ use std::{
     collections::{BTreeMap, HashMap},
     net::{IpAddr, SocketAddr},
     str::FromStr,
+    sync::Arc,
 };
 
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use jsonrpsee::{
     core::{client::ClientT, Error},
     http_client::HttpClientBuilder,
     rpc_params,
 };
 use massa_api_exports::{
-    address::{AddressFilter, AddressInfo},
-    block::{BlockInfo, BlockSummary},
+    address::{AddressBytecodeHash, AddressCategory, AddressFilter, AddressInfo, ParsedAddress},
+    block::{
+        BlockInfo, BlockSummary, BlockTimestamp, DetailedClique, SlotBlocks, SlotForkChoiceBlock,
+    },
     datastore::{DatastoreEntryInput, DatastoreEntryOutput},
+    denunciation::PendingDenunciation,
     endorsement::EndorsementInfo,
-    execution::{ExecuteReadOnlyResponse, ReadOnlyBytecodeExecution, ReadOnlyCall},
-    operation::{OperationInfo, OperationInput},
+    execution::{
+        ExecuteReadOnlyResponse, ExecuteReadOnlyResponseSerializer, ReadOnlyBulkCall,
+        ReadOnlyBytecodeExecution, ReadOnlyCall, ReadOnlyCallTarget, ReadOnlyResult,
+    },
+    node::{FeeParameters, NodeHealth, VersionInfo},
+    operation::{
+        DecodedOperation, OperationInclusionProof, OperationInfo, OperationInput,
+        OperationSimulation, OperationValidity,
+    },
+    slot::{ExecutionSlots, SlotAmount, ThreadExecutionSlots},
     TimeInterval,
 };
 use massa_consensus_exports::{
-    block_graph_export::BlockGraphExport, block_status::ExportCompiledBlock,
+    block_graph_export::BlockGraphExport, block_status::ExportCompiledBlock, error::ConsensusError,
     MockConsensusController,
 };
+use massa_ledger_exports::{
+    LedgerChanges, LedgerEntryUpdate, LedgerStats, SetOrDelete, SetOrKeep, SetUpdateOrDelete,
+};
 use massa_pool_exports::MockPoolController;
-use massa_pos_exports::MockSelectorController;
+use massa_pos_exports::{MockSelectorController, PosError, Selection};
 
-use crate::{tests::mock::start_public_api, RpcServer};
+use crate::{tests::mock::start_public_api, RequestAdmission, RpcServer};
 use massa_execution_exports::{
-    ExecutionAddressInfo, ExecutionQueryResponse, ExecutionQueryResponseItem,
-    MockExecutionController, ReadOnlyExecutionOutput,
+    ExecutionAddressInfo, ExecutionError, ExecutionQueryError, ExecutionQueryRequestItem,
+    ExecutionQueryResponse, ExecutionQueryResponseItem, MockExecutionController,
+    ReadOnlyExecutionOutput, ReadOnlyExecutionTarget,
 };
 use massa_models::{
-    address::Address,
+    address::{Address, ExecutionAddressCycleInfo, ScheduledRollCount},
     amount::Amount,
     block::{Block, BlockGraphStatus},
+    block_header::SecuredHeader,
+    block_id::BlockId,
     bytecode::Bytecode,
     clique::Clique,
-    endorsement::EndorsementId,
-    execution::EventFilter,
+    config::THREAD_COUNT,
+    denunciation::Denunciation,
+    endorsement::{Endorsement, EndorsementId, EndorsementSerializer, SecureShareEndorsement},
+    execution::{EventFilter, ExecutionTraceStep},
     node::NodeId,
-    operation::OperationId,
+    operation::{
+        compute_operations_hash, Operation, OperationId, OperationIdSerializer,
+        OperationSerializer, OperationType,
+    },
     output_event::SCOutputEvent,
-    prehash::{CapacityAllocator, PreHashMap},
+    prehash::{CapacityAllocator, PreHashMap, PreHashSet},
+    secure_share::SecureShareContent,
     slot::Slot,
     stats::{ConsensusStats, ExecutionStats, NetworkStats},
 };
 use massa_protocol_exports::{
-    test_exports::tools::{create_block, create_endorsement, create_operation_with_expire_period},
-    MockProtocolController,
+    test_exports::tools::{
+        create_block, create_block_with_operations, create_endorsement,
+        create_operation_with_expire_period,
+    },
+    MockProtocolController, ProtocolError,
 };
+use massa_serialization::Serializer;
 use massa_signature::KeyPair;
+use massa_test_framework::controllers::{
+    failing_consensus_controller, failing_execution_controller, failing_selector_controller,
+};
 use massa_time::MassaTime;
+use massa_wallet::Wallet;
+use parking_lot::RwLock;
 use serde_json::Value;
 use tempfile::NamedTempFile;
 
@@ -83,6 +117,9 @@ async fn get_status() {
             clique_count: 30,
         })
     });
+    consensus_ctrl
+        .expect_get_latest_final_slots()
+        .returning(|| vec![Slot::new(3, 0), Slot::new(2, 1)]);
 
     let mut protocol_ctrl = MockProtocolController::new();
     protocol_ctrl.expect_get_stats().returning(|| {
@@ -101,12 +138,18 @@ async fn get_status() {
     let mut pool_ctrl = MockPoolController::new();
     pool_ctrl.expect_get_operation_count().returning(|| 1024);
     pool_ctrl.expect_get_endorsement_count().returning(|| 2048);
+    pool_ctrl
+        .expect_get_operation_count_per_thread()
+        .returning(|| vec![1000, 24]);
 
     api_public.0.pool_command_sender = Box::new(pool_ctrl);
     api_public.0.protocol_controller = Box::new(protocol_ctrl);
     api_public.0.execution_controller = Box::new(exec_ctrl);
     api_public.0.consensus_controller = Box::new(consensus_ctrl);
 
+    // bootstrap server with two sessions in progress
+    api_public.0.bootstrap_sessions = Some(Arc::new(std::sync::atomic::AtomicUsize::new(2)));
+
     let api_public_handle = api_public
         .serve(&addr, &config)
         .await
@@ -125,21 +168,25 @@ async fn get_status() {
     assert_eq!(response.network_stats.in_connection_count, 10);
     assert_eq!(response.network_stats.out_connection_count, 5);
     assert_eq!(response.config.thread_count, 32);
+    assert_eq!(response.pool_stats.operation_count, 1024);
+    assert_eq!(response.pool_stats.endorsement_count, 2048);
+    assert_eq!(
+        response.pool_stats.operation_count_per_thread,
+        vec![1000, 24]
+    );
+    assert_eq!(response.final_slots, vec![Slot::new(3, 0), Slot::new(2, 1)]);
+    assert!(response.is_bootstrap_server);
+    assert_eq!(response.active_bootstrap_sessions, 2);
 
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_cliques() {
-    let addr: SocketAddr = "[::]:5002".parse().unwrap();
+async fn get_fee_parameters() {
+    let addr: SocketAddr = "[::]:5062".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
-
-    let mut consensus_ctrl = MockConsensusController::new();
-    consensus_ctrl
-        .expect_get_cliques()
-        .returning(|| vec![Clique::default()]);
-
-    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+    api_public.0.api_settings.max_read_only_gas = 2_000_000;
+    api_public.0.api_settings.minimal_fees = Amount::from_str("0.01").unwrap();
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -152,36 +199,23 @@ async fn get_cliques() {
             addr.to_string().split(':').last().unwrap()
         ))
         .unwrap();
-    let params = rpc_params![];
-    let response: Vec<massa_models::clique::Clique> =
-        client.request("get_cliques", params).await.unwrap();
 
-    assert_eq!(response.len(), 1);
+    let response: FeeParameters = client
+        .request("get_fee_parameters", rpc_params![])
+        .await
+        .unwrap();
+    assert_eq!(response.minimal_fee, Amount::from_str("0.01").unwrap());
+    assert_eq!(response.max_gas_per_block, config.max_gas_per_block);
+    assert_eq!(response.max_read_only_gas, 2_000_000);
 
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_operations() {
-    let addr: SocketAddr = "[::]:5003".parse().unwrap();
+async fn get_minimal_entry_balance() {
+    let addr: SocketAddr = "[::]:5095".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
-    let keypair = KeyPair::generate(0).unwrap();
-    let op = create_operation_with_expire_period(&keypair, 500000);
-
-    api_public.0.storage.store_operations(vec![op.clone()]);
-
-    let mut pool_ctrl = MockPoolController::new();
-    pool_ctrl
-        .expect_contains_operations()
-        .returning(|ids| ids.iter().map(|_id| true).collect());
-
-    let mut exec_ctrl = MockExecutionController::new();
-    exec_ctrl
-        .expect_get_ops_exec_status()
-        .returning(|op| op.iter().map(|_op| (Some(true), Some(true))).collect());
-
-    api_public.0.execution_controller = Box::new(exec_ctrl);
-    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+    api_public.0.api_settings.ledger_entry_base_cost = Amount::from_str("0.002").unwrap();
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -194,37 +228,21 @@ async fn get_operations() {
             addr.to_string().split(':').last().unwrap()
         ))
         .unwrap();
-    let params = rpc_params![vec![
-        OperationId::from_str("O1q4CBcuYo8YANEV34W4JRWVHrzcYns19VJfyAB7jT4qfitAnMC").unwrap(),
-        op.id
-    ]];
-    let response: Vec<OperationInfo> = client.request("get_operations", params).await.unwrap();
 
-    assert_eq!(response.len(), 1);
+    let response: Amount = client
+        .request("get_minimal_entry_balance", rpc_params![])
+        .await
+        .unwrap();
+    assert_eq!(response, Amount::from_str("0.002").unwrap());
 
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_endorsements() {
-    let addr: SocketAddr = "[::]:5005".parse().unwrap();
-    let (mut api_public, config) = start_public_api(addr);
-
-    let end = create_endorsement();
-    api_public.0.storage.store_endorsements(vec![end.clone()]);
-
-    let mut pool_ctrl = MockPoolController::new();
-    pool_ctrl
-        .expect_contains_endorsements()
-        .returning(|ids| ids.iter().map(|_| true).collect::<Vec<bool>>());
-
-    let mut consensus_ctrl = MockConsensusController::new();
-    consensus_ctrl
-        .expect_get_block_statuses()
-        .returning(|param| param.iter().map(|_| BlockGraphStatus::Final).collect());
-
-    api_public.0.consensus_controller = Box::new(consensus_ctrl);
-    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+async fn get_version_info() {
+    let addr: SocketAddr = "[::]:5080".parse().unwrap();
+    let (api_public, config) = start_public_api(addr);
+    let version = api_public.0.version;
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -238,47 +256,31 @@ async fn get_endorsements() {
         ))
         .unwrap();
 
-    let params = rpc_params![];
-    let response: Result<Vec<EndorsementInfo>, Error> =
-        client.request("get_endorsements", params.clone()).await;
-    assert!(response.unwrap_err().to_string().contains("Invalid params"));
-
-    let response: Vec<EndorsementInfo> = client
-        .request(
-            "get_endorsements",
-            rpc_params![vec![EndorsementId::from_str(
-                "E19dHCWcodoSppzEZbGccshMhNSxYDTFGthqo5LRa4QyaQbL8cw"
-            )
-            .unwrap()]],
-        )
-        .await
-        .unwrap();
-    assert!(response.is_empty());
-
-    let response: Vec<EndorsementInfo> = client
-        .request("get_endorsements", rpc_params![vec![end.id]])
+    // no version transition is registered in the MIP store
+    let response: VersionInfo = client
+        .request("get_version_info", rpc_params![])
         .await
         .unwrap();
-    assert!(response.len() == 1);
+    assert_eq!(response.version, version);
+    assert_eq!(response.current_network_version, 0);
+    assert_eq!(response.announced_network_version, None);
 
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_blocks() {
-    let addr: SocketAddr = "[::]:5006".parse().unwrap();
+async fn get_ledger_stats() {
+    let addr: SocketAddr = "[::]:5083".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
-    let keypair = KeyPair::generate(0).unwrap();
-    let block = create_block(&keypair);
-
-    api_public.0.storage.store_block(block.clone());
-
-    let mut consensus_ctrl = MockConsensusController::new();
-    consensus_ctrl
-        .expect_get_block_statuses()
-        .returning(|param| param.iter().map(|_| BlockGraphStatus::Final).collect());
 
-    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+    let stats = LedgerStats {
+        address_count: 2,
+        datastore_entry_count: 5,
+        byte_size: 1024,
+    };
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_ledger_stats().return_const(stats);
+    api_public.0.execution_controller = Box::new(exec_ctrl);
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -292,37 +294,105 @@ async fn get_blocks() {
         ))
         .unwrap();
 
-    let params = rpc_params![];
-    let response: Result<Vec<BlockInfo>, Error> =
-        client.request("get_blocks", params.clone()).await;
-    assert!(response.unwrap_err().to_string().contains("Invalid params"));
-
-    let response: Vec<BlockInfo> = client
-        .request("get_blocks", rpc_params![vec![block.id]])
+    let response: LedgerStats = client
+        .request("get_ledger_stats", rpc_params![])
         .await
         .unwrap();
-
-    assert_eq!(response[0].id, block.id);
+    assert_eq!(response, stats);
 
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_blockclique_block_by_slot() {
-    let addr: SocketAddr = "[::]:5007".parse().unwrap();
-    let (mut api_public, config) = start_public_api(addr);
+async fn response_compression() {
+    // send a raw request to see the response headers
+    async fn content_encoding(port: u16) -> Option<String> {
+        let request = hyper::Request::post(format!("http://localhost:{}", port))
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .header(hyper::header::ACCEPT_ENCODING, "gzip")
+            .body(hyper::Body::from(
+                r#"{"jsonrpc":"2.0","id":1,"method":"get_fee_parameters","params":[]}"#,
+            ))
+            .unwrap();
+        let response = hyper::Client::new().request(request).await.unwrap();
+        response
+            .headers()
+            .get(hyper::header::CONTENT_ENCODING)
+            .map(|value| value.to_str().unwrap().to_string())
+    }
+
+    // compression enabled, the response is above the threshold
+    let addr: SocketAddr = "[::]:5064".parse().unwrap();
+    let (api_public, mut config) = start_public_api(addr);
+    config.enable_response_compression = true;
+    config.compression_min_response_size = 16;
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+    assert_eq!(content_encoding(5064).await.as_deref(), Some("gzip"));
+    api_public_handle.stop().await;
 
-    let block = create_block(&KeyPair::generate(0).unwrap());
-    let id = block.id;
+    // compression enabled, the response is below the threshold
+    let addr: SocketAddr = "[::]:5065".parse().unwrap();
+    let (api_public, mut config) = start_public_api(addr);
+    config.enable_response_compression = true;
+    config.compression_min_response_size = u16::MAX;
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+    assert_eq!(content_encoding(5065).await, None);
+    api_public_handle.stop().await;
 
-    api_public.0.storage.store_block(block.clone());
+    // compression disabled
+    let addr: SocketAddr = "[::]:5066".parse().unwrap();
+    let (api_public, config) = start_public_api(addr);
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+    assert_eq!(content_encoding(5066).await, None);
+    api_public_handle.stop().await;
+}
 
-    let mut consensus_ctrl = MockConsensusController::new();
-    consensus_ctrl
-        .expect_get_blockclique_block_at_slot()
-        .returning(move |_s| Some(id));
+#[tokio::test]
+async fn get_health() {
+    let addr: SocketAddr = "[::]:5055".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
 
-    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+    // genesis was ten periods ago
+    let (thread_count, t0) = (config.thread_count, config.t0);
+    let genesis_timestamp = MassaTime::now()
+        .unwrap()
+        .saturating_sub(t0.checked_mul(10).unwrap());
+    api_public.0.api_settings.genesis_timestamp = genesis_timestamp;
+
+    // the execution is caught up on the first call, and stuck at genesis on the following ones
+    let call_count = std::sync::atomic::AtomicUsize::new(0);
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_stats().returning(move || {
+        let active_cursor = if call_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+            massa_models::timeslots::get_current_latest_block_slot(
+                thread_count,
+                t0,
+                genesis_timestamp,
+            )
+            .unwrap()
+            .unwrap_or_else(|| Slot::new(0, 0))
+        } else {
+            Slot::new(0, 0)
+        };
+        ExecutionStats {
+            time_window_start: MassaTime::now().unwrap(),
+            time_window_end: MassaTime::now().unwrap(),
+            final_block_count: 0,
+            final_executed_operations_count: 0,
+            active_cursor,
+            final_cursor: Slot::new(0, 0),
+        }
+    });
+    api_public.0.execution_controller = Box::new(exec_ctrl);
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -336,88 +406,83 @@ async fn get_blockclique_block_by_slot() {
         ))
         .unwrap();
 
-    let params = rpc_params![];
-    let response: Result<Option<Block>, Error> = client
-        .request("get_blockclique_block_by_slot", params.clone())
-        .await;
+    let response: NodeHealth = client.request("get_health", rpc_params![]).await.unwrap();
+    assert!(response.ready);
+    assert!(!response.syncing);
 
-    assert!(response.unwrap_err().to_string().contains("Invalid params"));
-    let response: Option<Block> = client
-        .request(
-            "get_blockclique_block_by_slot",
-            rpc_params![Slot {
-                period: 1,
-                thread: 0
-            }],
-        )
-        .await
-        .unwrap();
+    let response: NodeHealth = client.request("get_health", rpc_params![]).await.unwrap();
+    assert!(!response.ready);
+    assert!(response.syncing);
 
-    assert!(response.is_some());
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_graph_interval() {
-    let addr: SocketAddr = "[::]:5008".parse().unwrap();
+async fn get_status_cached() {
+    let addr: SocketAddr = "[::]:5052".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
+    api_public.0.api_settings.status_cache_ttl = MassaTime::from_millis(60000);
+
+    // the components are only queried once while the cached status is fresh
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_get_stats()
+        .times(1)
+        .returning(|| ExecutionStats {
+            time_window_start: MassaTime::now().unwrap(),
+            time_window_end: MassaTime::now().unwrap(),
+            final_block_count: 0,
+            final_executed_operations_count: 0,
+            active_cursor: Slot::new(0, 0),
+            final_cursor: Slot::new(0, 0),
+        });
 
     let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl.expect_get_stats().times(1).returning(|| {
+        Ok(ConsensusStats {
+            start_timespan: MassaTime::now().unwrap(),
+            end_timespan: MassaTime::now().unwrap(),
+            final_block_count: 50,
+            stale_block_count: 40,
+            clique_count: 30,
+        })
+    });
     consensus_ctrl
-        .expect_get_block_graph_status()
-        .returning(|_start, _end| {
-            let block = create_block(&KeyPair::generate(0).unwrap());
-            let id = block.id;
-
-            let mut active = PreHashMap::with_capacity(1);
-            active.insert(
-                id,
-                ExportCompiledBlock {
-                    header: block.content.header.clone(),
-                    children: vec![],
-                    is_final: false,
-                },
-            );
+        .expect_get_latest_final_slots()
+        .times(1)
+        .returning(Vec::new);
 
-            let mut discarded = PreHashMap::with_capacity(1);
-            discarded.insert(
-                id,
-                (
-                    massa_consensus_exports::block_status::DiscardReason::Invalid(
-                        "invalid".to_string(),
-                    ),
-                    (
-                        Slot::new(0, 0),
-                        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
-                            .unwrap(),
-                        vec![],
-                    ),
-                ),
-            );
+    let mut protocol_ctrl = MockProtocolController::new();
+    protocol_ctrl.expect_get_stats().times(1).returning(|| {
+        Ok((
+            NetworkStats {
+                in_connection_count: 10,
+                out_connection_count: 5,
+                known_peer_count: 6,
+                banned_peer_count: 0,
+                active_node_count: 15,
+            },
+            HashMap::new(),
+        ))
+    });
 
-            discarded.insert(
-                id,
-                (
-                    massa_consensus_exports::block_status::DiscardReason::Stale,
-                    (
-                        Slot::new(0, 0),
-                        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
-                            .unwrap(),
-                        vec![],
-                    ),
-                ),
-            );
-            Ok(BlockGraphExport {
-                genesis_blocks: vec![],
-                active_blocks: active,
-                discarded_blocks: discarded,
-                best_parents: vec![],
-                latest_final_blocks_periods: vec![],
-                gi_head: PreHashMap::with_capacity(1),
-                max_cliques: vec![Clique::default()],
-            })
-        });
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl
+        .expect_get_operation_count()
+        .times(1)
+        .returning(|| 1024);
+    pool_ctrl
+        .expect_get_endorsement_count()
+        .times(1)
+        .returning(|| 2048);
+    pool_ctrl
+        .expect_get_operation_count_per_thread()
+        .times(1)
+        .returning(|| vec![1024]);
 
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+    api_public.0.protocol_controller = Box::new(protocol_ctrl);
+    api_public.0.execution_controller = Box::new(exec_ctrl);
     api_public.0.consensus_controller = Box::new(consensus_ctrl);
 
     let api_public_handle = api_public
@@ -431,42 +496,76 @@ async fn get_graph_interval() {
             addr.to_string().split(':').last().unwrap()
         ))
         .unwrap();
+    let first: massa_api_exports::node::NodeStatus =
+        client.request("get_status", rpc_params![]).await.unwrap();
+    let second: massa_api_exports::node::NodeStatus =
+        client.request("get_status", rpc_params![]).await.unwrap();
+
+    assert_eq!(first.current_time, second.current_time);
+    assert_eq!(second.pool_stats.operation_count, 1024);
 
-    let params = rpc_params![TimeInterval {
-        start: Some(MassaTime::now().unwrap()),
-        end: Some(MassaTime::now().unwrap())
-    }];
-    let response: Vec<BlockSummary> = client
-        .request("get_graph_interval", params.clone())
-        .await
-        .unwrap();
-    assert!(response.len() == 2);
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn send_operations() {
-    let addr: SocketAddr = "[::]:5014".parse().unwrap();
+async fn get_status_retried() {
+    let addr: SocketAddr = "[::]:5102".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
+    assert_eq!(api_public.0.api_settings.status_retry_count, 1);
 
-    let mut pool_ctrl = MockPoolController::new();
-    pool_ctrl.expect_clone_box().returning(|| {
-        let mut pool_ctrl = MockPoolController::new();
-        pool_ctrl.expect_add_operations().returning(|_a| ());
-        Box::new(pool_ctrl)
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_stats().returning(|| ExecutionStats {
+        time_window_start: MassaTime::now().unwrap(),
+        time_window_end: MassaTime::now().unwrap(),
+        final_block_count: 0,
+        final_executed_operations_count: 0,
+        active_cursor: Slot::new(0, 0),
+        final_cursor: Slot::new(0, 0),
     });
 
+    // consensus fails once, then succeeds on the retry
+    let consensus_calls = std::sync::atomic::AtomicUsize::new(0);
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_stats()
+        .times(2)
+        .returning(move || {
+            if consensus_calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                return Err(ConsensusError::ContainerInconsistency(
+                    "switching cliques".to_string(),
+                ));
+            }
+            Ok(ConsensusStats {
+                start_timespan: MassaTime::now().unwrap(),
+                end_timespan: MassaTime::now().unwrap(),
+                final_block_count: 50,
+                stale_block_count: 40,
+                clique_count: 30,
+            })
+        });
+    consensus_ctrl
+        .expect_get_latest_final_slots()
+        .returning(Vec::new);
+
+    // protocol keeps failing: the error is reported once the retry is exhausted
     let mut protocol_ctrl = MockProtocolController::new();
-    protocol_ctrl.expect_clone_box().returning(|| {
-        let mut protocol_ctrl = MockProtocolController::new();
-        protocol_ctrl
-            .expect_propagate_operations()
-            .returning(|_a| Ok(()));
-        Box::new(protocol_ctrl)
+    protocol_ctrl.expect_get_stats().times(2).returning(|| {
+        Err(ProtocolError::GeneralProtocolError(
+            "network unavailable".to_string(),
+        ))
     });
 
-    api_public.0.protocol_controller = Box::new(protocol_ctrl);
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl.expect_get_operation_count().returning(|| 0);
+    pool_ctrl.expect_get_endorsement_count().returning(|| 0);
+    pool_ctrl
+        .expect_get_operation_count_per_thread()
+        .returning(Vec::new);
+
     api_public.0.pool_command_sender = Box::new(pool_ctrl);
+    api_public.0.protocol_controller = Box::new(protocol_ctrl);
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -479,57 +578,28 @@ async fn send_operations() {
             addr.to_string().split(':').last().unwrap()
         ))
         .unwrap();
-    let keypair = KeyPair::generate(0).unwrap();
-    let operation = create_operation_with_expire_period(&keypair, 500000);
-
-    let input: OperationInput = OperationInput {
-        creator_public_key: keypair.get_public_key(),
-        signature: operation.signature,
-        serialized_content: operation.serialized_data,
-    };
-
-    let response: Vec<OperationId> = client
-        .request("send_operations", rpc_params![vec![input]])
-        .await
-        .unwrap();
+    let response: Result<massa_api_exports::node::NodeStatus, Error> =
+        client.request("get_status", rpc_params![]).await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("network unavailable"));
 
-    assert_eq!(response.len(), 1);
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_filtered_sc_output_event() {
-    let addr: SocketAddr = "[::]:5013".parse().unwrap();
+async fn get_cliques() {
+    let addr: SocketAddr = "[::]:5002".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
 
-    let mut exec_ctrl = MockExecutionController::new();
-    exec_ctrl
-        .expect_get_filtered_sc_output_event()
-        .returning(|_a| {
-            vec![SCOutputEvent {
-                context: massa_models::output_event::EventExecutionContext {
-                    slot: Slot {
-                        period: 1,
-                        thread: 10,
-                    },
-                    block: None,
-                    read_only: false,
-                    index_in_slot: 1,
-                    call_stack: std::collections::VecDeque::new(),
-                    origin_operation_id: Some(
-                        massa_models::operation::OperationId::from_str(
-                            "O1q4CBcuYo8YANEV34W4JRWVHrzcYns19VJfyAB7jT4qfitAnMC",
-                        )
-                        .unwrap(),
-                    ),
-                    is_final: false,
-                    is_error: false,
-                },
-                data: "massa".to_string(),
-            }]
-        });
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_cliques()
+        .returning(|| vec![Clique::default()]);
+
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
 
-    api_public.0.execution_controller = Box::new(exec_ctrl);
     let api_public_handle = api_public
         .serve(&addr, &config)
         .await
@@ -541,56 +611,44 @@ async fn get_filtered_sc_output_event() {
             addr.to_string().split(':').last().unwrap()
         ))
         .unwrap();
+    let params = rpc_params![];
+    let response: Vec<massa_models::clique::Clique> =
+        client.request("get_cliques", params).await.unwrap();
 
-    let response: Result<Vec<SCOutputEvent>, Error> = client
-        .request("get_filtered_sc_output_event", rpc_params![])
-        .await;
-
-    // assert invalid params
-    assert!(response.unwrap_err().to_string().contains("Invalid params"));
-
-    let response: Result<Vec<SCOutputEvent>, Error> = client
-        .request(
-            "get_filtered_sc_output_event",
-            rpc_params![EventFilter {
-                start: Some(Slot {
-                    period: 1,
-                    thread: 1
-                }),
-                ..Default::default()
-            }],
-        )
-        .await;
+    assert_eq!(response.len(), 1);
+    assert_eq!(
+        response
+            .iter()
+            .filter(|clique| clique.is_blockclique)
+            .count(),
+        1
+    );
 
-    assert_eq!(response.unwrap().len(), 1);
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn execute_read_only_bytecode() {
-    let addr: SocketAddr = "[::]:5012".parse().unwrap();
+async fn get_operations() {
+    let addr: SocketAddr = "[::]:5003".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
+    let keypair = KeyPair::generate(0).unwrap();
+    let op = create_operation_with_expire_period(&keypair, 500000);
+
+    api_public.0.storage.store_operations(vec![op.clone()]);
+
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl
+        .expect_contains_operations()
+        .returning(|ids| ids.iter().map(|_id| true).collect());
 
     let mut exec_ctrl = MockExecutionController::new();
     exec_ctrl
-        .expect_execute_readonly_request()
-        .returning(|_req| {
-            Ok(ReadOnlyExecutionOutput {
-                out: massa_execution_exports::ExecutionOutput {
-                    slot: Slot {
-                        period: 1,
-                        thread: 5,
-                    },
-                    block_info: None,
-                    state_changes: massa_final_state::StateChanges::default(),
-                    events: massa_execution_exports::EventStore::default(),
-                },
-                gas_cost: 100,
-                call_result: "toto".as_bytes().to_vec(),
-            })
-        });
+        .expect_get_ops_exec_status()
+        .returning(|op| op.iter().map(|_op| (Some(true), Some(true))).collect());
 
     api_public.0.execution_controller = Box::new(exec_ctrl);
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+
     let api_public_handle = api_public
         .serve(&addr, &config)
         .await
@@ -602,78 +660,48 @@ async fn execute_read_only_bytecode() {
             addr.to_string().split(':').last().unwrap()
         ))
         .unwrap();
+    let params = rpc_params![vec![
+        OperationId::from_str("O1q4CBcuYo8YANEV34W4JRWVHrzcYns19VJfyAB7jT4qfitAnMC").unwrap(),
+        op.id
+    ]];
+    let response: Vec<OperationInfo> = client.request("get_operations", params).await.unwrap();
 
-    let params = rpc_params![vec![ReadOnlyBytecodeExecution {
-        max_gas: 100000,
-        bytecode: "hi".as_bytes().to_vec(),
-        address: Some(
-            Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap()
-        ),
-        operation_datastore: None,
-        is_final: false,
-        fee: None
-    }]];
-    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
-        .request("execute_read_only_bytecode", params.clone())
-        .await;
-
-    assert!(response.unwrap().len() == 1);
-
-    let params = rpc_params![vec![ReadOnlyBytecodeExecution {
-        max_gas: 100000,
-        bytecode: "hi".as_bytes().to_vec(),
-        address: None,
-        operation_datastore: None,
-        is_final: false,
-        fee: None,
-    }]];
-    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
-        .request("execute_read_only_bytecode", params.clone())
-        .await;
-
-    assert!(response.unwrap().len() == 1);
-
-    let params = rpc_params![vec![ReadOnlyBytecodeExecution {
-        max_gas: 100000,
-        bytecode: "hi".as_bytes().to_vec(),
-        address: None,
-        operation_datastore: Some("hi".as_bytes().to_vec()),
-        is_final: false,
-        fee: None
-    }]];
-    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
-        .request("execute_read_only_bytecode", params.clone())
-        .await;
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0].fee, op.content.fee);
+    assert_eq!(response[0].size_bytes, op.serialized_size());
 
-    assert!(response.is_err());
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn execute_read_only_call() {
-    let addr: SocketAddr = "[::]:5011".parse().unwrap();
+async fn get_operation() {
+    let addr: SocketAddr = "[::]:5088".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
+    let keypair = KeyPair::generate(0).unwrap();
+    let op = create_operation_with_expire_period(&keypair, 500000);
+
+    api_public.0.storage.store_operations(vec![op.clone()]);
+    let final_block = create_block_with_operations(&keypair, Slot::new(1, 0), vec![op.clone()]);
+    api_public.0.storage.store_block(final_block);
+
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_statuses()
+        .returning(|ids| ids.iter().map(|_| BlockGraphStatus::Final).collect());
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl
+        .expect_contains_operations()
+        .returning(|ids| ids.iter().map(|_id| false).collect());
 
     let mut exec_ctrl = MockExecutionController::new();
     exec_ctrl
-        .expect_execute_readonly_request()
-        .returning(|_req| {
-            Ok(ReadOnlyExecutionOutput {
-                out: massa_execution_exports::ExecutionOutput {
-                    slot: Slot {
-                        period: 1,
-                        thread: 5,
-                    },
-                    block_info: None,
-                    state_changes: massa_final_state::StateChanges::default(),
-                    events: massa_execution_exports::EventStore::default(),
-                },
-                gas_cost: 100,
-                call_result: "toto".as_bytes().to_vec(),
-            })
-        });
+        .expect_get_ops_exec_status()
+        .returning(|op| op.iter().map(|_op| (Some(true), Some(true))).collect());
 
     api_public.0.execution_controller = Box::new(exec_ctrl);
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -687,60 +715,63 @@ async fn execute_read_only_call() {
         ))
         .unwrap();
 
-    let params = rpc_params![];
-    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
-        .request("execute_read_only_call", params.clone())
-        .await;
-    assert!(response.unwrap_err().to_string().contains("Invalid params"));
+    let response: Option<OperationInfo> = client
+        .request("get_operation", rpc_params![op.id])
+        .await
+        .unwrap();
+    let info = response.unwrap();
+    assert_eq!(info.id, op.id);
+    assert_eq!(info.is_operation_final, Some(true));
+    assert!(!info.in_pool);
 
-    let params = rpc_params![vec![ReadOnlyCall {
-        max_gas: 1000000,
-        target_address: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
-            .unwrap(),
-        target_function: "hello".to_string(),
-        parameter: vec![],
-        caller_address: None,
-        is_final: false,
-        fee: None,
-        coins: None,
-    }]];
-    let response: Vec<ExecuteReadOnlyResponse> = client
-        .request("execute_read_only_call", params.clone())
+    // an unknown operation is not an error
+    let response: Option<OperationInfo> = client
+        .request(
+            "get_operation",
+            rpc_params![OperationId::from_str(
+                "O1q4CBcuYo8YANEV34W4JRWVHrzcYns19VJfyAB7jT4qfitAnMC"
+            )
+            .unwrap()],
+        )
         .await
         .unwrap();
+    assert!(response.is_none());
 
-    assert_eq!(response.len(), 1);
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_addresses() {
-    let addr: SocketAddr = "[::]:5010".parse().unwrap();
+async fn get_operations_finality_needs_final_block() {
+    let addr: SocketAddr = "[::]:5089".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
+    let keypair = KeyPair::generate(0).unwrap();
+    let op = create_operation_with_expire_period(&keypair, 500000);
 
-    let mut exec_ctrl = MockExecutionController::new();
-    exec_ctrl.expect_get_addresses_infos().returning(|a| {
-        a.iter()
-            .map(|_addr| ExecutionAddressInfo {
-                candidate_balance: Amount::from_str("100000").unwrap(),
-                final_balance: Amount::from_str("80000").unwrap(),
-                final_roll_count: 55,
-                final_datastore_keys: std::collections::BTreeSet::new(),
-                candidate_roll_count: 12,
-                candidate_datastore_keys: std::collections::BTreeSet::new(),
-                future_deferred_credits: BTreeMap::new(),
-                cycle_infos: vec![],
-            })
+    // while catching up, execution already reports the operation as final
+    // but consensus has not finalized the block including it yet
+    api_public.0.storage.store_operations(vec![op.clone()]);
+    let block = create_block_with_operations(&keypair, Slot::new(1, 0), vec![op.clone()]);
+    api_public.0.storage.store_block(block);
+
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl.expect_get_block_statuses().returning(|ids| {
+        ids.iter()
+            .map(|_| BlockGraphStatus::ActiveInBlockclique)
             .collect()
     });
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
 
-    let mut selector_ctrl = MockSelectorController::new();
-    selector_ctrl
-        .expect_get_available_selections_in_range()
-        .returning(|_range, _addrs| Ok(BTreeMap::new()));
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl
+        .expect_contains_operations()
+        .returning(|ids| ids.iter().map(|_id| false).collect());
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
 
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_get_ops_exec_status()
+        .returning(|op| op.iter().map(|_op| (Some(true), Some(true))).collect());
     api_public.0.execution_controller = Box::new(exec_ctrl);
-    api_public.0.selector_controller = Box::new(selector_ctrl);
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -754,42 +785,58 @@ async fn get_addresses() {
         ))
         .unwrap();
 
-    let params = rpc_params![];
-    let response: Result<Vec<AddressInfo>, Error> =
-        client.request("get_addresses", params.clone()).await;
-    assert!(response.unwrap_err().to_string().contains("Invalid params"));
-
-    let params = rpc_params![vec![Address::from_str(
-        "AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x"
-    )
-    .unwrap()]];
-    let response: Vec<AddressInfo> = client
-        .request("get_addresses", params.clone())
+    let response: Vec<OperationInfo> = client
+        .request("get_operations", rpc_params![vec![op.id]])
         .await
         .unwrap();
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0].is_operation_final, Some(false));
+    assert_eq!(response[0].op_exec_status, Some(true));
 
-    assert!(response.len() == 1);
+    // it is not returned when only final operations are requested
+    let response: Vec<OperationInfo> = client
+        .request("get_operations", rpc_params![vec![op.id], true])
+        .await
+        .unwrap();
+    assert!(response.is_empty());
 
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_addresses_bytecode() {
-    let addr: SocketAddr = "[::]:5019".parse().unwrap();
+async fn get_operations_finality_of_pruned_blocks() {
+    let addr: SocketAddr = "[::]:5112".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
+    let keypair = KeyPair::generate(0).unwrap();
+    let op_in_pruned_block = create_operation_with_expire_period(&keypair, 500000);
+    let op_without_block = create_operation_with_expire_period(&keypair, 500001);
+
+    // the block including the first operation was finalized long ago and pruned from consensus,
+    // the block including the second one is not retained anymore
+    api_public
+        .0
+        .storage
+        .store_operations(vec![op_in_pruned_block.clone(), op_without_block.clone()]);
+    let block =
+        create_block_with_operations(&keypair, Slot::new(1, 0), vec![op_in_pruned_block.clone()]);
+    api_public.0.storage.store_block(block);
 
-    let mut exec_ctrl: MockExecutionController = MockExecutionController::new();
-    exec_ctrl
-        .expect_query_state()
-        .returning(|_| ExecutionQueryResponse {
-            responses: vec![Ok(ExecutionQueryResponseItem::Bytecode(Bytecode(
-                "massa".as_bytes().to_vec(),
-            )))],
-            candidate_cursor: massa_models::slot::Slot::new(1, 2),
-            final_cursor: Slot::new(1, 7),
-            final_state_fingerprint: massa_hash::Hash::compute_from(&Vec::new()),
-        });
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_statuses()
+        .returning(|ids| ids.iter().map(|_| BlockGraphStatus::NotFound).collect());
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl
+        .expect_contains_operations()
+        .returning(|ids| ids.iter().map(|_id| false).collect());
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
 
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_get_ops_exec_status()
+        .returning(|op| op.iter().map(|_op| (Some(true), Some(true))).collect());
     api_public.0.execution_controller = Box::new(exec_ctrl);
 
     let api_public_handle = api_public
@@ -804,43 +851,119 @@ async fn get_addresses_bytecode() {
         ))
         .unwrap();
 
-    let params = rpc_params![];
-    let response: Result<Vec<Vec<u8>>, Error> = client
-        .request("get_addresses_bytecode", params.clone())
-        .await;
-    assert!(response.unwrap_err().to_string().contains("Invalid params"));
+    // the finality reported by execution is kept, also when only final operations are requested
+    let response: Vec<OperationInfo> = client
+        .request(
+            "get_operations",
+            rpc_params![vec![op_in_pruned_block.id, op_without_block.id], true],
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.len(), 2);
+    assert_eq!(response[0].id, op_in_pruned_block.id);
+    assert_eq!(response[0].in_blocks.len(), 1);
+    assert_eq!(response[0].is_operation_final, Some(true));
+    assert_eq!(response[1].id, op_without_block.id);
+    assert!(response[1].in_blocks.is_empty());
+    assert_eq!(response[1].is_operation_final, Some(true));
 
-    let params = rpc_params![vec![AddressFilter {
-        address: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
-            .unwrap(),
-        is_final: true
-    }]];
-    let response: Vec<Vec<u8>> = client
-        .request("get_addresses_bytecode", params.clone())
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn find_operations_by_prefix() {
+    let addr: SocketAddr = "[::]:5085".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    let keypair = KeyPair::generate(0).unwrap();
+    let ops: Vec<_> = (0..5)
+        .map(|i| create_operation_with_expire_period(&keypair, 500000 + i))
+        .collect();
+    api_public.0.storage.store_operations(ops.clone());
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
         .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
         .unwrap();
 
-    assert!(response.len() == 1);
+    // a long enough prefix matches a single operation
+    let id = ops[2].id;
+    let prefix = id.to_string()[..12].to_string();
+    let response: Vec<OperationId> = client
+        .request("find_operations_by_prefix", rpc_params![prefix, 10])
+        .await
+        .unwrap();
+    assert_eq!(response, vec![id]);
+
+    // every operation ID starts with the same letter, the limit applies to the sorted IDs
+    let mut sorted_ids: Vec<String> = ops.iter().map(|op| op.id.to_string()).collect();
+    sorted_ids.sort_unstable();
+    let response: Vec<OperationId> = client
+        .request("find_operations_by_prefix", rpc_params!["O", 3])
+        .await
+        .unwrap();
+    assert_eq!(
+        response.iter().map(|id| id.to_string()).collect::<Vec<_>>(),
+        sorted_ids[..3]
+    );
+
+    let response: Result<Vec<OperationId>, Error> = client
+        .request("find_operations_by_prefix", rpc_params!["", 3])
+        .await;
+    assert!(response.unwrap_err().to_string().contains("empty prefix"));
 
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_datastore_entries() {
-    let addr: SocketAddr = "[::]:5009".parse().unwrap();
+async fn get_operations_only_final() {
+    let addr: SocketAddr = "[::]:5059".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
+    let keypair = KeyPair::generate(0).unwrap();
+    let final_op = create_operation_with_expire_period(&keypair, 500000);
+    let candidate_op = create_operation_with_expire_period(&keypair, 500001);
+
+    api_public
+        .0
+        .storage
+        .store_operations(vec![final_op.clone(), candidate_op.clone()]);
+    let final_block =
+        create_block_with_operations(&keypair, Slot::new(1, 0), vec![final_op.clone()]);
+    api_public.0.storage.store_block(final_block);
+
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_statuses()
+        .returning(|ids| ids.iter().map(|_| BlockGraphStatus::Final).collect());
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
 
+    // the pool must not be queried
+    let pool_ctrl = MockPoolController::new();
+
+    let final_op_id = final_op.id;
     let mut exec_ctrl = MockExecutionController::new();
     exec_ctrl
-        .expect_get_final_and_active_data_entry()
-        .returning(|_a| {
-            vec![(
-                Some("massa".as_bytes().to_vec()),
-                Some("blockchain".as_bytes().to_vec()),
-            )]
+        .expect_get_ops_exec_status()
+        .returning(move |ops| {
+            ops.iter()
+                .map(|id| {
+                    if id == &final_op_id {
+                        (Some(true), Some(true))
+                    } else {
+                        (Some(true), None)
+                    }
+                })
+                .collect()
         });
 
     api_public.0.execution_controller = Box::new(exec_ctrl);
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -853,34 +976,134 @@ async fn get_datastore_entries() {
             addr.to_string().split(':').last().unwrap()
         ))
         .unwrap();
+    let params = rpc_params![vec![final_op.id, candidate_op.id], true];
+    let response: Vec<OperationInfo> = client.request("get_operations", params).await.unwrap();
 
-    let params = rpc_params![vec![DatastoreEntryInput {
-        address: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
-            .unwrap(),
-        key: "massa".as_bytes().to_vec()
-    }]];
-    let response: Vec<DatastoreEntryOutput> = client
-        .request("get_datastore_entries", params.clone())
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0].id, final_op.id);
+    assert!(!response[0].in_pool);
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_pool_operation_ids() {
+    let addr: SocketAddr = "[::]:5060".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.api_settings.max_arguments = 2;
+
+    let keypair = KeyPair::generate(0).unwrap();
+    let op_ids: Vec<OperationId> = (0..3)
+        .map(|i| create_operation_with_expire_period(&keypair, 500000 + i).id)
+        .collect();
+
+    // the requested limit is capped by max_arguments
+    let mut pool_ctrl = MockPoolController::new();
+    let pool_op_ids = op_ids.clone();
+    pool_ctrl
+        .expect_get_operation_ids()
+        .withf(|limit| *limit == 2)
+        .returning(move |limit| pool_op_ids.iter().take(limit).copied().collect());
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Vec<OperationId> = client
+        .request("get_pool_operation_ids", rpc_params![10])
         .await
         .unwrap();
+    assert_eq!(response, op_ids[..2]);
 
-    let entry = response.get(0).unwrap();
+    api_public_handle.stop().await;
+}
 
+#[tokio::test]
+async fn get_pending_denunciations() {
+    let addr: SocketAddr = "[::]:5100".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    // two endorsements of the same slot and index for different blocks
+    let keypair = KeyPair::generate(0).unwrap();
+    let slot = Slot::new(3, 7);
+    let endorsements: Vec<SecureShareEndorsement> = ["blk1", "blk2"]
+        .iter()
+        .map(|block| {
+            Endorsement::new_verifiable(
+                Endorsement {
+                    slot,
+                    index: 4,
+                    endorsed_block: BlockId::generate_from_hash(massa_hash::Hash::compute_from(
+                        block.as_bytes(),
+                    )),
+                },
+                EndorsementSerializer::new(),
+                &keypair,
+            )
+            .unwrap()
+        })
+        .collect();
+    let denunciation = Denunciation::try_from((&endorsements[0], &endorsements[1])).unwrap();
+
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl
+        .expect_get_pending_denunciations()
+        .withf(|limit| *limit == 128)
+        .returning(move |_limit| vec![denunciation.clone()]);
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Vec<PendingDenunciation> = client
+        .request("get_pending_denunciations", rpc_params![])
+        .await
+        .unwrap();
     assert_eq!(
-        entry.candidate_value.as_ref().unwrap(),
-        &"blockchain".as_bytes().to_vec()
-    );
-    assert_eq!(
-        entry.final_value.as_ref().unwrap(),
-        &"massa".as_bytes().to_vec()
+        response,
+        vec![PendingDenunciation {
+            address: Address::from_public_key(&keypair.get_public_key()),
+            slot,
+            index: Some(4),
+        }]
     );
+
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn wrong_api() {
-    let addr: SocketAddr = "[::]:5004".parse().unwrap();
-    let (api_public, config) = start_public_api(addr);
+async fn is_operation_in_pool() {
+    let addr: SocketAddr = "[::]:5072".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let keypair = KeyPair::generate(0).unwrap();
+    let pending_op = create_operation_with_expire_period(&keypair, 500000);
+    let unknown_op = create_operation_with_expire_period(&keypair, 500001);
+
+    let mut pool_ctrl = MockPoolController::new();
+    let pending_op_id = pending_op.id;
+    pool_ctrl
+        .expect_contains_operations()
+        .returning(move |ids| ids.iter().map(|id| id == &pending_op_id).collect());
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -894,236 +1117,4916 @@ async fn wrong_api() {
         ))
         .unwrap();
 
-    let params = rpc_params![];
-    let response: Result<(), Error> = client.request("stop_node", params.clone()).await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
-
-    let response: Result<(), Error> = client
-        .request("node_sign_message", rpc_params![Vec::<u8>::new()])
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    let response: Vec<bool> = client
+        .request(
+            "is_operation_in_pool",
+            rpc_params![vec![unknown_op.id, pending_op.id]],
+        )
+        .await
+        .unwrap();
+    assert_eq!(response, vec![false, true]);
 
-    let response: Result<(), Error> = client
+    // nothing to ask the pool
+    let response: Vec<bool> = client
         .request(
-            "remove_staking_addresses",
-            rpc_params![Vec::<Address>::new()],
+            "is_operation_in_pool",
+            rpc_params![Vec::<OperationId>::new()],
         )
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+        .await
+        .unwrap();
+    assert!(response.is_empty());
 
-    let response: Result<(), Error> = client
-        .request("get_staking_addresses", params.clone())
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    api_public_handle.stop().await;
+}
 
-    let response: Result<(), Error> = client
-        .request("node_ban_by_ip", rpc_params![Vec::<IpAddr>::new()])
-        .await;
+#[tokio::test]
+async fn get_endorsements() {
+    let addr: SocketAddr = "[::]:5005".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let end = create_endorsement();
+    let other_end = create_endorsement();
+    api_public
+        .0
+        .storage
+        .store_endorsements(vec![end.clone(), other_end.clone()]);
+
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl
+        .expect_contains_endorsements()
+        .returning(|ids| ids.iter().map(|_| true).collect::<Vec<bool>>());
+
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_statuses()
+        .returning(|param| param.iter().map(|_| BlockGraphStatus::Final).collect());
+
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![];
+    let response: Result<Vec<EndorsementInfo>, Error> =
+        client.request("get_endorsements", params.clone()).await;
+    assert!(response.unwrap_err().to_string().contains("Invalid params"));
+
+    let response: Vec<EndorsementInfo> = client
+        .request(
+            "get_endorsements",
+            rpc_params![vec![EndorsementId::from_str(
+                "E19dHCWcodoSppzEZbGccshMhNSxYDTFGthqo5LRa4QyaQbL8cw"
+            )
+            .unwrap()]],
+        )
+        .await
+        .unwrap();
+    assert!(response.is_empty());
+
+    let response: Vec<EndorsementInfo> = client
+        .request("get_endorsements", rpc_params![vec![end.id]])
+        .await
+        .unwrap();
+    assert!(response.len() == 1);
+
+    // results are in the order of the input ids
+    let response: Vec<EndorsementInfo> = client
+        .request("get_endorsements", rpc_params![vec![other_end.id, end.id]])
+        .await
+        .unwrap();
+    let ids: Vec<EndorsementId> = response.iter().map(|info| info.id).collect();
+    assert_eq!(ids, vec![other_end.id, end.id]);
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_blocks() {
+    let addr: SocketAddr = "[::]:5006".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    let keypair = KeyPair::generate(0).unwrap();
+    let block = create_block(&keypair);
+
+    api_public.0.storage.store_block(block.clone());
+
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_statuses()
+        .returning(|param| param.iter().map(|_| BlockGraphStatus::Final).collect());
+
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![];
+    let response: Result<Vec<BlockInfo>, Error> =
+        client.request("get_blocks", params.clone()).await;
+    assert!(response.unwrap_err().to_string().contains("Invalid params"));
+
+    let response: Vec<BlockInfo> = client
+        .request("get_blocks", rpc_params![vec![block.id]])
+        .await
+        .unwrap();
+
+    assert_eq!(response[0].id, block.id);
+    let content = response[0].content.as_ref().unwrap();
+    assert_eq!(content.current_version, 0);
+    assert_eq!(content.announced_version, None);
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_block_header() {
+    let addr: SocketAddr = "[::]:5071".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    let keypair = KeyPair::generate(0).unwrap();
+    let block = create_block(&keypair);
+    let unknown_block = create_block_with_operations(&keypair, Slot::new(2, 0), vec![]);
+
+    api_public.0.storage.store_block(block.clone());
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Option<SecuredHeader> = client
+        .request("get_block_header", rpc_params![block.id])
+        .await
+        .unwrap();
+    let header = response.unwrap();
+    let expected = &block.content.header;
+    assert_eq!(header.id, expected.id);
+    assert_eq!(header.content.slot, expected.content.slot);
+    assert_eq!(header.content.parents, expected.content.parents);
+    assert_eq!(
+        header.content_creator_address,
+        expected.content_creator_address
+    );
+    assert_eq!(header.serialized_data, expected.serialized_data);
+
+    let response: Option<SecuredHeader> = client
+        .request("get_block_header", rpc_params![unknown_block.id])
+        .await
+        .unwrap();
+    assert!(response.is_none());
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_blockclique_block_by_slot() {
+    let addr: SocketAddr = "[::]:5007".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let block = create_block(&KeyPair::generate(0).unwrap());
+    let id = block.id;
+
+    api_public.0.storage.store_block(block.clone());
+
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_blockclique_block_at_slot()
+        .returning(move |_s| Some(id));
+
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![];
+    let response: Result<Option<Block>, Error> = client
+        .request("get_blockclique_block_by_slot", params.clone())
+        .await;
+
+    assert!(response.unwrap_err().to_string().contains("Invalid params"));
+    let response: Option<Block> = client
+        .request(
+            "get_blockclique_block_by_slot",
+            rpc_params![Slot {
+                period: 1,
+                thread: 0
+            }],
+        )
+        .await
+        .unwrap();
+
+    assert!(response.is_some());
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_graph_interval() {
+    let addr: SocketAddr = "[::]:5008".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_graph_status()
+        .returning(|_start, _end| {
+            let block = create_block(&KeyPair::generate(0).unwrap());
+            let id = block.id;
+
+            let mut active = PreHashMap::with_capacity(1);
+            active.insert(
+                id,
+                ExportCompiledBlock {
+                    header: block.content.header.clone(),
+                    children: vec![],
+                    is_final: false,
+                },
+            );
+
+            let mut discarded = PreHashMap::with_capacity(1);
+            discarded.insert(
+                id,
+                (
+                    massa_consensus_exports::block_status::DiscardReason::Invalid(
+                        "invalid".to_string(),
+                    ),
+                    (
+                        Slot::new(0, 0),
+                        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+                            .unwrap(),
+                        vec![],
+                    ),
+                ),
+            );
+
+            discarded.insert(
+                id,
+                (
+                    massa_consensus_exports::block_status::DiscardReason::Stale,
+                    (
+                        Slot::new(0, 0),
+                        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+                            .unwrap(),
+                        vec![],
+                    ),
+                ),
+            );
+            Ok(BlockGraphExport {
+                genesis_blocks: vec![],
+                active_blocks: active,
+                discarded_blocks: discarded,
+                best_parents: vec![],
+                latest_final_blocks_periods: vec![],
+                gi_head: PreHashMap::with_capacity(1),
+                max_cliques: vec![Clique {
+                    block_ids: PreHashSet::from_iter([id]),
+                    ..Default::default()
+                }],
+            })
+        });
+
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let time_interval = TimeInterval {
+        start: Some(MassaTime::now().unwrap()),
+        end: Some(MassaTime::now().unwrap()),
+    };
+    let params = rpc_params![time_interval];
+    let response: Vec<BlockSummary> = client
+        .request("get_graph_interval", params.clone())
+        .await
+        .unwrap();
+    assert!(response.len() == 2);
+
+    // stale blocks are left out when only the blockclique is requested
+    let response: Vec<BlockSummary> = client
+        .request("get_graph_interval", rpc_params![time_interval, true])
+        .await
+        .unwrap();
+    assert_eq!(response.len(), 1);
+    assert!(response[0].is_in_blockclique);
+    assert!(!response[0].is_stale);
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_graph_interval_only_blockclique_keeps_final_blocks() {
+    let addr: SocketAddr = "[::]:5111".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let final_block = create_block(&KeyPair::generate(0).unwrap());
+    let tip = create_block(&KeyPair::generate(0).unwrap());
+    let fork = create_block(&KeyPair::generate(0).unwrap());
+    let (final_id, tip_id) = (final_block.id, tip.id);
+
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_graph_status()
+        .returning(move |_start, _end| {
+            let mut active = PreHashMap::with_capacity(3);
+            for (block, is_final) in [(&final_block, true), (&tip, false), (&fork, false)] {
+                active.insert(
+                    block.id,
+                    ExportCompiledBlock {
+                        header: block.content.header.clone(),
+                        children: vec![],
+                        is_final,
+                    },
+                );
+            }
+            // final blocks are removed from the cliques
+            Ok(BlockGraphExport {
+                genesis_blocks: vec![],
+                active_blocks: active,
+                discarded_blocks: PreHashMap::default(),
+                best_parents: vec![],
+                latest_final_blocks_periods: vec![],
+                gi_head: PreHashMap::default(),
+                max_cliques: vec![
+                    Clique {
+                        block_ids: PreHashSet::from_iter([tip.id]),
+                        fitness: 10,
+                        is_blockclique: true,
+                    },
+                    Clique {
+                        block_ids: PreHashSet::from_iter([fork.id]),
+                        fitness: 5,
+                        is_blockclique: false,
+                    },
+                ],
+            })
+        });
+
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let time_interval = TimeInterval {
+        start: Some(MassaTime::now().unwrap()),
+        end: Some(MassaTime::now().unwrap()),
+    };
+    let mut response: Vec<BlockSummary> = client
+        .request("get_graph_interval", rpc_params![time_interval, true])
+        .await
+        .unwrap();
+    response.sort_by_key(|block| !block.is_final);
+
+    // the final block and the blockclique tip are kept, the fork is left out
+    assert_eq!(response.len(), 2);
+    assert_eq!(response[0].id, final_id);
+    assert!(response[0].is_final);
+    assert_eq!(response[1].id, tip_id);
+    assert!(response[1].is_in_blockclique);
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_graph_interval_missing_blockclique() {
+    let addr: SocketAddr = "[::]:5050".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let graph_export = |is_blockclique: bool| BlockGraphExport {
+        genesis_blocks: vec![],
+        active_blocks: PreHashMap::default(),
+        discarded_blocks: PreHashMap::default(),
+        best_parents: vec![],
+        latest_final_blocks_periods: vec![],
+        gi_head: PreHashMap::default(),
+        max_cliques: vec![Clique {
+            is_blockclique,
+            ..Default::default()
+        }],
+    };
+
+    // the blockclique is missing on the first fetch, present on the second one,
+    // then missing on every following fetch
+    let fetch_count = std::sync::atomic::AtomicUsize::new(0);
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_graph_status()
+        .returning(move |_start, _end| {
+            let count = fetch_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(graph_export(count == 1))
+        });
+
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![TimeInterval {
+        start: None,
+        end: None
+    }];
+    // the fetch is retried once
+    let response: Vec<BlockSummary> = client
+        .request("get_graph_interval", params.clone())
+        .await
+        .unwrap();
+    assert!(response.is_empty());
+
+    // still missing after the retry: transient error
+    let response: Result<Vec<BlockSummary>, Error> =
+        client.request("get_graph_interval", params).await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("Service temporarily unavailable"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn failing_controllers() {
+    let addr: SocketAddr = "[::]:5081".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.consensus_controller = Box::new(failing_consensus_controller(|| {
+        ConsensusError::ContainerInconsistency("graph unavailable".to_string())
+    }));
+    api_public.0.execution_controller = Box::new(failing_execution_controller(|| {
+        ExecutionError::RuntimeError("vm unavailable".to_string())
+    }));
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    // consensus errors are reported as such to the client
+    let response: Result<Vec<BlockSummary>, Error> = client
+        .request(
+            "get_graph_interval",
+            rpc_params![TimeInterval {
+                start: None,
+                end: None
+            }],
+        )
+        .await;
+    let err = response.unwrap_err().to_string();
+    assert!(err.contains("consensus error"));
+    assert!(err.contains("graph unavailable"));
+
+    // read-only execution errors are part of the response
+    let response: Vec<ExecuteReadOnlyResponse> = client
+        .request(
+            "execute_read_only_call",
+            rpc_params![vec![ReadOnlyCall {
+                max_gas: 1_000_000,
+                target_address: Address::from_str(
+                    "AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x"
+                )
+                .unwrap(),
+                target_function: "hello".to_string(),
+                parameter: vec![],
+                caller_address: None,
+                is_final: false,
+                label: None,
+                coins: None,
+                fee: None,
+                trace: false,
+            }]],
+        )
+        .await
+        .unwrap();
+    match &response[0].result {
+        ReadOnlyResult::Error(err) => assert!(err.contains("vm unavailable")),
+        ReadOnlyResult::Ok(_) => panic!("expected a failed read-only call"),
+    }
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_graph_interval_range_too_large() {
+    let addr: SocketAddr = "[::]:5079".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.api_settings.max_graph_interval_slots = 64;
+    let t0 = api_public.0.api_settings.t0;
+    let thread_count = api_public.0.api_settings.thread_count;
+
+    // an open start is clamped to 64 slots, i.e. 2 periods, before the end
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_graph_status()
+        .returning(move |start, end| {
+            assert_eq!(end, Some(Slot::new(10, 0)));
+            assert_eq!(start, Some(Slot::new(10 - 64 / thread_count as u64, 0)));
+            Ok(BlockGraphExport {
+                genesis_blocks: vec![],
+                active_blocks: PreHashMap::default(),
+                discarded_blocks: PreHashMap::default(),
+                best_parents: vec![],
+                latest_final_blocks_periods: vec![],
+                gi_head: PreHashMap::default(),
+                max_cliques: vec![Clique {
+                    is_blockclique: true,
+                    ..Default::default()
+                }],
+            })
+        });
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+    let genesis_timestamp = api_public.0.api_settings.genesis_timestamp;
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let end = genesis_timestamp.saturating_add(t0.checked_mul(10).unwrap());
+    let response: Vec<BlockSummary> = client
+        .request(
+            "get_graph_interval",
+            rpc_params![TimeInterval {
+                start: None,
+                end: Some(end)
+            }],
+        )
+        .await
+        .unwrap();
+    assert!(response.is_empty());
+
+    // from genesis to far in the future
+    let response: Result<Vec<BlockSummary>, Error> = client
+        .request(
+            "get_graph_interval",
+            rpc_params![TimeInterval {
+                start: Some(genesis_timestamp),
+                end: Some(end.saturating_add(t0.checked_mul(1_000_000).unwrap()))
+            }],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("Requested range too large"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_recent_stale_blocks() {
+    let addr: SocketAddr = "[::]:5073".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.api_settings.max_arguments = 2;
+
+    let keypair = KeyPair::generate(0).unwrap();
+    let creator = Address::from_public_key(&keypair.get_public_key());
+    // stale blocks, most recent first
+    let stale_blocks: Vec<(BlockId, (Slot, Address, Vec<BlockId>))> = (1..=3)
+        .rev()
+        .map(|period| {
+            let block = create_block_with_operations(&keypair, Slot::new(period, 0), vec![]);
+            let parents = block.content.header.content.parents.clone();
+            (block.id, (Slot::new(period, 0), creator, parents))
+        })
+        .collect();
+    let expected_ids: Vec<BlockId> = stale_blocks.iter().take(2).map(|(id, _)| *id).collect();
+
+    // the requested limit is capped by max_arguments
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_recent_stale_blocks()
+        .withf(|limit| *limit == 2)
+        .returning(move |limit| stale_blocks.iter().take(limit).cloned().collect());
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Vec<BlockSummary> = client
+        .request("get_recent_stale_blocks", rpc_params![10])
+        .await
+        .unwrap();
+    assert_eq!(
+        response.iter().map(|b| b.id).collect::<Vec<_>>(),
+        expected_ids
+    );
+    assert!(response.iter().all(|b| b.is_stale && !b.is_final));
+    assert_eq!(response[0].slot, Slot::new(3, 0));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_recent_block_intervals() {
+    let addr: SocketAddr = "[::]:5077".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.api_settings.max_arguments = 2;
+    let t0 = api_public.0.api_settings.t0;
+
+    let keypair = KeyPair::generate(0).unwrap();
+    // blockclique blocks, oldest first, the one at period 2 with an unknown arrival time
+    let blocks: Vec<(BlockId, Slot, Option<MassaTime>)> = (1..=3)
+        .map(|period| {
+            let slot = Slot::new(period, 0);
+            (
+                create_block_with_operations(&keypair, slot, vec![]).id,
+                slot,
+                (period != 2).then(|| MassaTime::from_millis(1_250 * period)),
+            )
+        })
+        .collect();
+    let expected_ids: Vec<BlockId> = blocks.iter().skip(1).map(|(id, _, _)| *id).collect();
+
+    // the requested count is capped by max_arguments
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_recent_blockclique_blocks()
+        .withf(|count| *count == 2)
+        .returning(move |count| blocks[blocks.len() - count..].to_vec());
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Vec<BlockTimestamp> = client
+        .request("get_recent_block_intervals", rpc_params![10])
+        .await
+        .unwrap();
+    assert_eq!(
+        response.iter().map(|b| b.id).collect::<Vec<_>>(),
+        expected_ids
+    );
+    assert_eq!(response[0].slot, Slot::new(2, 0));
+    // consecutive slots of the same thread are one period apart
+    assert_eq!(
+        response[1].timestamp.saturating_sub(response[0].timestamp),
+        t0
+    );
+    // the arrival times are reported as given by consensus
+    assert_eq!(response[0].received_at, None);
+    assert_eq!(response[1].received_at, Some(MassaTime::from_millis(3_750)));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn send_operations() {
+    let addr: SocketAddr = "[::]:5014".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl.expect_clone_box().returning(|| {
+        let mut pool_ctrl = MockPoolController::new();
+        pool_ctrl
+            .expect_add_operations_sync()
+            .returning(|ops| ops.get_op_refs().clone());
+        Box::new(pool_ctrl)
+    });
+
+    let mut protocol_ctrl = MockProtocolController::new();
+    protocol_ctrl.expect_clone_box().returning(|| {
+        let mut protocol_ctrl = MockProtocolController::new();
+        protocol_ctrl
+            .expect_propagate_operations()
+            .returning(|_a| Ok(()));
+        Box::new(protocol_ctrl)
+    });
+
+    api_public.0.protocol_controller = Box::new(protocol_ctrl);
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+    let keypair = KeyPair::generate(0).unwrap();
+    let operation = create_operation_with_expire_period(&keypair, 500000);
+
+    let input: OperationInput = OperationInput {
+        creator_public_key: keypair.get_public_key(),
+        signature: operation.signature,
+        serialized_content: operation.serialized_data,
+    };
+
+    let response: Vec<(OperationId, bool)> = client
+        .request("send_operations", rpc_params![vec![input]])
+        .await
+        .unwrap();
+
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0], (operation.id, true));
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn send_operations_propagates_accepted_only() {
+    let addr: SocketAddr = "[::]:5118".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let keypair = KeyPair::generate(0).unwrap();
+    let accepted = create_operation_with_expire_period(&keypair, 500000);
+    let rejected = create_operation_with_expire_period(&keypair, 500001);
+    let accepted_id = accepted.id;
+
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl.expect_clone_box().returning(move || {
+        let mut pool_ctrl = MockPoolController::new();
+        pool_ctrl
+            .expect_add_operations_sync()
+            .returning(move |_ops| PreHashSet::from_iter([accepted_id]));
+        Box::new(pool_ctrl)
+    });
+
+    let mut protocol_ctrl = MockProtocolController::new();
+    protocol_ctrl.expect_clone_box().returning(move || {
+        let mut protocol_ctrl = MockProtocolController::new();
+        protocol_ctrl
+            .expect_propagate_operations()
+            .withf(move |ops| *ops.get_op_refs() == PreHashSet::from_iter([accepted_id]))
+            .times(1)
+            .returning(|_a| Ok(()));
+        Box::new(protocol_ctrl)
+    });
+
+    api_public.0.protocol_controller = Box::new(protocol_ctrl);
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let inputs: Vec<OperationInput> = [&accepted, &rejected]
+        .iter()
+        .map(|op| OperationInput {
+            creator_public_key: keypair.get_public_key(),
+            signature: op.signature,
+            serialized_content: op.serialized_data.clone(),
+        })
+        .collect();
+
+    let response: Vec<(OperationId, bool)> = client
+        .request("send_operations", rpc_params![inputs])
+        .await
+        .unwrap();
+
+    assert_eq!(response, vec![(accepted.id, true), (rejected.id, false)]);
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn send_operations_pool_timeout() {
+    let addr: SocketAddr = "[::]:5119".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl.expect_clone_box().returning(|| {
+        let mut pool_ctrl = MockPoolController::new();
+        pool_ctrl.expect_add_operations_sync().returning(|ops| {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            ops.get_op_refs().clone()
+        });
+        Box::new(pool_ctrl)
+    });
+
+    let mut protocol_ctrl = MockProtocolController::new();
+    protocol_ctrl.expect_clone_box().returning(|| {
+        let mut protocol_ctrl = MockProtocolController::new();
+        protocol_ctrl.expect_propagate_operations().times(0);
+        Box::new(protocol_ctrl)
+    });
+
+    api_public.0.protocol_controller = Box::new(protocol_ctrl);
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+    api_public.0.api_settings.pool_add_timeout = MassaTime::from_millis(50);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+    let keypair = KeyPair::generate(0).unwrap();
+    let operation = create_operation_with_expire_period(&keypair, 500000);
+
+    let input: OperationInput = OperationInput {
+        creator_public_key: keypair.get_public_key(),
+        signature: operation.signature,
+        serialized_content: operation.serialized_data,
+    };
+
+    let response: Result<Vec<(OperationId, bool)>, Error> = client
+        .request("send_operations", rpc_params![vec![input]])
+        .await;
+
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("the pool did not process the operations in time"));
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn send_operations_deserialize_error() {
+    let addr: SocketAddr = "[::]:5057".parse().unwrap();
+    let (api_public, config) = start_public_api(addr);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+    let keypair = KeyPair::generate(0).unwrap();
+    let operation = create_operation_with_expire_period(&keypair, 500000);
+
+    // the content is truncated in the middle of the operation type
+    let mut serialized_content = operation.serialized_data;
+    serialized_content.truncate(serialized_content.len() - 2);
+    let input = OperationInput {
+        creator_public_key: keypair.get_public_key(),
+        signature: operation.signature,
+        serialized_content,
+    };
+
+    let response: Result<Vec<(OperationId, bool)>, Error> = client
+        .request("send_operations", rpc_params![vec![input]])
+        .await;
+    let err = response.unwrap_err().to_string();
+    assert!(err.contains("Deserialization error"));
+    assert!(err.contains("at byte"));
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn validate_operations() {
+    let addr: SocketAddr = "[::]:5048".parse().unwrap();
+    let (api_public, config) = start_public_api(addr);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+    let keypair = KeyPair::generate(0).unwrap();
+    let operation = create_operation_with_expire_period(&keypair, 500000);
+    let other_operation = create_operation_with_expire_period(&keypair, 500001);
+
+    let valid_input = OperationInput {
+        creator_public_key: keypair.get_public_key(),
+        signature: operation.signature,
+        serialized_content: operation.serialized_data.clone(),
+    };
+    // signature of another operation
+    let bad_signature_input = OperationInput {
+        creator_public_key: keypair.get_public_key(),
+        signature: other_operation.signature,
+        serialized_content: operation.serialized_data,
+    };
+
+    let response: Vec<OperationValidity> = client
+        .request(
+            "validate_operations",
+            rpc_params![vec![valid_input, bad_signature_input]],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.len(), 2);
+    assert!(response[0].is_valid);
+    assert_eq!(response[0].id, Some(operation.id));
+    assert!(response[0].error.is_none());
+    assert!(!response[1].is_valid);
+    assert!(response[1].id.is_none());
+    assert!(response[1].error.is_some());
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn compute_operation_id() {
+    let addr: SocketAddr = "[::]:5103".parse().unwrap();
+    let (api_public, config) = start_public_api(addr);
+
+    // the pool is not involved
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+    let keypair = KeyPair::generate(0).unwrap();
+    let operation = create_operation_with_expire_period(&keypair, 500000);
+    let other_operation = create_operation_with_expire_period(&keypair, 500001);
+
+    let response: OperationId = client
+        .request(
+            "compute_operation_id",
+            rpc_params![OperationInput {
+                creator_public_key: keypair.get_public_key(),
+                signature: operation.signature,
+                serialized_content: operation.serialized_data.clone(),
+            }],
+        )
+        .await
+        .unwrap();
+    assert_eq!(response, operation.id);
+
+    // operations rejected by send_operations get no id
+    let response: Result<OperationId, Error> = client
+        .request(
+            "compute_operation_id",
+            rpc_params![OperationInput {
+                creator_public_key: keypair.get_public_key(),
+                signature: other_operation.signature,
+                serialized_content: operation.serialized_data,
+            }],
+        )
+        .await;
+    assert!(response.is_err());
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn simulate_operation() {
+    let addr: SocketAddr = "[::]:5076".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let keypair = KeyPair::generate(0).unwrap();
+    let target_addr =
+        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+    let operation = Operation::new_verifiable(
+        Operation {
+            fee: Amount::from_str("0.01").unwrap(),
+            expire_period: 500000,
+            op: OperationType::CallSC {
+                target_addr,
+                target_func: "transfer".to_string(),
+                param: vec![],
+                max_gas: 1_000_000,
+                coins: Amount::from_str("1").unwrap(),
+            },
+        },
+        OperationSerializer::new(),
+        &keypair,
+    )
+    .unwrap();
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_execute_readonly_request()
+        .returning(move |req| {
+            // the operation is run speculatively on the candidate state
+            assert!(!req.is_final);
+            assert_eq!(req.max_gas, 1_000_000);
+            let mut ledger_changes = LedgerChanges::default();
+            ledger_changes.0.insert(
+                target_addr,
+                SetUpdateOrDelete::Update(LedgerEntryUpdate {
+                    balance: SetOrKeep::Set(Amount::from_str("11").unwrap()),
+                    bytecode: SetOrKeep::Keep,
+                    datastore: BTreeMap::from([
+                        (b"counter".to_vec(), SetOrDelete::Set(vec![1])),
+                        (b"lock".to_vec(), SetOrDelete::Delete),
+                    ]),
+                }),
+            );
+            let state_changes = massa_final_state::StateChanges {
+                ledger_changes,
+                ..Default::default()
+            };
+            Ok(ReadOnlyExecutionOutput {
+                out: massa_execution_exports::ExecutionOutput {
+                    slot: Slot::new(1, 5),
+                    block_info: None,
+                    state_changes,
+                    events: massa_execution_exports::EventStore::default(),
+                },
+                gas_cost: 100,
+                call_result: vec![],
+                trace: None,
+            })
+        });
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let input = OperationInput {
+        creator_public_key: keypair.get_public_key(),
+        signature: operation.signature,
+        serialized_content: operation.serialized_data.clone(),
+    };
+    let response: OperationSimulation = client
+        .request("simulate_operation", rpc_params![input])
+        .await
+        .unwrap();
+    assert_eq!(response.id, operation.id);
+    assert_eq!(response.executed_at, Some(Slot::new(1, 5)));
+    assert_eq!(response.gas_cost, 100);
+    assert_eq!(
+        response.balance_changes,
+        vec![(target_addr, Amount::from_str("11").unwrap())]
+    );
+    assert_eq!(
+        response.datastore_changes,
+        vec![
+            (target_addr, b"counter".to_vec(), Some(vec![1])),
+            (target_addr, b"lock".to_vec(), None),
+        ]
+    );
+
+    // the read-only gas limit applies to simulations, even below the block gas limit
+    let operation = Operation::new_verifiable(
+        Operation {
+            fee: Amount::from_str("0.01").unwrap(),
+            expire_period: 500000,
+            op: OperationType::CallSC {
+                target_addr,
+                target_func: "transfer".to_string(),
+                param: vec![],
+                max_gas: config.max_read_only_gas + 1,
+                coins: Amount::from_str("1").unwrap(),
+            },
+        },
+        OperationSerializer::new(),
+        &keypair,
+    )
+    .unwrap();
+    let input = OperationInput {
+        creator_public_key: keypair.get_public_key(),
+        signature: operation.signature,
+        serialized_content: operation.serialized_data,
+    };
+    let response: Result<OperationSimulation, Error> = client
+        .request("simulate_operation", rpc_params![input])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("higher than the read-only gas limit"));
+
+    // roll operations cannot be simulated
+    let roll_buy = Operation::new_verifiable(
+        Operation {
+            fee: Amount::zero(),
+            expire_period: 500000,
+            op: OperationType::RollBuy { roll_count: 1 },
+        },
+        OperationSerializer::new(),
+        &keypair,
+    )
+    .unwrap();
+    let input = OperationInput {
+        creator_public_key: keypair.get_public_key(),
+        signature: roll_buy.signature,
+        serialized_content: roll_buy.serialized_data,
+    };
+    let response: Result<OperationSimulation, Error> = client
+        .request("simulate_operation", rpc_params![input])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("roll operations are not supported"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn simulate_operation_transaction() {
+    let addr: SocketAddr = "[::]:5115".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let keypair = KeyPair::generate(0).unwrap();
+    let sender = Address::from_public_key(&keypair.get_public_key());
+    let recipient =
+        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+    let transaction = |amount: &str| {
+        Operation::new_verifiable(
+            Operation {
+                fee: Amount::from_str("0.5").unwrap(),
+                expire_period: 500000,
+                op: OperationType::Transaction {
+                    recipient_address: recipient,
+                    amount: Amount::from_str(amount).unwrap(),
+                },
+            },
+            OperationSerializer::new(),
+            &keypair,
+        )
+        .unwrap()
+    };
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_get_final_and_candidate_balance()
+        .returning(move |addresses| {
+            // the candidate balances are used, the recipient has none yet
+            assert_eq!(addresses, [sender, recipient]);
+            vec![
+                (
+                    Some(Amount::from_str("100").unwrap()),
+                    Some(Amount::from_str("10").unwrap()),
+                ),
+                (None, None),
+            ]
+        });
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    // the sender pays the amount and the fee, the recipient receives the amount
+    let operation = transaction("3");
+    let input = OperationInput {
+        creator_public_key: keypair.get_public_key(),
+        signature: operation.signature,
+        serialized_content: operation.serialized_data.clone(),
+    };
+    let response: OperationSimulation = client
+        .request("simulate_operation", rpc_params![input])
+        .await
+        .unwrap();
+    assert_eq!(response.id, operation.id);
+    assert_eq!(response.executed_at, None);
+    assert_eq!(response.gas_cost, 0);
+    assert!(matches!(response.result, ReadOnlyResult::Ok(_)));
+    let mut expected = vec![
+        (sender, Amount::from_str("6.5").unwrap()),
+        (recipient, Amount::from_str("3").unwrap()),
+    ];
+    expected.sort_by_key(|(address, _)| *address);
+    assert_eq!(response.balance_changes, expected);
+    assert!(response.datastore_changes.is_empty());
+
+    // the candidate balance of the sender does not cover the amount and the fee
+    let operation = transaction("9.6");
+    let input = OperationInput {
+        creator_public_key: keypair.get_public_key(),
+        signature: operation.signature,
+        serialized_content: operation.serialized_data.clone(),
+    };
+    let response: OperationSimulation = client
+        .request("simulate_operation", rpc_params![input])
+        .await
+        .unwrap();
+    match response.result {
+        ReadOnlyResult::Error(err) => assert!(err.contains("insufficient balance")),
+        ReadOnlyResult::Ok(_) => panic!("the transaction should fail"),
+    }
+    assert!(response.balance_changes.is_empty());
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_filtered_sc_output_event() {
+    let addr: SocketAddr = "[::]:5013".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_get_filtered_sc_output_event()
+        .returning(|_a| {
+            vec![SCOutputEvent {
+                context: massa_models::output_event::EventExecutionContext {
+                    slot: Slot {
+                        period: 1,
+                        thread: 10,
+                    },
+                    block: None,
+                    read_only: false,
+                    index_in_slot: 1,
+                    call_stack: std::collections::VecDeque::new(),
+                    origin_operation_id: Some(
+                        massa_models::operation::OperationId::from_str(
+                            "O1q4CBcuYo8YANEV34W4JRWVHrzcYns19VJfyAB7jT4qfitAnMC",
+                        )
+                        .unwrap(),
+                    ),
+                    is_final: false,
+                    is_error: false,
+                },
+                data: "massa".to_string(),
+            }]
+        });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Result<Vec<SCOutputEvent>, Error> = client
+        .request("get_filtered_sc_output_event", rpc_params![])
+        .await;
+
+    // assert invalid params
+    assert!(response.unwrap_err().to_string().contains("Invalid params"));
+
+    let response: Result<Vec<SCOutputEvent>, Error> = client
+        .request(
+            "get_filtered_sc_output_event",
+            rpc_params![EventFilter {
+                start: Some(Slot {
+                    period: 1,
+                    thread: 1
+                }),
+                ..Default::default()
+            }],
+        )
+        .await;
+
+    assert_eq!(response.unwrap().len(), 1);
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn execute_read_only_bytecode_from_address() {
+    let addr: SocketAddr = "[::]:5056".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let contract =
+        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_query_state().returning(move |req| {
+        let response = match req.requests.as_slice() {
+            [ExecutionQueryRequestItem::AddressBytecodeCandidate(address)]
+                if *address == contract =>
+            {
+                Ok(ExecutionQueryResponseItem::Bytecode(Bytecode(
+                    "massa".as_bytes().to_vec(),
+                )))
+            }
+            _ => Err(ExecutionQueryError::NotFound("account".to_string())),
+        };
+        ExecutionQueryResponse {
+            responses: vec![response],
+            candidate_cursor: Slot::new(1, 2),
+            final_cursor: Slot::new(1, 7),
+            final_state_fingerprint: massa_hash::Hash::compute_from(&Vec::new()),
+        }
+    });
+    exec_ctrl
+        .expect_execute_readonly_request()
+        .returning(|req| {
+            // the bytecode of the address is executed
+            match req.target {
+                ReadOnlyExecutionTarget::BytecodeExecution(bytecode) => {
+                    assert_eq!(bytecode, "massa".as_bytes().to_vec())
+                }
+                _ => panic!("expected a bytecode execution"),
+            }
+            Ok(ReadOnlyExecutionOutput {
+                out: massa_execution_exports::ExecutionOutput {
+                    slot: Slot::new(1, 5),
+                    block_info: None,
+                    state_changes: massa_final_state::StateChanges::default(),
+                    events: massa_execution_exports::EventStore::default(),
+                },
+                gas_cost: 100,
+                call_result: vec![],
+                trace: None,
+            })
+        });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let request = |from_address: Address| ReadOnlyBytecodeExecution {
+        max_gas: 100000,
+        bytecode: vec![],
+        from_address: Some(from_address),
+        address: None,
+        operation_datastore: None,
+        is_final: false,
+        label: None,
+        fee: None,
+    };
+
+    let response: Vec<ExecuteReadOnlyResponse> = client
+        .request(
+            "execute_read_only_bytecode",
+            rpc_params![vec![request(contract)]],
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0].gas_cost, 100);
+
+    // an address without bytecode
+    let no_bytecode = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
+        .request(
+            "execute_read_only_bytecode",
+            rpc_params![vec![request(no_bytecode)]],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("has no bytecode"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn execute_read_only_bytecode() {
+    let addr: SocketAddr = "[::]:5012".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_execute_readonly_request()
+        .returning(|req| {
+            // the requested fee is passed on to the execution, to be deducted from the caller
+            if let Some(fee) = req.fee {
+                assert_eq!(fee, Amount::from_str("0.01").unwrap());
+            }
+            Ok(ReadOnlyExecutionOutput {
+                out: massa_execution_exports::ExecutionOutput {
+                    slot: Slot {
+                        period: 1,
+                        thread: 5,
+                    },
+                    block_info: None,
+                    state_changes: massa_final_state::StateChanges::default(),
+                    events: massa_execution_exports::EventStore::default(),
+                },
+                gas_cost: 100,
+                call_result: "toto".as_bytes().to_vec(),
+                trace: None,
+            })
+        });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![vec![ReadOnlyBytecodeExecution {
+        max_gas: 100000,
+        bytecode: "hi".as_bytes().to_vec(),
+        address: None,
+        operation_datastore: None,
+        is_final: false,
+        label: None,
+        fee: Some(Amount::from_str("0.01").unwrap()),
+        from_address: None,
+    }]];
+    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
+        .request("execute_read_only_bytecode", params.clone())
+        .await;
+
+    assert!(response.unwrap().len() == 1);
+
+    let params = rpc_params![vec![ReadOnlyBytecodeExecution {
+        max_gas: 100000,
+        bytecode: "hi".as_bytes().to_vec(),
+        address: Some(
+            Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap()
+        ),
+        operation_datastore: None,
+        is_final: false,
+        label: None,
+        fee: None,
+        from_address: None,
+    }]];
+    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
+        .request("execute_read_only_bytecode", params.clone())
+        .await;
+
+    assert!(response.unwrap().len() == 1);
+
+    let params = rpc_params![vec![ReadOnlyBytecodeExecution {
+        max_gas: 100000,
+        bytecode: "hi".as_bytes().to_vec(),
+        address: None,
+        operation_datastore: None,
+        is_final: false,
+        label: None,
+        fee: None,
+        from_address: None,
+    }]];
+    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
+        .request("execute_read_only_bytecode", params.clone())
+        .await;
+
+    assert!(response.unwrap().len() == 1);
+
+    let params = rpc_params![vec![ReadOnlyBytecodeExecution {
+        max_gas: 100000,
+        bytecode: "hi".as_bytes().to_vec(),
+        address: None,
+        operation_datastore: Some("hi".as_bytes().to_vec()),
+        is_final: false,
+        label: None,
+        fee: None,
+        from_address: None,
+    }]];
+    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
+        .request("execute_read_only_bytecode", params.clone())
+        .await;
+
+    assert!(response.is_err());
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn execute_read_only_call() {
+    let addr: SocketAddr = "[::]:5011".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    let default_caller = api_public.0.api_settings.read_only_default_caller;
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_execute_readonly_request()
+        .returning(move |req| {
+            // the label is only used to tag the logs of the execution
+            assert_eq!(req.label.as_deref(), Some("simulation-1"));
+            // no caller given: the fixed default caller is used, not a random address
+            assert_eq!(req.call_stack[0].address, default_caller);
+            assert_eq!(req.call_stack[0].owned_addresses, vec![default_caller]);
+            let trace = req.trace.then(|| {
+                vec![ExecutionTraceStep::Call {
+                    depth: 2,
+                    caller: default_caller,
+                    target: req.call_stack[1].address,
+                    function: Some("hello".to_string()),
+                    coins: Amount::zero(),
+                }]
+            });
+            Ok(ReadOnlyExecutionOutput {
+                out: massa_execution_exports::ExecutionOutput {
+                    slot: Slot {
+                        period: 1,
+                        thread: 5,
+                    },
+                    block_info: None,
+                    state_changes: massa_final_state::StateChanges::default(),
+                    events: massa_execution_exports::EventStore::default(),
+                },
+                gas_cost: 100,
+                call_result: "toto".as_bytes().to_vec(),
+                trace,
+            })
+        });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![];
+    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
+        .request("execute_read_only_call", params.clone())
+        .await;
+    assert!(response.unwrap_err().to_string().contains("Invalid params"));
+
+    let params = rpc_params![vec![ReadOnlyCall {
+        max_gas: 1000000,
+        target_address: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+            .unwrap(),
+        target_function: "hello".to_string(),
+        parameter: vec![],
+        caller_address: None,
+        is_final: false,
+        label: Some("simulation-1".to_string()),
+        fee: None,
+        coins: None,
+        trace: false,
+    }]];
+    let response: Vec<ExecuteReadOnlyResponse> = client
+        .request("execute_read_only_call", params.clone())
+        .await
+        .unwrap();
+
+    assert_eq!(response.len(), 1);
+    assert!(response[0].trace.is_none());
+
+    // the trace is only recorded on request
+    let params = rpc_params![vec![ReadOnlyCall {
+        max_gas: 1000000,
+        target_address: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+            .unwrap(),
+        target_function: "hello".to_string(),
+        parameter: vec![],
+        caller_address: None,
+        is_final: false,
+        label: Some("simulation-1".to_string()),
+        fee: None,
+        coins: None,
+        trace: true,
+    }]];
+    let response: Vec<ExecuteReadOnlyResponse> = client
+        .request("execute_read_only_call", params)
+        .await
+        .unwrap();
+    let trace = response[0].trace.as_ref().expect("missing trace");
+    assert!(matches!(
+        &trace[..],
+        [ExecutionTraceStep::Call { function: Some(function), .. }] if function == "hello"
+    ));
+    // the label is echoed in the response
+    assert_eq!(response[0].label.as_deref(), Some("simulation-1"));
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn execute_read_only_call_failure_keeps_trace() {
+    let addr: SocketAddr = "[::]:5114".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    let default_caller = api_public.0.api_settings.read_only_default_caller;
+
+    // the call fails after having recorded its first step
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_execute_readonly_request()
+        .returning(move |req| {
+            let target = req.call_stack[1].address;
+            let error = ExecutionError::NoBytecode(target);
+            if !req.trace {
+                return Err(error);
+            }
+            Err(ExecutionError::TracedError {
+                error: Box::new(error),
+                trace: vec![ExecutionTraceStep::Call {
+                    depth: 2,
+                    caller: default_caller,
+                    target,
+                    function: Some("hello".to_string()),
+                    coins: Amount::zero(),
+                }],
+            })
+        });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let call = |trace| ReadOnlyCall {
+        max_gas: 1000000,
+        target_address: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+            .unwrap(),
+        target_function: "hello".to_string(),
+        parameter: vec![],
+        caller_address: None,
+        is_final: false,
+        label: None,
+        fee: None,
+        coins: None,
+        trace,
+    };
+
+    let response: Vec<ExecuteReadOnlyResponse> = client
+        .request("execute_read_only_call", rpc_params![vec![call(true)]])
+        .await
+        .unwrap();
+    match &response[0].result {
+        ReadOnlyResult::Error(err) => assert!(err.contains("is not a smart contract")),
+        ReadOnlyResult::Ok(_) => panic!("the call should fail"),
+    }
+    let trace = response[0].trace.as_ref().expect("missing trace");
+    assert!(matches!(
+        &trace[..],
+        [ExecutionTraceStep::Call { function: Some(function), .. }] if function == "hello"
+    ));
+
+    // without the trace flag, a failure has no trace
+    let response: Vec<ExecuteReadOnlyResponse> = client
+        .request("execute_read_only_call", rpc_params![vec![call(false)]])
+        .await
+        .unwrap();
+    assert!(response[0].trace.is_none());
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn execute_read_only_bulk_call() {
+    let addr: SocketAddr = "[::]:5101".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    let caller = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+
+    // every call runs with the shared context, and returns the name of its function
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_execute_readonly_request()
+        .times(3)
+        .returning(move |req| {
+            assert_eq!(req.max_gas, 500_000);
+            assert_eq!(req.call_stack[0].address, caller);
+            assert_eq!(req.fee, Some(Amount::from_str("0.01").unwrap()));
+            assert!(req.is_final);
+            let ReadOnlyExecutionTarget::FunctionCall { target_func, .. } = req.target else {
+                panic!("expected a function call");
+            };
+            Ok(ReadOnlyExecutionOutput {
+                out: massa_execution_exports::ExecutionOutput {
+                    slot: Slot::new(1, 0),
+                    block_info: None,
+                    state_changes: massa_final_state::StateChanges::default(),
+                    events: massa_execution_exports::EventStore::default(),
+                },
+                gas_cost: 100,
+                call_result: target_func.into_bytes(),
+                trace: None,
+            })
+        });
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let target_address =
+        Address::from_str("AS12htxRWiEm8jDJpJptr6cwEhWNcCSFWstN1MLSa96DDkVM9Y42G").unwrap();
+    let functions = ["balanceOf", "name", "symbol"];
+    let response: Vec<ExecuteReadOnlyResponse> = client
+        .request(
+            "execute_read_only_bulk_call",
+            rpc_params![ReadOnlyBulkCall {
+                max_gas: 500_000,
+                caller_address: Some(caller),
+                fee: Some(Amount::from_str("0.01").unwrap()),
+                is_final: true,
+                label: None,
+                calls: functions
+                    .iter()
+                    .map(|function| ReadOnlyCallTarget {
+                        target_address,
+                        target_function: function.to_string(),
+                        parameter: vec![],
+                    })
+                    .collect(),
+            }],
+        )
+        .await
+        .unwrap();
+
+    // the responses are in the order of the calls
+    let results: Vec<Vec<u8>> = response
+        .into_iter()
+        .map(|response| match response.result {
+            ReadOnlyResult::Ok(result) => result,
+            ReadOnlyResult::Error(e) => panic!("unexpected error: {}", e),
+        })
+        .collect();
+    assert_eq!(
+        results,
+        functions
+            .iter()
+            .map(|function| function.as_bytes().to_vec())
+            .collect::<Vec<_>>()
+    );
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn execute_read_only_call_cost_overflow() {
+    let addr: SocketAddr = "[::]:5084".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    // invalid costs are rejected before reaching execution
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_execute_readonly_request().never();
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    // the read-only gas limit applies, even below the block gas limit
+    assert!(config.max_read_only_gas < config.max_gas_per_block);
+    let call = ReadOnlyCall {
+        max_gas: config.max_read_only_gas + 1,
+        target_address: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+            .unwrap(),
+        target_function: "hello".to_string(),
+        parameter: vec![],
+        caller_address: None,
+        is_final: false,
+        label: None,
+        fee: None,
+        coins: None,
+        trace: false,
+    };
+    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
+        .request("execute_read_only_call", rpc_params![vec![call.clone()]])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("higher than the read-only gas limit"));
+
+    let call = ReadOnlyCall {
+        max_gas: config.max_read_only_gas,
+        fee: Some(Amount::MAX),
+        coins: Some(Amount::MAX),
+        ..call
+    };
+    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
+        .request("execute_read_only_call", rpc_params![vec![call]])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("fee and coins overflow"));
+
+    let bytecode_execution = ReadOnlyBytecodeExecution {
+        max_gas: u64::MAX,
+        bytecode: "hi".as_bytes().to_vec(),
+        address: None,
+        from_address: None,
+        operation_datastore: None,
+        is_final: false,
+        label: None,
+        fee: Some(Amount::MAX),
+    };
+    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> = client
+        .request(
+            "execute_read_only_bytecode",
+            rpc_params![vec![bytecode_execution]],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("higher than the read-only gas limit"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn execute_read_only_call_no_bytecode() {
+    let addr: SocketAddr = "[::]:5061".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_execute_readonly_request()
+        .returning(|req| match req.target {
+            ReadOnlyExecutionTarget::FunctionCall { target_addr, .. } => {
+                Err(ExecutionError::NoBytecode(target_addr))
+            }
+            _ => panic!("unexpected read-only target"),
+        });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let target_address = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+    let params = rpc_params![vec![ReadOnlyCall {
+        max_gas: 1000000,
+        target_address,
+        target_function: "hello".to_string(),
+        parameter: vec![],
+        caller_address: None,
+        is_final: false,
+        label: None,
+        fee: None,
+        coins: None,
+        trace: false,
+    }]];
+    let response: Vec<ExecuteReadOnlyResponse> = client
+        .request("execute_read_only_call", params)
+        .await
+        .unwrap();
+
+    assert_eq!(response.len(), 1);
+    match &response[0].result {
+        ReadOnlyResult::Error(err) => assert!(err.contains(&format!(
+            "Target address {} is not a smart contract",
+            target_address
+        ))),
+        ReadOnlyResult::Ok(_) => panic!("expected an error"),
+    }
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn execute_read_only_call_deadline() {
+    let addr: SocketAddr = "[::]:5074".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.api_settings.read_only_deadline = MassaTime::from_millis(100);
+
+    // executions run on a clone of the controller, the `slow` function outlives the deadline
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_clone_box().returning(|| {
+        let mut exec_ctrl = MockExecutionController::new();
+        exec_ctrl
+            .expect_execute_readonly_request()
+            .returning(|req| match req.target {
+                ReadOnlyExecutionTarget::FunctionCall {
+                    target_addr,
+                    target_func,
+                    ..
+                } => {
+                    if target_func == "slow" {
+                        std::thread::sleep(std::time::Duration::from_millis(1000));
+                    }
+                    Err(ExecutionError::NoBytecode(target_addr))
+                }
+                _ => panic!("unexpected read-only target"),
+            });
+        Box::new(exec_ctrl)
+    });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let target_address = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+    let call = |target_function: &str| ReadOnlyCall {
+        max_gas: 1000000,
+        target_address,
+        target_function: target_function.to_string(),
+        parameter: vec![],
+        caller_address: None,
+        is_final: false,
+        label: None,
+        fee: None,
+        coins: None,
+        trace: false,
+    };
+    let response: Vec<ExecuteReadOnlyResponse> = client
+        .request(
+            "execute_read_only_call",
+            rpc_params![vec![call("fast"), call("slow")]],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.len(), 2);
+    match &response[0].result {
+        ReadOnlyResult::Error(err) => assert!(err.contains("is not a smart contract")),
+        ReadOnlyResult::Ok(_) => panic!("expected an error"),
+    }
+    match &response[1].result {
+        ReadOnlyResult::Error(err) => assert_eq!(err, "deadline exceeded"),
+        ReadOnlyResult::Ok(_) => panic!("expected the deadline to be exceeded"),
+    }
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn execute_read_only_call_deadline_keeps_permit() {
+    let addr: SocketAddr = "[::]:5107".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.api_settings.read_only_deadline = MassaTime::from_millis(100);
+    // a single request at a time
+    api_public.0.admission = RequestAdmission::new(1);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_clone_box().returning(|| {
+        let mut exec_ctrl = MockExecutionController::new();
+        exec_ctrl
+            .expect_execute_readonly_request()
+            .returning(|req| match req.target {
+                ReadOnlyExecutionTarget::FunctionCall { target_addr, .. } => {
+                    std::thread::sleep(std::time::Duration::from_millis(1000));
+                    Err(ExecutionError::NoBytecode(target_addr))
+                }
+                _ => panic!("unexpected read-only target"),
+            });
+        Box::new(exec_ctrl)
+    });
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let call = ReadOnlyCall {
+        max_gas: 1000000,
+        target_address: Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key()),
+        target_function: "slow".to_string(),
+        parameter: vec![],
+        caller_address: None,
+        is_final: false,
+        label: None,
+        fee: None,
+        coins: None,
+        trace: false,
+    };
+    let response: Vec<ExecuteReadOnlyResponse> = client
+        .request("execute_read_only_call", rpc_params![vec![call]])
+        .await
+        .unwrap();
+    match &response[0].result {
+        ReadOnlyResult::Error(err) => assert_eq!(err, "deadline exceeded"),
+        ReadOnlyResult::Ok(_) => panic!("expected the deadline to be exceeded"),
+    }
+
+    // the execution still runs in the background and holds the only permit
+    let response: Result<ExecutionSlots, Error> =
+        client.request("get_execution_slots", rpc_params![]).await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("too many requests in progress"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn stop_graceful() {
+    let addr: SocketAddr = "[::]:5087".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    // the read-only call is still running when the server is asked to stop
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_clone_box().returning(|| {
+        let mut exec_ctrl = MockExecutionController::new();
+        exec_ctrl
+            .expect_execute_readonly_request()
+            .returning(|req| match req.target {
+                ReadOnlyExecutionTarget::FunctionCall { target_addr, .. } => {
+                    std::thread::sleep(std::time::Duration::from_millis(500));
+                    Err(ExecutionError::NoBytecode(target_addr))
+                }
+                _ => panic!("unexpected read-only target"),
+            });
+        Box::new(exec_ctrl)
+    });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let url = format!(
+        "http://localhost:{}",
+        addr.to_string().split(':').last().unwrap()
+    );
+    let client = HttpClientBuilder::default().build(url.clone()).unwrap();
+    let in_flight = tokio::spawn(async move {
+        let call = ReadOnlyCall {
+            max_gas: 1000000,
+            target_address: Address::from_public_key(
+                &KeyPair::generate(0).unwrap().get_public_key(),
+            ),
+            target_function: "slow".to_string(),
+            parameter: vec![],
+            caller_address: None,
+            is_final: false,
+            label: None,
+            fee: None,
+            coins: None,
+            trace: false,
+        };
+        client
+            .request::<Vec<ExecuteReadOnlyResponse>, _>(
+                "execute_read_only_call",
+                rpc_params![vec![call]],
+            )
+            .await
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    api_public_handle
+        .stop_graceful(std::time::Duration::from_secs(5))
+        .await;
+
+    // the in-flight request completed, new ones are refused
+    assert_eq!(in_flight.await.unwrap().unwrap().len(), 1);
+    let client = HttpClientBuilder::default().build(url).unwrap();
+    let response: Result<FeeParameters, Error> =
+        client.request("get_fee_parameters", rpc_params![]).await;
+    assert!(response.is_err());
+}
+
+#[tokio::test]
+async fn stop_graceful_timeout() {
+    let addr: SocketAddr = "[::]:5113".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    // the execution runs apart from the request, which waits for it
+    api_public.0.api_settings.read_only_deadline = MassaTime::from_millis(10_000);
+
+    // the read-only call hangs well past the stop timeout and the end of the test
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_clone_box().returning(|| {
+        let mut exec_ctrl = MockExecutionController::new();
+        exec_ctrl
+            .expect_execute_readonly_request()
+            .returning(|req| match req.target {
+                ReadOnlyExecutionTarget::FunctionCall { target_addr, .. } => {
+                    std::thread::sleep(std::time::Duration::from_secs(60));
+                    Err(ExecutionError::NoBytecode(target_addr))
+                }
+                _ => panic!("unexpected read-only target"),
+            });
+        Box::new(exec_ctrl)
+    });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+    let in_flight = tokio::spawn(async move {
+        let call = ReadOnlyCall {
+            max_gas: 1000000,
+            target_address: Address::from_public_key(
+                &KeyPair::generate(0).unwrap().get_public_key(),
+            ),
+            target_function: "slow".to_string(),
+            parameter: vec![],
+            caller_address: None,
+            is_final: false,
+            label: None,
+            fee: None,
+            coins: None,
+            trace: false,
+        };
+        client
+            .request::<Vec<ExecuteReadOnlyResponse>, _>(
+                "execute_read_only_call",
+                rpc_params![vec![call]],
+            )
+            .await
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    // the server is aborted once the timeout expires, without waiting for the request
+    let start = std::time::Instant::now();
+    api_public_handle
+        .stop_graceful(std::time::Duration::from_millis(200))
+        .await;
+    assert!(start.elapsed() < std::time::Duration::from_secs(2));
+
+    // dropping the handle aborted the server: the connection of the hung request is closed
+    let response = tokio::time::timeout(std::time::Duration::from_secs(2), in_flight)
+        .await
+        .expect("the hung request was not aborted")
+        .unwrap();
+    assert!(response.is_err());
+}
+
+#[tokio::test]
+async fn get_next_block_draw() {
+    let addr: SocketAddr = "[::]:5049".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let staker =
+        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+    let other = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+
+    let mut selector_ctrl = MockSelectorController::new();
+    selector_ctrl
+        .expect_get_selection()
+        .returning(move |_slot| {
+            Ok(Selection {
+                endorsements: vec![],
+                producer: other,
+            })
+        });
+    selector_ctrl
+        .expect_get_available_selections_in_range()
+        .returning(move |_range, _addrs| {
+            Ok(BTreeMap::from([
+                // the staker only endorses at the first slot
+                (
+                    Slot::new(3, 0),
+                    Selection {
+                        endorsements: vec![staker],
+                        producer: other,
+                    },
+                ),
+                (
+                    Slot::new(4, 1),
+                    Selection {
+                        endorsements: vec![],
+                        producer: staker,
+                    },
+                ),
+                (
+                    Slot::new(6, 2),
+                    Selection {
+                        endorsements: vec![],
+                        producer: staker,
+                    },
+                ),
+            ]))
+        });
+
+    api_public.0.selector_controller = Box::new(selector_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Option<Slot> = client
+        .request("get_next_block_draw", rpc_params![staker])
+        .await
+        .unwrap();
+    assert_eq!(response, Some(Slot::new(4, 1)));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_next_block_draw_selector_failure() {
+    let addr: SocketAddr = "[::]:5116".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.selector_controller = Box::new(failing_selector_controller(|| {
+        PosError::ChannelDown("selector stopped".to_string())
+    }));
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    // a selector failure is reported, not mistaken for the address not being drawn
+    let staker =
+        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+    let response: Result<Option<Slot>, Error> = client
+        .request("get_next_block_draw", rpc_params![staker])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("selector stopped"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_active_staking_addresses() {
+    let addr: SocketAddr = "[::]:5053".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let wallet_dir = tempfile::tempdir().expect("cannot create temp dir");
+    let mut wallet = Wallet::new(wallet_dir.path().to_path_buf(), "password".to_string()).unwrap();
+    let staking_addresses = wallet
+        .add_keypairs(vec![KeyPair::generate(0).unwrap()])
+        .unwrap();
+    api_public.0.node_wallet = Arc::new(RwLock::new(wallet));
+    api_public.0.api_settings.expose_staking_addresses = true;
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: PreHashSet<Address> = client
+        .request("get_active_staking_addresses", rpc_params![])
+        .await
+        .unwrap();
+    assert_eq!(response, staking_addresses.into_iter().collect());
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn slot_timestamp_conversions() {
+    let addr: SocketAddr = "[::]:5054".parse().unwrap();
+    let (api_public, config) = start_public_api(addr);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let slot = Slot::new(12, 5);
+    let timestamp: MassaTime = client
+        .request("get_slot_timestamp", rpc_params![slot])
+        .await
+        .unwrap();
+    assert_eq!(
+        timestamp,
+        massa_models::timeslots::get_block_slot_timestamp(
+            config.thread_count,
+            config.t0,
+            config.genesis_timestamp,
+            slot
+        )
+        .unwrap()
+    );
+
+    let response: Slot = client
+        .request("get_timestamp_slot", rpc_params![timestamp])
+        .await
+        .unwrap();
+    assert_eq!(response, slot);
+
+    let response: Result<MassaTime, Error> = client
+        .request(
+            "get_slot_timestamp",
+            rpc_params![Slot::new(1, config.thread_count)],
+        )
+        .await;
+    assert!(response.is_err());
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_address_threads() {
+    let addr: SocketAddr = "[::]:5051".parse().unwrap();
+    let (api_public, config) = start_public_api(addr);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let addresses: Vec<Address> = (0..4)
+        .map(|_| Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key()))
+        .collect();
+    let response: Vec<u8> = client
+        .request("get_address_threads", rpc_params![addresses.clone()])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        response,
+        addresses
+            .iter()
+            .map(|address| address.get_thread(config.thread_count))
+            .collect::<Vec<u8>>()
+    );
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn parse_address() {
+    let addr: SocketAddr = "[::]:5097".parse().unwrap();
+    let (api_public, config) = start_public_api(addr);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let user_address = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+    let response: ParsedAddress = client
+        .request("parse_address", rpc_params![user_address.to_string()])
+        .await
+        .unwrap();
+    assert_eq!(response.address, user_address);
+    assert_eq!(
+        response.thread,
+        user_address.get_thread(config.thread_count)
+    );
+    assert_eq!(response.category, AddressCategory::UserAddress);
+
+    let sc_address = "AS12htxRWiEm8jDJpJptr6cwEhWNcCSFWstN1MLSa96DDkVM9Y42G";
+    let response: ParsedAddress = client
+        .request("parse_address", rpc_params![sc_address])
+        .await
+        .unwrap();
+    assert_eq!(response.address, Address::from_str(sc_address).unwrap());
+    assert_eq!(response.category, AddressCategory::SCAddress);
+
+    // invalid addresses are reported as bad requests
+    let response: Result<ParsedAddress, Error> = client
+        .request("parse_address", rpc_params!["AU1notanaddress"])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("invalid address AU1notanaddress"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_addresses() {
+    let addr: SocketAddr = "[::]:5010".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_addresses_infos().returning(|a| {
+        a.iter()
+            .map(|_addr| ExecutionAddressInfo {
+                candidate_balance: Amount::from_str("100000").unwrap(),
+                final_balance: Amount::from_str("80000").unwrap(),
+                final_roll_count: 55,
+                final_datastore_keys: std::collections::BTreeSet::new(),
+                candidate_roll_count: 12,
+                candidate_datastore_keys: std::collections::BTreeSet::new(),
+                has_bytecode: false,
+                future_deferred_credits: BTreeMap::new(),
+                cycle_infos: vec![],
+                roll_schedule: vec![ScheduledRollCount {
+                    cycle: 7,
+                    active_rolls: 3,
+                    is_final: false,
+                }],
+            })
+            .collect()
+    });
+
+    let mut selector_ctrl = MockSelectorController::new();
+    selector_ctrl.expect_get_selection().returning(|_slot| {
+        Ok(Selection {
+            endorsements: vec![],
+            producer: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+                .unwrap(),
+        })
+    });
+    selector_ctrl
+        .expect_get_available_selections_in_range()
+        .returning(|_range, _addrs| Ok(BTreeMap::new()));
+
+    // one operation of the creator is still in the pool, the other one is not
+    let creator = KeyPair::generate(0).unwrap();
+    let creator_address = Address::from_public_key(&creator.get_public_key());
+    let pending_op = create_operation_with_expire_period(&creator, 500000);
+    let pending_op_id = pending_op.id;
+    let included_op = create_operation_with_expire_period(&creator, 500001);
+    api_public
+        .0
+        .storage
+        .store_operations(vec![pending_op, included_op]);
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl
+        .expect_contains_operations()
+        .returning(move |ids| ids.iter().map(|id| id == &pending_op_id).collect());
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+    api_public.0.selector_controller = Box::new(selector_ctrl);
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![];
+    let response: Result<Vec<AddressInfo>, Error> =
+        client.request("get_addresses", params.clone()).await;
+    assert!(response.unwrap_err().to_string().contains("Invalid params"));
+
+    let response: Vec<AddressInfo> = client
+        .request("get_addresses", rpc_params![vec![creator_address]])
+        .await
+        .unwrap();
+    assert_eq!(response[0].created_operations.len(), 2);
+    assert_eq!(response[0].pool_operations, vec![pending_op_id]);
+    assert!(!response[0].is_contract);
+    assert_eq!(response[0].roll_schedule.len(), 1);
+    assert_eq!(response[0].roll_schedule[0].cycle, 7);
+    assert_eq!(response[0].roll_schedule[0].active_rolls, 3);
+
+    // the label tags the node logs and is echoed in the results
+    assert!(response[0].label.is_none());
+    let labeled_response: Vec<AddressInfo> = client
+        .request(
+            "get_addresses",
+            rpc_params![vec![creator_address], "support-1234"],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        labeled_response[0].created_operations,
+        response[0].created_operations
+    );
+    assert_eq!(labeled_response[0].label.as_deref(), Some("support-1234"));
+
+    let params = rpc_params![vec![Address::from_str(
+        "AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x"
+    )
+    .unwrap()]];
+    let response: Vec<AddressInfo> = client
+        .request("get_addresses", params.clone())
+        .await
+        .unwrap();
+
+    assert!(response.len() == 1);
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_addresses_execution_not_ready() {
+    let addr: SocketAddr = "[::]:5068".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    // execution module not ready: no info is returned for the requested addresses
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_get_addresses_infos()
+        .returning(|_a| Vec::new());
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![vec![Address::from_str(
+        "AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x"
+    )
+    .unwrap()]];
+    let response: Result<Vec<AddressInfo>, Error> = client.request("get_addresses", params).await;
+    let err = response.unwrap_err().to_string();
+    assert!(err.contains("Service temporarily unavailable"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_addresses_best_effort() {
+    let addr: SocketAddr = "[::]:5108".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    // neither execution nor the selector can answer
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_get_addresses_infos()
+        .returning(|_a| Vec::new());
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+    api_public.0.selector_controller = Box::new(failing_selector_controller(|| {
+        PosError::CycleUnavailable(0)
+    }));
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    // the failing sub-queries are reported, the storage info is still returned
+    let address =
+        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+    let response: Vec<AddressInfo> = client
+        .request(
+            "get_addresses",
+            rpc_params![vec![address], Option::<String>::None, true],
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0].address, address);
+    assert!(response[0].created_operations.is_empty());
+    assert!(response[0].next_block_draws.is_empty());
+    assert_eq!(
+        response[0].unavailable.keys().collect::<Vec<_>>(),
+        vec!["execution", "selector"]
+    );
+
+    // unavailable balances and rolls are null rather than zero
+    assert_eq!(response[0].final_balance, None);
+    assert_eq!(response[0].candidate_balance, None);
+    assert_eq!(response[0].final_roll_count, None);
+    assert_eq!(response[0].candidate_roll_count, None);
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_addresses_bytecode() {
+    let addr: SocketAddr = "[::]:5019".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl: MockExecutionController = MockExecutionController::new();
+    exec_ctrl
+        .expect_query_state()
+        .returning(|_| ExecutionQueryResponse {
+            responses: vec![Ok(ExecutionQueryResponseItem::Bytecode(Bytecode(
+                "massa".as_bytes().to_vec(),
+            )))],
+            candidate_cursor: massa_models::slot::Slot::new(1, 2),
+            final_cursor: Slot::new(1, 7),
+            final_state_fingerprint: massa_hash::Hash::compute_from(&Vec::new()),
+        });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![];
+    let response: Result<Vec<Vec<u8>>, Error> = client
+        .request("get_addresses_bytecode", params.clone())
+        .await;
+    assert!(response.unwrap_err().to_string().contains("Invalid params"));
+
+    let params = rpc_params![vec![AddressFilter {
+        address: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+            .unwrap(),
+        is_final: true
+    }]];
+    let response: Vec<Vec<u8>> = client
+        .request("get_addresses_bytecode", params.clone())
+        .await
+        .unwrap();
+
+    assert!(response.len() == 1);
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_bytecode_hash() {
+    let addr: SocketAddr = "[::]:5069".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let contract =
+        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+    let user = Address::from_str("AU12htxRWiEm8jDJpJptr6cwEhWNcCSFWstN1MLSa96DDkVM9Y42G").unwrap();
+
+    // the contract was upgraded in the candidate state, the user has no bytecode
+    let mut exec_ctrl: MockExecutionController = MockExecutionController::new();
+    exec_ctrl.expect_query_state().returning(move |req| {
+        let responses = req
+            .requests
+            .into_iter()
+            .map(|item| match item {
+                ExecutionQueryRequestItem::AddressBytecodeFinal(a) if a == contract => Ok(
+                    ExecutionQueryResponseItem::Bytecode(Bytecode(b"v1".to_vec())),
+                ),
+                ExecutionQueryRequestItem::AddressBytecodeCandidate(a) if a == contract => Ok(
+                    ExecutionQueryResponseItem::Bytecode(Bytecode(b"v2".to_vec())),
+                ),
+                ExecutionQueryRequestItem::AddressBytecodeFinal(_) => {
+                    Err(ExecutionQueryError::NotFound("Account".to_string()))
+                }
+                _ => Ok(ExecutionQueryResponseItem::Bytecode(Bytecode(Vec::new()))),
+            })
+            .collect();
+        ExecutionQueryResponse {
+            responses,
+            candidate_cursor: Slot::new(1, 2),
+            final_cursor: Slot::new(1, 7),
+            final_state_fingerprint: massa_hash::Hash::compute_from(&Vec::new()),
+        }
+    });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Result<Vec<AddressBytecodeHash>, Error> = client
+        .request("get_bytecode_hash", rpc_params![Vec::<Address>::new()])
+        .await;
+    assert!(response.unwrap_err().to_string().contains("no arguments"));
+
+    let response: Vec<AddressBytecodeHash> = client
+        .request("get_bytecode_hash", rpc_params![vec![contract, user]])
+        .await
+        .unwrap();
+    assert_eq!(
+        response,
+        vec![
+            AddressBytecodeHash {
+                address: contract,
+                final_hash: Some(massa_hash::Hash::compute_from(b"v1")),
+                candidate_hash: Some(massa_hash::Hash::compute_from(b"v2")),
+            },
+            AddressBytecodeHash {
+                address: user,
+                final_hash: None,
+                candidate_hash: None,
+            },
+        ]
+    );
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_datastore_entries() {
+    let addr: SocketAddr = "[::]:5009".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_get_final_and_active_data_entry()
+        .returning(|_a| {
+            vec![(
+                Some("massa".as_bytes().to_vec()),
+                Some("blockchain".as_bytes().to_vec()),
+            )]
+        });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![vec![DatastoreEntryInput {
+        address: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+            .unwrap(),
+        key: "massa".as_bytes().to_vec()
+    }]];
+    let response: Vec<DatastoreEntryOutput> = client
+        .request("get_datastore_entries", params.clone())
+        .await
+        .unwrap();
+
+    let entry = response.get(0).unwrap();
+
+    assert_eq!(
+        entry.candidate_value.as_ref().unwrap(),
+        &"blockchain".as_bytes().to_vec()
+    );
+    assert_eq!(
+        entry.final_value.as_ref().unwrap(),
+        &"massa".as_bytes().to_vec()
+    );
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_datastore_entries_all_keys() {
+    let addr: SocketAddr = "[::]:5078".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.api_settings.max_arguments = 3;
+
+    let mut exec_ctrl = MockExecutionController::new();
+    // every address has the keys "a" and "b", and the listing gives up past max_keys
+    exec_ctrl
+        .expect_get_bounded_datastore_keys()
+        .returning(|a, max_keys| {
+            (a.len() * 2 <= max_keys).then(|| {
+                a.iter()
+                    .map(|_addr| std::collections::BTreeSet::from([b"a".to_vec(), b"b".to_vec()]))
+                    .collect()
+            })
+        });
+    exec_ctrl
+        .expect_get_final_and_active_data_entry()
+        .returning(|queries| {
+            queries
+                .iter()
+                .map(|(_addr, key)| (None, Some(key.clone())))
+                .collect()
+        });
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let address =
+        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+    let other_address =
+        Address::from_str("AU12htxRWiEm8jDJpJptr6cwEhWNcCSFWstN1MLSa96DDkVM9Y42G").unwrap();
+
+    // the empty key is expanded to the final and candidate keys of the address
+    let response: Vec<DatastoreEntryOutput> = client
+        .request(
+            "get_datastore_entries",
+            rpc_params![vec![
+                DatastoreEntryInput {
+                    address: other_address,
+                    key: b"c".to_vec(),
+                },
+                DatastoreEntryInput {
+                    address,
+                    key: vec![],
+                },
+            ]],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        response
+            .iter()
+            .map(|entry| (entry.address, entry.key.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (other_address, b"c".to_vec()),
+            (address, b"a".to_vec()),
+            (address, b"b".to_vec()),
+        ]
+    );
+    assert_eq!(response[2].candidate_value, Some(b"b".to_vec()));
+
+    // the expanded entries are capped by max_arguments
+    let response: Result<Vec<DatastoreEntryOutput>, Error> = client
+        .request(
+            "get_datastore_entries",
+            rpc_params![vec![
+                DatastoreEntryInput {
+                    address,
+                    key: vec![],
+                },
+                DatastoreEntryInput {
+                    address: other_address,
+                    key: vec![],
+                },
+            ]],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("too many datastore entries requested"));
+
+    // the explicit keys count against the listed ones
+    let response: Result<Vec<DatastoreEntryOutput>, Error> = client
+        .request(
+            "get_datastore_entries",
+            rpc_params![vec![
+                DatastoreEntryInput {
+                    address: other_address,
+                    key: b"c".to_vec(),
+                },
+                DatastoreEntryInput {
+                    address: other_address,
+                    key: b"d".to_vec(),
+                },
+                DatastoreEntryInput {
+                    address,
+                    key: vec![],
+                },
+            ]],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("too many datastore entries requested"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn wrong_api() {
+    let addr: SocketAddr = "[::]:5004".parse().unwrap();
+    let (api_public, config) = start_public_api(addr);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![];
+    let response: Result<(), Error> = client.request("stop_node", params.clone()).await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request("node_sign_message", rpc_params![Vec::<u8>::new()])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request(
+            "remove_staking_addresses",
+            rpc_params![Vec::<Address>::new()],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request("get_staking_addresses", params.clone())
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request(
+            "export_block_graph_dot",
+            rpc_params![Option::<Slot>::None, Option::<Slot>::None],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request("node_ban_by_ip", rpc_params![Vec::<IpAddr>::new()])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request("node_ban_by_id", rpc_params![Vec::<NodeId>::new()])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request("node_unban_by_ip", rpc_params![Vec::<IpAddr>::new()])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request("node_unban_by_id", rpc_params![Vec::<NodeId>::new()])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client.request("node_peers_whitelist", params.clone()).await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request(
+            "node_add_to_peers_whitelist",
+            rpc_params![Vec::<IpAddr>::new()],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request(
+            "node_remove_from_peers_whitelist",
+            rpc_params![Vec::<IpAddr>::new()],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request(
+            "node_bootstrap_whitelist",
+            rpc_params![Vec::<IpAddr>::new()],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request("node_bootstrap_whitelist_allow_all", rpc_params![])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request(
+            "node_add_to_bootstrap_whitelist",
+            rpc_params![Vec::<IpAddr>::new()],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request(
+            "node_remove_from_peers_whitelist",
+            rpc_params![Vec::<IpAddr>::new()],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request("node_bootstrap_whitelist", rpc_params![])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request("node_bootstrap_whitelist_allow_all", rpc_params![])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request(
+            "node_add_to_bootstrap_whitelist",
+            rpc_params![Vec::<IpAddr>::new()],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request(
+            "node_remove_from_bootstrap_whitelist",
+            rpc_params![Vec::<IpAddr>::new()],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request("node_bootstrap_blacklist", rpc_params![])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request(
+            "node_add_to_bootstrap_blacklist",
+            rpc_params![Vec::<IpAddr>::new()],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request(
+            "node_remove_from_bootstrap_blacklist",
+            rpc_params![Vec::<IpAddr>::new()],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    let response: Result<(), Error> = client
+        .request("add_staking_secret_keys", rpc_params![Vec::<String>::new()])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("The wrong API (either Public or Private) was called"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_openrpc_spec() {
+    let addr: SocketAddr = "[::]:5016".parse().unwrap();
+    let (mut api_public, mut config) = start_public_api(addr);
+
+    let open_rpc_file = NamedTempFile::new().unwrap();
+
+    let s = "{
+        \"title\": \"Sample Pet Store App\",
+        \"version\": \"1.0.1\"
+      }";
+
+    serde_json::to_writer_pretty(open_rpc_file.as_file(), s).expect("unable to write ledger file");
+
+    config.openrpc_spec_path = open_rpc_file.path().to_path_buf();
+    api_public.0.api_settings.openrpc_spec_path = open_rpc_file.path().to_path_buf();
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+    let params = rpc_params![];
+
+    let response: Value = client.request("rpc.discover", params).await.unwrap();
+    assert!(response.as_str().unwrap().contains("Sample Pet Store App"));
+
+    api_public_handle.stop().await;
+
+    let addr: SocketAddr = "[::]:5017".parse().unwrap();
+    let (mut api_public, mut config) = start_public_api(addr);
+
+    let open_rpc_file = NamedTempFile::new().unwrap();
+
+    config.openrpc_spec_path = open_rpc_file.path().to_path_buf();
+    api_public.0.api_settings.openrpc_spec_path = open_rpc_file.path().to_path_buf();
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+    let params = rpc_params![];
+
+    let response: Result<Value, Error> = client.request("rpc.discover", params).await;
+
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("failed to parse OpenRPC specification"));
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_stakers() {
+    let addr: SocketAddr = "[::]:5015".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_cycle_active_rolls().returning(|_| {
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(
+            Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap(),
+            5_u64,
+        );
+        map.insert(
+            Address::from_str("AU12htxRWiEm8jDJpJptr6cwEhWNcCSFWstN1MLSa96DDkVM9Y42G").unwrap(),
+            10_u64,
+        );
+        map.insert(
+            Address::from_str("AU12cMW9zRKFDS43Z2W88VCmdQFxmHjAo54XvuVV34UzJeXRLXW9M").unwrap(),
+            20_u64,
+        );
+        map.insert(
+            Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key()),
+            30_u64,
+        );
+
+        map
+    });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+    let params = rpc_params![];
+
+    let response: Value = client.request("get_stakers", params).await.unwrap();
+
+    response.as_array().unwrap().iter().for_each(|v| {
+        let staker: (Address, u64) = serde_json::from_value(v.clone()).unwrap();
+        assert!(staker.1 > 4);
+    });
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_total_active_rolls() {
+    let addr: SocketAddr = "[::]:5086".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    // cycle 3 has two stakers, the other cycles have none
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_get_cycle_active_rolls()
+        .returning(|cycle| match cycle {
+            3 => std::collections::BTreeMap::from([
+                (
+                    Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+                        .unwrap(),
+                    5_u64,
+                ),
+                (
+                    Address::from_str("AU12htxRWiEm8jDJpJptr6cwEhWNcCSFWstN1MLSa96DDkVM9Y42G")
+                        .unwrap(),
+                    10_u64,
+                ),
+            ]),
+            _ => std::collections::BTreeMap::new(),
+        });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: u64 = client
+        .request("get_total_active_rolls", rpc_params![3])
+        .await
+        .unwrap();
+    assert_eq!(response, 15);
+
+    let response: u64 = client
+        .request("get_total_active_rolls", rpc_params![4])
+        .await
+        .unwrap();
+    assert_eq!(response, 0);
+
+    // the current cycle is used by default
+    client
+        .request::<u64, _>("get_total_active_rolls", rpc_params![])
+        .await
+        .unwrap();
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_multi_cycle_rolls() {
+    let addr: SocketAddr = "[::]:5075".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.api_settings.max_arguments = 3;
+
+    // cycles 4 to 6 are retained, the address had no rolls at cycle 5
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_addresses_infos().returning(|a| {
+        a.iter()
+            .map(|_addr| ExecutionAddressInfo {
+                candidate_balance: Amount::zero(),
+                final_balance: Amount::zero(),
+                final_roll_count: 0,
+                final_datastore_keys: std::collections::BTreeSet::new(),
+                candidate_roll_count: 0,
+                candidate_datastore_keys: std::collections::BTreeSet::new(),
+                has_bytecode: false,
+                future_deferred_credits: BTreeMap::new(),
+                cycle_infos: [(4, Some(10)), (5, None), (6, Some(12))]
+                    .into_iter()
+                    .map(|(cycle, active_rolls)| ExecutionAddressCycleInfo {
+                        cycle,
+                        is_final: true,
+                        ok_count: 0,
+                        nok_count: 0,
+                        active_rolls,
+                    })
+                    .collect(),
+                roll_schedule: vec![],
+            })
+            .collect()
+    });
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let address =
+        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+
+    let response: Vec<(u64, u64)> = client
+        .request("get_multi_cycle_rolls", rpc_params![address, vec![6, 4, 5]])
+        .await
+        .unwrap();
+    assert_eq!(response, vec![(6, 12), (4, 10), (5, 0)]);
+
+    let response: Result<Vec<(u64, u64)>, Error> = client
+        .request("get_multi_cycle_rolls", rpc_params![address, vec![4, 2]])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("cycle 2 is not in the retained history"));
+
+    let response: Result<Vec<(u64, u64)>, Error> = client
+        .request(
+            "get_multi_cycle_rolls",
+            rpc_params![address, vec![4, 5, 6, 4]],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("too many arguments"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_cycle_draw_seed() {
+    let addr: SocketAddr = "[::]:5070".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    // only the draws of cycle 3 are retained
+    let seed = massa_hash::Hash::compute_from(b"seed");
+    let mut selector_ctrl = MockSelectorController::new();
+    selector_ctrl
+        .expect_get_cycle_draw_seed()
+        .returning(move |cycle| match cycle {
+            3 => Ok(seed),
+            _ => Err(PosError::CycleUnavailable(cycle)),
+        });
+    api_public.0.selector_controller = Box::new(selector_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Vec<u8> = client
+        .request("get_cycle_draw_seed", rpc_params![3])
+        .await
+        .unwrap();
+    assert_eq!(response, seed.to_bytes().to_vec());
+
+    let response: Result<Vec<u8>, Error> =
+        client.request("get_cycle_draw_seed", rpc_params![1]).await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("draws of cycle 1 are not in the retained history"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_endorsement_draws() {
+    let addr: SocketAddr = "[::]:5098".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    // the network is in cycle 5
+    api_public.0.api_settings.genesis_timestamp = MassaTime::now()
+        .unwrap()
+        .saturating_sub(config.t0.checked_mul(5 * 128 + 10).unwrap());
+
+    let endorsers: Vec<Address> = (0..16)
+        .map(|_| Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key()))
+        .collect();
+    let drawn = endorsers.clone();
+    let mut selector_ctrl = MockSelectorController::new();
+    selector_ctrl
+        .expect_get_selection()
+        .returning(move |slot| match slot.period {
+            10 => Ok(Selection {
+                endorsements: drawn.clone(),
+                producer: drawn[0],
+            }),
+            // like the selector, past and future cycles are both unavailable
+            _ => Err(PosError::CycleUnavailable(slot.get_cycle(128))),
+        });
+    api_public.0.selector_controller = Box::new(selector_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Vec<(u64, Address)> = client
+        .request("get_endorsement_draws", rpc_params![Slot::new(10, 0)])
+        .await
+        .unwrap();
+    assert_eq!(
+        response,
+        endorsers
+            .into_iter()
+            .enumerate()
+            .map(|(index, address)| (index as u64, address))
+            .collect::<Vec<_>>()
+    );
+
+    let response: Result<Vec<(u64, Address)>, Error> = client
+        .request("get_endorsement_draws", rpc_params![Slot::new(0, 0)])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("draws of cycle 0 are not in the retained history"));
+
+    let response: Result<Vec<(u64, Address)>, Error> = client
+        .request("get_endorsement_draws", rpc_params![Slot::new(1000, 0)])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("draws of cycle 7 are not computed yet"));
+
+    let response: Result<Vec<(u64, Address)>, Error> = client
+        .request(
+            "get_endorsement_draws",
+            rpc_params![Slot::new(10, config.thread_count)],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("is out of range"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_block_producer() {
+    let addr: SocketAddr = "[::]:5099".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    // the network is in cycle 5
+    api_public.0.api_settings.genesis_timestamp = MassaTime::now()
+        .unwrap()
+        .saturating_sub(config.t0.checked_mul(5 * 128 + 10).unwrap());
+
+    let producer = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+    let mut selector_ctrl = MockSelectorController::new();
+    selector_ctrl
+        .expect_get_producer()
+        .returning(move |slot| match slot.period {
+            10 => Ok(producer),
+            // like the selector, past and future cycles are both unavailable
+            _ => Err(PosError::CycleUnavailable(slot.get_cycle(128))),
+        });
+    api_public.0.selector_controller = Box::new(selector_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Address = client
+        .request("get_block_producer", rpc_params![Slot::new(10, 1)])
+        .await
+        .unwrap();
+    assert_eq!(response, producer);
+
+    let response: Result<Address, Error> = client
+        .request("get_block_producer", rpc_params![Slot::new(1000, 0)])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("draws of cycle 7 are not computed yet"));
+
+    let response: Result<Address, Error> = client
+        .request("get_block_producer", rpc_params![Slot::new(0, 0)])
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("draws of cycle 0 are not in the retained history"));
+
+    let response: Result<Address, Error> = client
+        .request(
+            "get_block_producer",
+            rpc_params![Slot::new(10, config.thread_count)],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("is out of range"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_execution_slots() {
+    let addr: SocketAddr = "[::]:5040".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_stats().returning(|| ExecutionStats {
+        time_window_start: MassaTime::now().unwrap(),
+        time_window_end: MassaTime::now().unwrap(),
+        final_block_count: 0,
+        final_executed_operations_count: 0,
+        active_cursor: Slot::new(12, 3),
+        final_cursor: Slot::new(10, 1),
+    });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+    let response: ExecutionSlots = client
+        .request("get_execution_slots", rpc_params![])
+        .await
+        .unwrap();
+
+    assert_eq!(response.final_slot, Slot::new(10, 1));
+    assert_eq!(response.active_slot, Slot::new(12, 3));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_operation_inclusions() {
+    let addr: SocketAddr = "[::]:5067".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let keypair = KeyPair::generate(0).unwrap();
+    let op1 = create_operation_with_expire_period(&keypair, 500000);
+    let op2 = create_operation_with_expire_period(&keypair, 500001);
+    let op3 = create_operation_with_expire_period(&keypair, 500002);
+    let final_block =
+        create_block_with_operations(&keypair, Slot::new(1, 0), vec![op1.clone(), op2.clone()]);
+    let candidate_block =
+        create_block_with_operations(&keypair, Slot::new(2, 0), vec![op2.clone()]);
+    let final_block_id = final_block.id;
+    api_public.0.storage.store_block(final_block);
+    api_public.0.storage.store_block(candidate_block.clone());
+
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_statuses()
+        .returning(move |ids| {
+            ids.iter()
+                .map(|id| {
+                    if id == &final_block_id {
+                        BlockGraphStatus::Final
+                    } else {
+                        BlockGraphStatus::ActiveInAlternativeCliques
+                    }
+                })
+                .collect()
+        });
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: PreHashMap<OperationId, Vec<(BlockId, bool)>> = client
+        .request(
+            "get_operation_inclusions",
+            rpc_params![vec![op1.id, op2.id, op3.id]],
+        )
+        .await
+        .unwrap();
+    assert_eq!(response[&op1.id], vec![(final_block_id, true)]);
+    let mut op2_blocks = response[&op2.id].clone();
+    op2_blocks.sort_by_key(|(_, is_final)| !is_final);
+    assert_eq!(
+        op2_blocks,
+        vec![(final_block_id, true), (candidate_block.id, false)]
+    );
+    assert!(response[&op3.id].is_empty());
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_operation_proof() {
+    let addr: SocketAddr = "[::]:5041".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let keypair = KeyPair::generate(0).unwrap();
+    let op1 = create_operation_with_expire_period(&keypair, 500000);
+    let op2 = create_operation_with_expire_period(&keypair, 500001);
+    let block = create_block_with_operations(&keypair, Slot::new(1, 0), vec![op1, op2.clone()]);
+    api_public.0.storage.store_block(block.clone());
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Option<OperationInclusionProof> = client
+        .request("get_operation_proof", rpc_params![block.id, op2.id])
+        .await
+        .unwrap();
+    let proof = response.unwrap();
+    assert_eq!(proof.block_id, block.id);
+    assert_eq!(proof.operation_ids[proof.index], op2.id);
+    assert_eq!(
+        compute_operations_hash(&proof.operation_ids, &OperationIdSerializer::new()),
+        proof.header.content.operation_merkle_root
+    );
+
+    // operation not included in the block
+    let other_op = create_operation_with_expire_period(&keypair, 500002);
+    let response: Option<OperationInclusionProof> = client
+        .request("get_operation_proof", rpc_params![block.id, other_op.id])
+        .await
+        .unwrap();
+    assert!(response.is_none());
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn execute_read_only_call_binary() {
+    let addr: SocketAddr = "[::]:5042".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_execute_readonly_request()
+        .returning(|_req| {
+            Ok(ReadOnlyExecutionOutput {
+                out: massa_execution_exports::ExecutionOutput {
+                    slot: Slot {
+                        period: 1,
+                        thread: 5,
+                    },
+                    block_info: None,
+                    state_changes: massa_final_state::StateChanges::default(),
+                    events: massa_execution_exports::EventStore::default(),
+                },
+                gas_cost: 100,
+                call_result: "toto".as_bytes().to_vec(),
+                trace: None,
+            })
+        });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![vec![ReadOnlyCall {
+        max_gas: 1000000,
+        target_address: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+            .unwrap(),
+        target_function: "hello".to_string(),
+        parameter: vec![],
+        caller_address: None,
+        is_final: false,
+        label: None,
+        fee: None,
+        coins: None,
+        trace: false,
+    }]];
+    let json_response: Vec<ExecuteReadOnlyResponse> = client
+        .request("execute_read_only_call", params.clone())
+        .await
+        .unwrap();
+    let binary_response: Vec<String> = client
+        .request("execute_read_only_call_binary", params)
+        .await
+        .unwrap();
+
+    assert_eq!(binary_response.len(), 1);
+    let mut expected = Vec::new();
+    ExecuteReadOnlyResponseSerializer::new()
+        .serialize(&json_response[0], &mut expected)
+        .unwrap();
+    assert_eq!(STANDARD.decode(&binary_response[0]).unwrap(), expected);
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn execute_read_only_bytecode_too_large() {
+    let addr: SocketAddr = "[::]:5043".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.api_settings.max_request_bytes = 4;
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![vec![ReadOnlyBytecodeExecution {
+        max_gas: 100000,
+        bytecode: "too big".as_bytes().to_vec(),
+        address: None,
+        operation_datastore: None,
+        is_final: false,
+        label: None,
+        fee: None,
+        from_address: None,
+    }]];
+    let response: Result<Vec<ExecuteReadOnlyResponse>, Error> =
+        client.request("execute_read_only_bytecode", params).await;
     assert!(response
         .unwrap_err()
         .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+        .contains("Request too large"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_addresses_selector_not_ready() {
+    let addr: SocketAddr = "[::]:5044".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_addresses_infos().returning(|a| {
+        a.iter()
+            .map(|_addr| ExecutionAddressInfo {
+                candidate_balance: Amount::from_str("100000").unwrap(),
+                final_balance: Amount::from_str("80000").unwrap(),
+                final_roll_count: 55,
+                final_datastore_keys: std::collections::BTreeSet::new(),
+                candidate_roll_count: 12,
+                candidate_datastore_keys: std::collections::BTreeSet::new(),
+                has_bytecode: false,
+                future_deferred_credits: BTreeMap::new(),
+                cycle_infos: vec![],
+                roll_schedule: vec![],
+            })
+            .collect()
+    });
+
+    // right after startup the selector has not drawn the current cycle yet
+    let mut selector_ctrl = MockSelectorController::new();
+    selector_ctrl
+        .expect_get_selection()
+        .returning(|slot| Err(PosError::CycleUnavailable(slot.get_cycle(128))));
+    selector_ctrl
+        .expect_get_available_selections_in_range()
+        .returning(|_range, _addrs| Ok(BTreeMap::new()));
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+    api_public.0.selector_controller = Box::new(selector_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![vec![Address::from_str(
+        "AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x"
+    )
+    .unwrap()]];
+    let response: Result<Vec<AddressInfo>, Error> = client.request("get_addresses", params).await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("Selector not ready"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_addresses_selector_failure() {
+    let addr: SocketAddr = "[::]:5117".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_addresses_infos().returning(|a| {
+        a.iter()
+            .map(|_addr| ExecutionAddressInfo {
+                candidate_balance: Amount::from_str("100000").unwrap(),
+                final_balance: Amount::from_str("80000").unwrap(),
+                final_roll_count: 55,
+                final_datastore_keys: std::collections::BTreeSet::new(),
+                candidate_roll_count: 12,
+                candidate_datastore_keys: std::collections::BTreeSet::new(),
+                has_bytecode: false,
+                future_deferred_credits: BTreeMap::new(),
+                cycle_infos: vec![],
+                roll_schedule: vec![],
+            })
+            .collect()
+    });
+
+    // the current cycle is drawn, but the draws query fails
+    let mut selector_ctrl = MockSelectorController::new();
+    selector_ctrl.expect_get_selection().returning(|_slot| {
+        Ok(Selection {
+            endorsements: vec![],
+            producer: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+                .unwrap(),
+        })
+    });
+    selector_ctrl
+        .expect_get_available_selections_in_range()
+        .returning(|_range, _addrs| Err(PosError::ChannelDown("selector stopped".to_string())));
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+    api_public.0.selector_controller = Box::new(selector_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let address =
+        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+
+    // strict mode fails instead of returning empty draws
+    let response: Result<Vec<AddressInfo>, Error> = client
+        .request(
+            "get_addresses",
+            rpc_params![vec![address], Option::<String>::None, false],
+        )
+        .await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("selector stopped"));
+
+    // best-effort mode reports the failure
+    let response: Vec<AddressInfo> = client
+        .request(
+            "get_addresses",
+            rpc_params![vec![address], Option::<String>::None, true],
+        )
+        .await
+        .unwrap();
+    assert!(response[0].next_block_draws.is_empty());
+    assert_eq!(
+        response[0].unavailable.keys().collect::<Vec<_>>(),
+        vec!["selector"]
+    );
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_deferred_credits() {
+    let addr: SocketAddr = "[::]:5045".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl: MockExecutionController = MockExecutionController::new();
+    exec_ctrl
+        .expect_query_state()
+        .returning(|_| ExecutionQueryResponse {
+            responses: vec![Ok(ExecutionQueryResponseItem::DeferredCredits(
+                BTreeMap::from([
+                    (Slot::new(3, 0), Amount::from_str("100").unwrap()),
+                    (Slot::new(5, 2), Amount::from_str("50").unwrap()),
+                ]),
+            ))],
+            candidate_cursor: Slot::new(1, 2),
+            final_cursor: Slot::new(1, 7),
+            final_state_fingerprint: massa_hash::Hash::compute_from(&Vec::new()),
+        });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let params = rpc_params![vec![AddressFilter {
+        address: Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x")
+            .unwrap(),
+        is_final: false
+    }]];
+    let response: Vec<Vec<SlotAmount>> = client
+        .request("get_deferred_credits", params)
+        .await
+        .unwrap();
+
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0].len(), 2);
+    assert_eq!(response[0][0].slot, Slot::new(3, 0));
+    assert_eq!(response[0][0].amount, Amount::from_str("100").unwrap());
+    assert_eq!(response[0][1].slot, Slot::new(5, 2));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn rate_limit() {
+    let addr: SocketAddr = "[::]:5046".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public
+        .0
+        .api_settings
+        .rate_limits
+        .insert("get_execution_slots".to_string(), 1);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_stats().returning(|| ExecutionStats {
+        time_window_start: MassaTime::now().unwrap(),
+        time_window_end: MassaTime::now().unwrap(),
+        final_block_count: 0,
+        final_executed_operations_count: 0,
+        active_cursor: Slot::new(12, 3),
+        final_cursor: Slot::new(10, 1),
+    });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Result<ExecutionSlots, Error> =
+        client.request("get_execution_slots", rpc_params![]).await;
+    assert!(response.is_ok());
+
+    // the bucket only holds one token per second
+    let response: Result<ExecutionSlots, Error> =
+        client.request("get_execution_slots", rpc_params![]).await;
+    assert!(response.unwrap_err().to_string().contains("Rate limited"));
+
+    // other methods are not limited
+    let response: Result<Vec<Vec<u8>>, Error> = client
+        .request("get_addresses_bytecode", rpc_params![])
+        .await;
+    assert!(!response.unwrap_err().to_string().contains("Rate limited"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn max_concurrent_requests() {
+    let addr: SocketAddr = "[::]:5058".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    // no permit available: every request is considered over the limit
+    api_public.0.admission = RequestAdmission::new(0);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Result<ExecutionSlots, Error> =
+        client.request("get_execution_slots", rpc_params![]).await;
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("too many requests in progress"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_blocks_at_slot() {
+    let addr: SocketAddr = "[::]:5063".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let final_block = create_block(&KeyPair::generate(0).unwrap());
+    let final_id = final_block.id;
+    let candidate_block = create_block(&KeyPair::generate(0).unwrap());
+    let candidate_id = candidate_block.id;
+
+    api_public.0.storage.store_block(final_block);
+    api_public.0.storage.store_block(candidate_block);
+
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_blockclique_block_at_slot()
+        .returning(move |s| match s.period {
+            1 => Some(final_id),
+            2 => Some(candidate_id),
+            _ => None,
+        });
+    consensus_ctrl
+        .expect_get_block_statuses()
+        .returning(move |ids| {
+            ids.iter()
+                .map(|id| {
+                    if id == &final_id {
+                        BlockGraphStatus::Final
+                    } else {
+                        BlockGraphStatus::ActiveInBlockclique
+                    }
+                })
+                .collect()
+        });
+
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    // final slot: both are the final block
+    let response: SlotBlocks = client
+        .request("get_blocks_at_slot", rpc_params![Slot::new(1, 0)])
+        .await
+        .unwrap();
+    assert_eq!(response.final_block.unwrap().id, final_id);
+    assert_eq!(response.candidate_block.unwrap().id, final_id);
+
+    // block not final yet
+    let response: SlotBlocks = client
+        .request("get_blocks_at_slot", rpc_params![Slot::new(2, 0)])
+        .await
+        .unwrap();
+    assert!(response.final_block.is_none());
+    assert_eq!(response.candidate_block.unwrap().id, candidate_id);
+
+    // missed slot
+    let response: SlotBlocks = client
+        .request("get_blocks_at_slot", rpc_params![Slot::new(3, 0)])
+        .await
+        .unwrap();
+    assert!(response.final_block.is_none());
+    assert!(response.candidate_block.is_none());
+
+    api_public_handle.stop().await;
+}
 
-    let response: Result<(), Error> = client
-        .request("node_ban_by_id", rpc_params![Vec::<NodeId>::new()])
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+#[tokio::test]
+async fn get_block_at_slot() {
+    let addr: SocketAddr = "[::]:5047".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
 
-    let response: Result<(), Error> = client
-        .request("node_unban_by_ip", rpc_params![Vec::<IpAddr>::new()])
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    let block = create_block(&KeyPair::generate(0).unwrap());
+    let id = block.id;
 
-    let response: Result<(), Error> = client
-        .request("node_unban_by_id", rpc_params![Vec::<NodeId>::new()])
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    api_public.0.storage.store_block(block.clone());
 
-    let response: Result<(), Error> = client.request("node_peers_whitelist", params.clone()).await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_blockclique_block_at_slot()
+        .returning(move |s| if s.period == 1 { Some(id) } else { None });
+    consensus_ctrl
+        .expect_get_block_statuses()
+        .returning(|ids| vec![BlockGraphStatus::Final; ids.len()]);
 
-    let response: Result<(), Error> = client
-        .request(
-            "node_add_to_peers_whitelist",
-            rpc_params![Vec::<IpAddr>::new()],
-        )
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
 
-    let response: Result<(), Error> = client
-        .request(
-            "node_remove_from_peers_whitelist",
-            rpc_params![Vec::<IpAddr>::new()],
-        )
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
 
-    let response: Result<(), Error> = client
-        .request(
-            "node_bootstrap_whitelist",
-            rpc_params![Vec::<IpAddr>::new()],
-        )
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Option<BlockInfo> = client
+        .request("get_block_at_slot", rpc_params![Slot::new(1, 0)])
+        .await
+        .unwrap();
+    let block_info = response.unwrap();
+    assert_eq!(block_info.id, id);
+    assert!(block_info.content.unwrap().is_final);
+
+    // missed slot
+    let response: Option<BlockInfo> = client
+        .request("get_block_at_slot", rpc_params![Slot::new(2, 0)])
+        .await
+        .unwrap();
+    assert!(response.is_none());
+
+    // thread out of range
+    let response: Result<Option<BlockInfo>, Error> = client
+        .request("get_block_at_slot", rpc_params![Slot::new(1, THREAD_COUNT)])
         .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    assert!(response.unwrap_err().to_string().contains("out of range"));
 
-    let response: Result<(), Error> = client
-        .request("node_bootstrap_whitelist_allow_all", rpc_params![])
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_datastore_keys() {
+    let addr: SocketAddr = "[::]:5082".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let contract =
+        Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+    let unknown =
+        Address::from_str("AU12htxRWiEm8jDJpJptr6cwEhWNcCSFWstN1MLSa96DDkVM9Y42G").unwrap();
+
+    // the contract wrote a new key in the candidate state, the other address is unknown
+    let mut exec_ctrl: MockExecutionController = MockExecutionController::new();
+    exec_ctrl.expect_query_state().returning(move |req| {
+        let responses = req
+            .requests
+            .into_iter()
+            .map(|item| match item {
+                ExecutionQueryRequestItem::AddressDatastoreKeysFinal { addr, .. }
+                    if addr == contract =>
+                {
+                    Ok(ExecutionQueryResponseItem::KeyList(
+                        std::collections::BTreeSet::from([b"a".to_vec()]),
+                    ))
+                }
+                ExecutionQueryRequestItem::AddressDatastoreKeysCandidate { addr, .. }
+                    if addr == contract =>
+                {
+                    Ok(ExecutionQueryResponseItem::KeyList(
+                        std::collections::BTreeSet::from([b"a".to_vec(), b"b".to_vec()]),
+                    ))
+                }
+                _ => Err(ExecutionQueryError::NotFound("Account".to_string())),
+            })
+            .collect();
+        ExecutionQueryResponse {
+            responses,
+            candidate_cursor: Slot::new(1, 2),
+            final_cursor: Slot::new(1, 7),
+            final_state_fingerprint: massa_hash::Hash::compute_from(&Vec::new()),
+        }
+    });
+
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Vec<(
+        Address,
+        std::collections::BTreeSet<Vec<u8>>,
+        std::collections::BTreeSet<Vec<u8>>,
+    )> = client
+        .request("get_datastore_keys", rpc_params![vec![contract, unknown]])
+        .await
+        .unwrap();
+    assert_eq!(
+        response,
+        vec![
+            (
+                contract,
+                std::collections::BTreeSet::from([b"a".to_vec()]),
+                std::collections::BTreeSet::from([b"a".to_vec(), b"b".to_vec()]),
+            ),
+            (
+                unknown,
+                std::collections::BTreeSet::new(),
+                std::collections::BTreeSet::new(),
+            ),
+        ]
+    );
+
+    // too many arguments
+    let too_many = vec![contract; config.max_arguments as usize + 1];
+    let response: Result<
+        Vec<(
+            Address,
+            std::collections::BTreeSet<Vec<u8>>,
+            std::collections::BTreeSet<Vec<u8>>,
+        )>,
+        Error,
+    > = client
+        .request("get_datastore_keys", rpc_params![too_many])
         .await;
     assert!(response
         .unwrap_err()
         .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+        .contains("too many arguments"));
 
-    let response: Result<(), Error> = client
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_consensus_stats_history() {
+    let addr: SocketAddr = "[::]:5090".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+    api_public.0.api_settings.max_arguments = 2;
+
+    // three past snapshots followed by the current stats
+    let history: Vec<ConsensusStats> = (0..4u64)
+        .map(|i| ConsensusStats {
+            start_timespan: MassaTime::from_millis(i * 1000),
+            end_timespan: MassaTime::from_millis((i + 1) * 1000),
+            final_block_count: i,
+            stale_block_count: 0,
+            clique_count: 1,
+        })
+        .collect();
+
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_stats_history()
+        .withf(|duration| *duration == MassaTime::from_millis(5000))
+        .returning(move |_| Ok(history.clone()));
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    // the oldest snapshot is dropped: at most max_arguments past snapshots plus the current stats
+    let response: Vec<ConsensusStats> = client
+        .request("get_consensus_stats_history", rpc_params![5000])
+        .await
+        .unwrap();
+    assert_eq!(
+        response
+            .iter()
+            .map(|stats| stats.final_block_count)
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_filtered_sc_output_event_by_operation() {
+    let addr: SocketAddr = "[::]:5091".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let op_a =
+        OperationId::from_str("O1q4CBcuYo8YANEV34W4JRWVHrzcYns19VJfyAB7jT4qfitAnMC").unwrap();
+    let op_b = create_operation_with_expire_period(&KeyPair::generate(0).unwrap(), 10).id;
+    let event = |origin_operation_id: Option<OperationId>, data: &str| SCOutputEvent {
+        context: massa_models::output_event::EventExecutionContext {
+            slot: Slot::new(1, 0),
+            block: None,
+            read_only: false,
+            index_in_slot: 0,
+            call_stack: std::collections::VecDeque::new(),
+            origin_operation_id,
+            is_final: false,
+            is_error: false,
+        },
+        data: data.to_string(),
+    };
+    let events = vec![
+        event(Some(op_a), "a1"),
+        event(Some(op_b), "b1"),
+        event(None, "async message"),
+        event(Some(op_a), "a2"),
+    ];
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl
+        .expect_get_filtered_sc_output_event()
+        .returning(move |_| events.clone());
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: PreHashMap<OperationId, Vec<SCOutputEvent>> = client
         .request(
-            "node_add_to_bootstrap_whitelist",
-            rpc_params![Vec::<IpAddr>::new()],
+            "get_filtered_sc_output_event_by_operation",
+            rpc_params![EventFilter::default()],
         )
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+        .await
+        .unwrap();
+
+    // events without an originating operation are left out, emission order is kept
+    assert_eq!(response.len(), 2);
+    assert_eq!(
+        response[&op_a]
+            .iter()
+            .map(|e| e.data.as_str())
+            .collect::<Vec<_>>(),
+        vec!["a1", "a2"]
+    );
+    assert_eq!(response[&op_b].len(), 1);
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn decode_operation() {
+    let addr: SocketAddr = "[::]:5092".parse().unwrap();
+    let (api_public, config) = start_public_api(addr);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let keypair = KeyPair::generate(0).unwrap();
+    let operation = create_operation_with_expire_period(&keypair, 500000);
+    let other_operation = create_operation_with_expire_period(&keypair, 500001);
+    let mut bytes = Vec::new();
+    bytes.extend(operation.signature.to_bytes());
+    bytes.extend(keypair.get_public_key().to_bytes());
+    bytes.extend(operation.serialized_data.clone());
+
+    let response: DecodedOperation = client
+        .request("decode_operation", rpc_params![bytes.clone(), true])
+        .await
+        .unwrap();
+    assert_eq!(response.operation.id, operation.id);
+    assert_eq!(response.operation.content.expire_period, 500000);
+    assert!(response.rest.is_empty());
+    assert_eq!(response.signature_valid, Some(true));
+
+    // trailing bytes are returned, the signature is not checked unless requested
+    let mut framed = Vec::new();
+    framed.extend(other_operation.signature.to_bytes());
+    framed.extend(keypair.get_public_key().to_bytes());
+    framed.extend(operation.serialized_data);
+    framed.extend([1, 2, 3]);
+    let response: DecodedOperation = client
+        .request("decode_operation", rpc_params![framed.clone()])
+        .await
+        .unwrap();
+    assert_eq!(response.rest, vec![1, 2, 3]);
+    assert_eq!(response.signature_valid, None);
+    let response: DecodedOperation = client
+        .request("decode_operation", rpc_params![framed, true])
+        .await
+        .unwrap();
+    assert_eq!(response.signature_valid, Some(false));
+
+    // truncated input
+    bytes.truncate(bytes.len() - 2);
+    let response: Result<DecodedOperation, Error> =
+        client.request("decode_operation", rpc_params![bytes]).await;
+    assert!(response.is_err());
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_metrics_text() {
+    let addr: SocketAddr = "[::]:5093".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_stats().returning(|| ExecutionStats {
+        time_window_start: MassaTime::now().unwrap(),
+        time_window_end: MassaTime::now().unwrap(),
+        final_block_count: 0,
+        final_executed_operations_count: 0,
+        active_cursor: Slot::new(12, 1),
+        final_cursor: Slot::new(10, 3),
+    });
+
+    let mut protocol_ctrl = MockProtocolController::new();
+    protocol_ctrl.expect_get_stats().returning(|| {
+        Ok((
+            NetworkStats {
+                in_connection_count: 10,
+                out_connection_count: 5,
+                known_peer_count: 6,
+                banned_peer_count: 0,
+                active_node_count: 15,
+            },
+            HashMap::new(),
+        ))
+    });
+
+    let mut pool_ctrl = MockPoolController::new();
+    pool_ctrl.expect_get_operation_count().returning(|| 1024);
+    pool_ctrl.expect_get_endorsement_count().returning(|| 2048);
+
+    api_public.0.pool_command_sender = Box::new(pool_ctrl);
+    api_public.0.protocol_controller = Box::new(protocol_ctrl);
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
 
-    let response: Result<(), Error> = client
-        .request(
-            "node_remove_from_peers_whitelist",
-            rpc_params![Vec::<IpAddr>::new()],
-        )
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
 
-    let response: Result<(), Error> = client
-        .request("node_bootstrap_whitelist", rpc_params![])
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    let _: String = client
+        .request("get_metrics_text", rpc_params![])
+        .await
+        .unwrap();
+    let response: String = client
+        .request("get_metrics_text", rpc_params![])
+        .await
+        .unwrap();
 
-    let response: Result<(), Error> = client
-        .request("node_bootstrap_whitelist_allow_all", rpc_params![])
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    let lines: Vec<&str> = response.lines().collect();
+    assert!(lines.contains(&"# TYPE massa_network_in_connections gauge"));
+    assert!(lines.contains(&"massa_network_in_connections 10"));
+    assert!(lines.contains(&"massa_pool_operations 1024"));
+    assert!(lines.contains(&"massa_pool_endorsements 2048"));
+    assert!(lines.contains(&"massa_execution_final_period 10"));
+    assert!(lines.contains(&"massa_execution_final_thread 3"));
+    assert!(lines.contains(&"# TYPE massa_api_requests_total counter"));
+    assert!(lines.contains(&"massa_api_requests_total{method=\"get_metrics_text\"} 2"));
 
-    let response: Result<(), Error> = client
-        .request(
-            "node_add_to_bootstrap_whitelist",
-            rpc_params![Vec::<IpAddr>::new()],
-        )
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    api_public_handle.stop().await;
+}
 
-    let response: Result<(), Error> = client
-        .request(
-            "node_remove_from_bootstrap_whitelist",
-            rpc_params![Vec::<IpAddr>::new()],
-        )
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+#[tokio::test]
+async fn get_slot_fork_choice() {
+    let addr: SocketAddr = "[::]:5094".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
 
-    let response: Result<(), Error> = client
-        .request("node_bootstrap_blacklist", rpc_params![])
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    let slot = Slot::new(1, 0);
+    let chosen = create_block_with_operations(&KeyPair::generate(0).unwrap(), slot, vec![]);
+    let competing = create_block_with_operations(&KeyPair::generate(0).unwrap(), slot, vec![]);
+    let (chosen_id, competing_id) = (chosen.id, competing.id);
 
-    let response: Result<(), Error> = client
-        .request(
-            "node_add_to_bootstrap_blacklist",
-            rpc_params![Vec::<IpAddr>::new()],
-        )
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_graph_status()
+        .returning(move |start, end| {
+            assert_eq!(start, Some(Slot::new(1, 0)));
+            assert_eq!(end, Some(Slot::new(1, 1)));
+            let mut active_blocks = PreHashMap::with_capacity(2);
+            for block in [&chosen, &competing] {
+                active_blocks.insert(
+                    block.id,
+                    ExportCompiledBlock {
+                        header: block.content.header.clone(),
+                        children: vec![],
+                        is_final: false,
+                    },
+                );
+            }
+            Ok(BlockGraphExport {
+                genesis_blocks: vec![],
+                active_blocks,
+                discarded_blocks: PreHashMap::default(),
+                best_parents: vec![],
+                latest_final_blocks_periods: vec![],
+                gi_head: PreHashMap::default(),
+                max_cliques: vec![
+                    Clique {
+                        block_ids: PreHashSet::from_iter([competing.id]),
+                        fitness: 5,
+                        is_blockclique: false,
+                    },
+                    Clique {
+                        block_ids: PreHashSet::from_iter([chosen.id]),
+                        fitness: 10,
+                        is_blockclique: true,
+                    },
+                ],
+            })
+        });
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
 
-    let response: Result<(), Error> = client
-        .request(
-            "node_remove_from_bootstrap_blacklist",
-            rpc_params![Vec::<IpAddr>::new()],
-        )
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
 
-    let response: Result<(), Error> = client
-        .request("add_staking_secret_keys", rpc_params![Vec::<String>::new()])
-        .await;
-    assert!(response
-        .unwrap_err()
-        .to_string()
-        .contains("The wrong API (either Public or Private) was called"));
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+
+    let response: Vec<SlotForkChoiceBlock> = client
+        .request("get_slot_fork_choice", rpc_params![slot])
+        .await
+        .unwrap();
+
+    // the blockclique block comes first
+    assert_eq!(response.len(), 2);
+    assert_eq!(response[0].id, chosen_id);
+    assert!(response[0].is_in_blockclique);
+    assert_eq!(response[0].cliques.len(), 1);
+    assert_eq!(response[0].cliques[0].index, 1);
+    assert_eq!(response[0].cliques[0].fitness, 10);
+    assert_eq!(response[1].id, competing_id);
+    assert!(!response[1].is_in_blockclique);
+    assert_eq!(response[1].cliques[0].index, 0);
+    assert_eq!(response[1].cliques[0].fitness, 5);
 
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_openrpc_spec() {
-    let addr: SocketAddr = "[::]:5016".parse().unwrap();
-    let (mut api_public, mut config) = start_public_api(addr);
-
-    let open_rpc_file = NamedTempFile::new().unwrap();
-
-    let s = "{
-        \"title\": \"Sample Pet Store App\",
-        \"version\": \"1.0.1\"
-      }";
+async fn get_slot_fork_choice_final_block() {
+    let addr: SocketAddr = "[::]:5110".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
 
-    serde_json::to_writer_pretty(open_rpc_file.as_file(), s).expect("unable to write ledger file");
+    let slot = Slot::new(1, 0);
+    let final_block = create_block_with_operations(&KeyPair::generate(0).unwrap(), slot, vec![]);
+    let competing = create_block_with_operations(&KeyPair::generate(0).unwrap(), slot, vec![]);
+    let (final_id, competing_id) = (final_block.id, competing.id);
 
-    config.openrpc_spec_path = open_rpc_file.path().to_path_buf();
-    api_public.0.api_settings.openrpc_spec_path = open_rpc_file.path().to_path_buf();
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_graph_status()
+        .returning(move |_, _| {
+            let mut active_blocks = PreHashMap::with_capacity(2);
+            for (block, is_final) in [(&final_block, true), (&competing, false)] {
+                active_blocks.insert(
+                    block.id,
+                    ExportCompiledBlock {
+                        header: block.content.header.clone(),
+                        children: vec![],
+                        is_final,
+                    },
+                );
+            }
+            // final blocks are removed from the cliques
+            Ok(BlockGraphExport {
+                genesis_blocks: vec![],
+                active_blocks,
+                discarded_blocks: PreHashMap::default(),
+                best_parents: vec![],
+                latest_final_blocks_periods: vec![],
+                gi_head: PreHashMap::default(),
+                max_cliques: vec![
+                    Clique {
+                        block_ids: PreHashSet::from_iter([competing.id]),
+                        fitness: 5,
+                        is_blockclique: false,
+                    },
+                    Clique {
+                        block_ids: PreHashSet::default(),
+                        fitness: 0,
+                        is_blockclique: true,
+                    },
+                ],
+            })
+        });
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -1136,20 +6039,79 @@ async fn get_openrpc_spec() {
             addr.to_string().split(':').last().unwrap()
         ))
         .unwrap();
-    let params = rpc_params![];
 
-    let response: Value = client.request("rpc.discover", params).await.unwrap();
-    assert!(response.as_str().unwrap().contains("Sample Pet Store App"));
+    let response: Vec<SlotForkChoiceBlock> = client
+        .request("get_slot_fork_choice", rpc_params![slot])
+        .await
+        .unwrap();
+
+    // the final block is the chosen one even though it is in no clique
+    assert_eq!(response.len(), 2);
+    assert_eq!(response[0].id, final_id);
+    assert!(response[0].is_final);
+    assert!(response[0].is_in_blockclique);
+    assert!(response[0].cliques.is_empty());
+    assert_eq!(response[0].fitness, 1);
+    assert_eq!(response[1].id, competing_id);
+    assert!(!response[1].is_in_blockclique);
+    assert_eq!(response[1].fitness, 1);
 
     api_public_handle.stop().await;
+}
 
-    let addr: SocketAddr = "[::]:5017".parse().unwrap();
-    let (mut api_public, mut config) = start_public_api(addr);
+#[tokio::test]
+async fn get_graph_cliques_detailed() {
+    let addr: SocketAddr = "[::]:5096".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
 
-    let open_rpc_file = NamedTempFile::new().unwrap();
+    let common =
+        create_block_with_operations(&KeyPair::generate(0).unwrap(), Slot::new(1, 0), vec![]);
+    let chosen =
+        create_block_with_operations(&KeyPair::generate(0).unwrap(), Slot::new(2, 0), vec![]);
+    let competing =
+        create_block_with_operations(&KeyPair::generate(0).unwrap(), Slot::new(2, 0), vec![]);
+    let (common_id, chosen_id, competing_id) = (common.id, chosen.id, competing.id);
 
-    config.openrpc_spec_path = open_rpc_file.path().to_path_buf();
-    api_public.0.api_settings.openrpc_spec_path = open_rpc_file.path().to_path_buf();
+    let mut consensus_ctrl = MockConsensusController::new();
+    consensus_ctrl
+        .expect_get_block_graph_status()
+        .times(1)
+        .returning(move |start, end| {
+            assert_eq!(start, Some(Slot::new(1, 0)));
+            assert_eq!(end, Some(Slot::new(3, 0)));
+            let mut active_blocks = PreHashMap::with_capacity(3);
+            for block in [&common, &chosen, &competing] {
+                active_blocks.insert(
+                    block.id,
+                    ExportCompiledBlock {
+                        header: block.content.header.clone(),
+                        children: vec![],
+                        is_final: false,
+                    },
+                );
+            }
+            Ok(BlockGraphExport {
+                genesis_blocks: vec![],
+                active_blocks,
+                discarded_blocks: PreHashMap::default(),
+                best_parents: vec![],
+                latest_final_blocks_periods: vec![],
+                gi_head: PreHashMap::default(),
+                max_cliques: vec![
+                    Clique {
+                        block_ids: PreHashSet::from_iter([common.id, competing.id]),
+                        fitness: 5,
+                        is_blockclique: false,
+                    },
+                    Clique {
+                        block_ids: PreHashSet::from_iter([common.id, chosen.id]),
+                        fitness: 10,
+                        is_blockclique: true,
+                    },
+                ],
+            })
+        });
+    api_public.0.consensus_controller = Box::new(consensus_ctrl);
 
     let api_public_handle = api_public
         .serve(&addr, &config)
@@ -1162,43 +6124,92 @@ async fn get_openrpc_spec() {
             addr.to_string().split(':').last().unwrap()
         ))
         .unwrap();
-    let params = rpc_params![];
 
-    let response: Result<Value, Error> = client.request("rpc.discover", params).await;
+    let response: Vec<DetailedClique> = client
+        .request(
+            "get_graph_cliques_detailed",
+            rpc_params![Some(Slot::new(1, 0)), Some(Slot::new(3, 0))],
+        )
+        .await
+        .unwrap();
+
+    // blocks are sorted by slot, and flagged as in the blockclique in every clique they belong to
+    assert_eq!(response.len(), 2);
+    assert!(!response[0].is_blockclique);
+    assert_eq!(
+        response[0]
+            .blocks
+            .iter()
+            .map(|block| block.id)
+            .collect::<Vec<_>>(),
+        vec![common_id, competing_id]
+    );
+    assert!(response[0].blocks[0].is_in_blockclique);
+    assert!(!response[0].blocks[1].is_in_blockclique);
+    assert!(response[1].is_blockclique);
+    assert_eq!(response[1].fitness, 10);
+    assert_eq!(
+        response[1]
+            .blocks
+            .iter()
+            .map(|block| block.id)
+            .collect::<Vec<_>>(),
+        vec![common_id, chosen_id]
+    );
 
+    // the range is bounded like in get_graph_interval
+    let response: Result<Vec<DetailedClique>, Error> = client
+        .request(
+            "get_graph_cliques_detailed",
+            rpc_params![Some(Slot::new(0, 0)), Some(Slot::new(100_000, 0))],
+        )
+        .await;
     assert!(response
         .unwrap_err()
         .to_string()
-        .contains("failed to parse OpenRPC specification"));
+        .contains("the interval covers"));
+
     api_public_handle.stop().await;
 }
 
 #[tokio::test]
-async fn get_stakers() {
-    let addr: SocketAddr = "[::]:5015".parse().unwrap();
+async fn get_balance_at_slot() {
+    let addr: SocketAddr = "[::]:5104".parse().unwrap();
     let (mut api_public, config) = start_public_api(addr);
 
-    let mut exec_ctrl = MockExecutionController::new();
-    exec_ctrl.expect_get_cycle_active_rolls().returning(|_| {
-        let mut map = std::collections::BTreeMap::new();
-        map.insert(
-            Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap(),
-            5_u64,
-        );
-        map.insert(
-            Address::from_str("AU12htxRWiEm8jDJpJptr6cwEhWNcCSFWstN1MLSa96DDkVM9Y42G").unwrap(),
-            10_u64,
-        );
-        map.insert(
-            Address::from_str("AU12cMW9zRKFDS43Z2W88VCmdQFxmHjAo54XvuVV34UzJeXRLXW9M").unwrap(),
-            20_u64,
-        );
-        map.insert(
-            Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key()),
-            30_u64,
-        );
+    let known = Address::from_str("AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x").unwrap();
+    let unknown =
+        Address::from_str("AU12htxRWiEm8jDJpJptr6cwEhWNcCSFWstN1MLSa96DDkVM9Y42G").unwrap();
 
-        map
+    // states from slot (2, 0) to slot (3, 0) are retained
+    let mut exec_ctrl: MockExecutionController = MockExecutionController::new();
+    exec_ctrl.expect_query_state().returning(move |req| {
+        let responses = req
+            .requests
+            .into_iter()
+            .map(|item| match item {
+                ExecutionQueryRequestItem::AddressBalanceAtSlot { slot, .. }
+                    if slot < Slot::new(2, 0) =>
+                {
+                    Err(ExecutionQueryError::SlotUnavailable(format!(
+                        "state at slot {} has been pruned",
+                        slot
+                    )))
+                }
+                ExecutionQueryRequestItem::AddressBalanceAtSlot { addr, slot } if addr == known => {
+                    Ok(ExecutionQueryResponseItem::Amount(Amount::from_raw(
+                        slot.period,
+                    )))
+                }
+                _ => Err(ExecutionQueryError::NotFound("Account".to_string())),
+            })
+            .collect();
+        ExecutionQueryResponse {
+            responses,
+            candidate_cursor: Slot::new(3, 0),
+            final_cursor: Slot::new(2, 0),
+            final_state_fingerprint: massa_hash::Hash::compute_from(&Vec::new()),
+        }
     });
 
     api_public.0.execution_controller = Box::new(exec_ctrl);
@@ -1214,14 +6225,69 @@ async fn get_stakers() {
             addr.to_string().split(':').last().unwrap()
         ))
         .unwrap();
-    let params = rpc_params![];
 
-    let response: Value = client.request("get_stakers", params).await.unwrap();
+    let response: Amount = client
+        .request("get_balance_at_slot", rpc_params![known, Slot::new(3, 0)])
+        .await
+        .unwrap();
+    assert_eq!(response, Amount::from_raw(3));
 
-    response.as_array().unwrap().iter().for_each(|v| {
-        let staker: (Address, u64) = serde_json::from_value(v.clone()).unwrap();
-        assert!(staker.1 > 4);
+    let response: Amount = client
+        .request("get_balance_at_slot", rpc_params![unknown, Slot::new(3, 0)])
+        .await
+        .unwrap();
+    assert_eq!(response, Amount::zero());
+
+    let response: Result<Amount, Error> = client
+        .request("get_balance_at_slot", rpc_params![known, Slot::new(1, 0)])
+        .await;
+    assert!(response.unwrap_err().to_string().contains("pruned"));
+
+    api_public_handle.stop().await;
+}
+
+#[tokio::test]
+async fn get_execution_status() {
+    let addr: SocketAddr = "[::]:5105".parse().unwrap();
+    let (mut api_public, config) = start_public_api(addr);
+
+    let mut exec_ctrl = MockExecutionController::new();
+    exec_ctrl.expect_get_stats().returning(|| ExecutionStats {
+        time_window_start: MassaTime::now().unwrap(),
+        time_window_end: MassaTime::now().unwrap(),
+        final_block_count: 0,
+        final_executed_operations_count: 0,
+        active_cursor: Slot::new(12, 3),
+        final_cursor: Slot::new(10, 1),
     });
 
+    api_public.0.execution_controller = Box::new(exec_ctrl);
+
+    let api_public_handle = api_public
+        .serve(&addr, &config)
+        .await
+        .expect("failed to start PUBLIC API");
+
+    let client = HttpClientBuilder::default()
+        .build(format!(
+            "http://localhost:{}",
+            addr.to_string().split(':').last().unwrap()
+        ))
+        .unwrap();
+    let response: Vec<ThreadExecutionSlots> = client
+        .request("get_execution_status", rpc_params![])
+        .await
+        .unwrap();
+
+    assert_eq!(response.len(), config.thread_count as usize);
+    for status in response {
+        let (final_period, active_period) = (
+            if status.thread <= 1 { 10 } else { 9 },
+            if status.thread <= 3 { 12 } else { 11 },
+        );
+        assert_eq!(status.final_slot, Slot::new(final_period, status.thread));
+        assert_eq!(status.active_slot, Slot::new(active_period, status.thread));
+    }
+
     api_public_handle.stop().await;
 }
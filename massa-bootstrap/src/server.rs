@@ -45,7 +45,10 @@ use parking_lot::RwLock;
 use std::{
     collections::HashMap,
     net::{IpAddr, SocketAddr},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
     thread,
     time::{Duration, Instant},
 };
@@ -81,6 +84,8 @@ pub struct BootstrapManager {
     update_stopper_tx: crossbeam::channel::Sender<()>,
     /// shared white/black list
     pub white_black_list: SharedWhiteBlackList<'static>,
+    /// number of active bootstrap sessions
+    active_sessions: Arc<AtomicUsize>,
 }
 
 impl BootstrapManager {
@@ -92,6 +97,7 @@ impl BootstrapManager {
         update_stopper_tx: crossbeam::channel::Sender<()>,
         listener_stopper: BootstrapListenerStopHandle,
         white_black_list: SharedWhiteBlackList<'static>,
+        active_sessions: Arc<AtomicUsize>,
     ) -> Self {
         Self {
             update_handle,
@@ -99,9 +105,15 @@ impl BootstrapManager {
             update_stopper_tx,
             listener_stopper,
             white_black_list,
+            active_sessions,
         }
     }
 
+    /// Get the number of active bootstrap sessions, kept up to date by the server, to read it from other modules.
+    pub fn active_sessions(&self) -> Arc<AtomicUsize> {
+        self.active_sessions.clone()
+    }
+
     /// stop the bootstrap server
     pub fn stop(self) -> Result<(), BootstrapError> {
         massa_trace!("bootstrap.lib.stop", {});
@@ -175,6 +187,9 @@ pub fn start_bootstrap_server(
         })
         .expect("in `start_bootstrap_server`, OS failed to spawn list-updater thread");
 
+    let active_sessions = Arc::new(AtomicUsize::new(0));
+    let main_loop_active_sessions = active_sessions.clone();
+
     let w_b_list = white_black_list.clone();
     let main_handle = thread::Builder::new()
         .name("bs-main-loop".to_string())
@@ -191,7 +206,7 @@ pub fn start_bootstrap_server(
                 bootstrap_config: config,
                 massa_metrics,
             }
-            .event_loop(max_bootstraps)
+            .event_loop(max_bootstraps, main_loop_active_sessions)
         })
         .expect("in `start_bootstrap_server`, OS failed to spawn main-loop thread");
     // Give the runtime to the bootstrap manager, otherwise it will be dropped, forcibly aborting the spawned tasks.
@@ -202,6 +217,7 @@ pub fn start_bootstrap_server(
         update_stopper_tx,
         listener_stopper,
         white_black_list,
+        active_sessions,
     ))
 }
 
@@ -239,9 +255,11 @@ impl BootstrapServer<'_> {
         }
     }
 
-    fn event_loop(mut self, max_bootstraps: usize) -> Result<(), BootstrapError> {
-        // Use the strong-count of this variable to track the session count
-        let bootstrap_sessions_counter: Arc<()> = Arc::new(());
+    fn event_loop(
+        mut self,
+        max_bootstraps: usize,
+        active_sessions: Arc<AtomicUsize>,
+    ) -> Result<(), BootstrapError> {
         let per_ip_min_interval = self.bootstrap_config.per_ip_min_interval.to_duration();
         // TODO: Work out how to integration-test this
         let limit = self.bootstrap_config.rate_limit;
@@ -280,13 +298,8 @@ impl BootstrapServer<'_> {
                     continue;
                 };
 
-                // the `- 1` is to account for the top-level Arc that is created at the top
-                // of this method. subsequent counts correspond to each `clone` that is passed
-                // into a thread
-                // TODO: If we don't find a way to handle the counting automagically, make
-                //       a dedicated wrapper-type with doc-comments, manual drop impl that
-                //       integrates logging, etc...
-                if Arc::strong_count(&bootstrap_sessions_counter) - 1 < max_bootstraps {
+                // sessions are only started by this loop, so the count cannot grow between the check and the start
+                if active_sessions.load(Ordering::SeqCst) < max_bootstraps {
                     massa_trace!("bootstrap.lib.run.select.accept", {
                         "remote_addr": remote_addr
                     });
@@ -336,7 +349,7 @@ impl BootstrapServer<'_> {
                     let protocol_controller = self.protocol_controller.clone();
                     let config = self.bootstrap_config.clone();
 
-                    let bootstrap_count_token = bootstrap_sessions_counter.clone();
+                    let session_token = SessionToken::new(&active_sessions);
                     let massa_metrics = self.massa_metrics.clone();
 
                     let _ = thread::Builder::new()
@@ -344,7 +357,7 @@ impl BootstrapServer<'_> {
                         .spawn(move || {
                             run_bootstrap_session(
                                 server_binding,
-                                bootstrap_count_token,
+                                session_token,
                                 config,
                                 remote_addr,
                                 data_execution,
@@ -356,7 +369,7 @@ impl BootstrapServer<'_> {
                         });
 
                     massa_trace!("bootstrap.session.started", {
-                        "active_count": Arc::strong_count(&bootstrap_sessions_counter) - 1
+                        "active_count": active_sessions.load(Ordering::SeqCst)
                     });
                 } else {
                     server_binding.close_and_send_error(
@@ -398,18 +411,33 @@ impl BootstrapServer<'_> {
     }
 }
 
+/// Held by a running bootstrap session: counted in the active sessions until dropped, even if the session panics
+struct SessionToken(Arc<AtomicUsize>);
+
+impl SessionToken {
+    fn new(active_sessions: &Arc<AtomicUsize>) -> Self {
+        active_sessions.fetch_add(1, Ordering::SeqCst);
+        SessionToken(active_sessions.clone())
+    }
+}
+
+impl Drop for SessionToken {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 /// To be called from a `thread::spawn` invocation
 ///
 /// Runs the bootstrap management in a dedicated thread, handling the async by using
 /// a multi-thread-aware tokio runtime (the bs-main-loop runtime, to be exact). When this
 /// function blocks in the `block_on`, it should thread-block, and switch to another session
 ///
-/// The arc_counter variable is used as a proxy to keep track the number of active bootstrap
-/// sessions.
+/// The session token keeps this session counted in the active bootstrap sessions.
 #[allow(clippy::too_many_arguments)]
 fn run_bootstrap_session(
     mut server: BootstrapServerBinder,
-    arc_counter: Arc<()>,
+    session_token: SessionToken,
     config: BootstrapConfig,
     remote_addr: SocketAddr,
     data_execution: Arc<RwLock<FinalState>>,
@@ -432,11 +460,10 @@ fn run_bootstrap_session(
     );
 
     // This drop allows the server to accept new connections before having to complete the error notifications
-    // account for this session being finished, as well as the root-instance
     massa_trace!("bootstrap.session.finished", {
-        "sessions_remaining": Arc::strong_count(&arc_counter) - 2
+        "sessions_remaining": session_token.0.load(Ordering::SeqCst).saturating_sub(1)
     });
-    drop(arc_counter);
+    drop(session_token);
     match res {
         Err(BootstrapError::TimedOut(_)) => {
             debug!("bootstrap timeout for peer {}", remote_addr);
@@ -87,6 +87,13 @@ pub enum Command {
     )]
     node_ban_by_id,
 
+    #[strum(
+        ascii_case_insensitive,
+        props(pwd_not_needed = "true"),
+        message = "show the currently banned peers, with the time and reason of their ban"
+    )]
+    node_get_banned_peers,
+
     #[strum(
         ascii_case_insensitive,
         props(pwd_not_needed = "true"),
@@ -160,7 +167,7 @@ pub enum Command {
     #[strum(
         ascii_case_insensitive,
         props(args = "Address Key", pwd_not_needed = "true"),
-        message = "get a datastore entry (key must be UTF-8)"
+        message = "get a datastore entry (key must be UTF-8, an empty key gets all the entries of the address)"
     )]
     get_datastore_entry,
 
@@ -517,6 +524,11 @@ impl Command {
                 Ok(Box::new(()))
             }
 
+            Command::node_get_banned_peers => match client.private.get_banned_peers().await {
+                Ok(banned_peers) => Ok(Box::new(banned_peers)),
+                Err(e) => rpc_error!(e),
+            },
+
             Command::node_stop => {
                 match client.private.stop_node().await {
                     Ok(()) => {
@@ -873,7 +885,10 @@ impl Command {
                             {
                                 match addresses_info.get(0) {
                                     Some(info) => {
-                                        if info.candidate_balance < total {
+                                        if info
+                                            .candidate_balance
+                                            .map_or(false, |balance| balance < total)
+                                        {
                                             client_warning!("this operation may be rejected due to insufficient balance");
                                         }
                                     }
@@ -918,8 +933,12 @@ impl Command {
                     if let Ok(addresses_info) = client.public.get_addresses(vec![addr]).await {
                         match addresses_info.get(0) {
                             Some(info) => {
-                                if info.candidate_balance < fee
-                                    || roll_count > info.candidate_roll_count
+                                if info
+                                    .candidate_balance
+                                    .map_or(false, |balance| balance < fee)
+                                    || info
+                                        .candidate_roll_count
+                                        .map_or(false, |rolls| roll_count > rolls)
                                 {
                                     client_warning!("this operation may be rejected due to insufficient balance or roll count");
                                 }
@@ -955,7 +974,10 @@ impl Command {
                     if let Ok(addresses_info) = client.public.get_addresses(vec![addr]).await {
                         match addresses_info.get(0) {
                             Some(info) => {
-                                if info.candidate_balance < fee {
+                                if info
+                                    .candidate_balance
+                                    .map_or(false, |balance| balance < fee)
+                                {
                                     client_warning!("this operation may be rejected due to insufficient balance");
                                 }
                             }
@@ -1020,7 +1042,9 @@ impl Command {
                     if let Ok(addresses_info) = client.public.get_addresses(vec![addr]).await {
                         match addresses_info.get(0) {
                             Some(info) => {
-                                if info.candidate_balance < fee.saturating_add(max_coins) {
+                                if info.candidate_balance.map_or(false, |balance| {
+                                    balance < fee.saturating_add(max_coins)
+                                }) {
                                     client_warning!("this operation may be rejected due to insufficient balance");
                                 }
                             }
@@ -1078,7 +1102,10 @@ impl Command {
                             {
                                 match addresses_info.get(0) {
                                     Some(info) => {
-                                        if info.candidate_balance < total {
+                                        if info
+                                            .candidate_balance
+                                            .map_or(false, |balance| balance < total)
+                                        {
                                             client_warning!("this operation may be rejected due to insufficient balance");
                                         }
                                     }
@@ -1156,7 +1183,9 @@ impl Command {
                         address,
                         operation_datastore: None, // TODO - #3072
                         is_final,
+                        label: None,
                         fee,
+                        from_address: None,
                     })
                     .await
                 {
@@ -1197,8 +1226,10 @@ impl Command {
                         parameter,
                         max_gas,
                         is_final,
+                        label: None,
                         coins,
                         fee,
+                        trace: false,
                     })
                     .await
                 {
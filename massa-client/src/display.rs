@@ -4,8 +4,12 @@ use crate::cmds::ExtendedWallet;
 use console::style;
 use erased_serde::{Serialize, Serializer};
 use massa_api_exports::{
-    address::AddressInfo, block::BlockInfo, datastore::DatastoreEntryOutput,
-    endorsement::EndorsementInfo, execution::ExecuteReadOnlyResponse, node::NodeStatus,
+    address::AddressInfo,
+    block::BlockInfo,
+    datastore::DatastoreEntryOutput,
+    endorsement::EndorsementInfo,
+    execution::ExecuteReadOnlyResponse,
+    node::{BannedPeerInfo, NodeStatus},
     operation::OperationInfo,
 };
 use massa_models::composite::PubkeySig;
@@ -107,6 +111,17 @@ impl Style {
             Style::Time => 117,      // #87d7ff
         })
     }
+
+    /// Style an optional value, displaying `unavailable` when it is missing
+    fn style_option<T: ToString>(
+        &self,
+        msg: Option<T>,
+    ) -> console::StyledObject<std::string::String> {
+        match msg {
+            Some(msg) => self.style(msg),
+            None => Style::Unknown.style("unavailable"),
+        }
+    }
 }
 
 pub trait Output: Serialize {
@@ -150,18 +165,18 @@ impl Output for ExtendedWallet {
             println!(
                 "\tBalance: {}={}, {}={}",
                 Style::Finished.style("final"),
-                Style::Coins.style(entry.address_info.final_balance),
+                Style::Coins.style_option(entry.address_info.final_balance),
                 Style::Pending.style("candidate"),
-                Style::Coins.style(entry.address_info.candidate_balance),
+                Style::Coins.style_option(entry.address_info.candidate_balance),
             );
             println!(
                 "\tRolls: {}={}, {}={}, {}={}",
                 Style::Good.style("active"),
                 Style::Protocol.style(entry.address_info.active_rolls),
                 Style::Finished.style("final"),
-                Style::Protocol.style(entry.address_info.final_rolls),
+                Style::Protocol.style_option(entry.address_info.final_rolls),
                 Style::Pending.style("candidate"),
-                Style::Protocol.style(entry.address_info.candidate_rolls),
+                Style::Protocol.style_option(entry.address_info.candidate_rolls),
             );
             println!("{}", Style::Separator.style("====="));
         }
@@ -243,6 +258,14 @@ impl Output for NodeStatus {
             );
         }
         println!("Next slot: {}", Style::Protocol.style(self.next_slot));
+        println!(
+            "Final slots: {}",
+            self.final_slots
+                .iter()
+                .map(|slot| Style::Protocol.style(slot).to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
         println!();
 
         self.consensus_stats.pretty_print();
@@ -250,17 +273,27 @@ impl Output for NodeStatus {
         println!("Pool stats:");
         println!(
             "\tOperations count: {}",
-            Style::Protocol.style(self.pool_stats.0)
+            Style::Protocol.style(self.pool_stats.operation_count)
         );
         println!(
             "\tEndorsements count: {}",
-            Style::Protocol.style(self.pool_stats.1)
+            Style::Protocol.style(self.pool_stats.endorsement_count)
         );
         println!();
 
         self.network_stats.pretty_print();
         self.execution_stats.pretty_print();
 
+        if self.is_bootstrap_server {
+            println!(
+                "Bootstrap server: {} active session(s)",
+                Style::Protocol.style(self.active_bootstrap_sessions)
+            );
+        } else {
+            println!("Bootstrap server: {}", Style::Unknown.style("disabled"));
+        }
+        println!();
+
         if !self.connected_nodes.is_empty() {
             println!("Connected nodes:");
             for (node_id, (ip_addr, is_outgoing)) in &self.connected_nodes {
@@ -418,16 +451,16 @@ impl Output for Vec<AddressInfo> {
             println!(
                 "\tBalance: {}={}, {}={}",
                 Style::Finished.style("final"),
-                Style::Coins.style(info.final_balance),
+                Style::Coins.style_option(info.final_balance),
                 Style::Pending.style("candidate"),
-                Style::Coins.style(info.candidate_balance),
+                Style::Coins.style_option(info.candidate_balance),
             );
             println!(
                 "\tRolls: {}={}, {}={}",
                 Style::Finished.style("final"),
-                Style::Protocol.style(info.final_roll_count),
+                Style::Protocol.style_option(info.final_roll_count),
                 Style::Pending.style("candidate"),
-                Style::Protocol.style(info.candidate_roll_count),
+                Style::Protocol.style_option(info.candidate_roll_count),
             );
 
             print!("\tLocked coins:");
@@ -483,6 +516,14 @@ impl Output for Vec<EndorsementInfo> {
     }
 }
 
+impl Output for Vec<BannedPeerInfo> {
+    fn pretty_print(&self) {
+        for banned_peer in self {
+            println!("{}", banned_peer);
+        }
+    }
+}
+
 impl Output for Vec<IpAddr> {
     fn pretty_print(&self) {
         for ips in self {
@@ -568,6 +609,18 @@ impl Output for Vec<OperationId> {
     }
 }
 
+impl Output for Vec<(OperationId, bool)> {
+    fn pretty_print(&self) {
+        for (operation_id, accepted) in self {
+            if *accepted {
+                println!("{}", operation_id);
+            } else {
+                println!("{} {}", operation_id, style("(rejected by pool)").red());
+            }
+        }
+    }
+}
+
 impl Output for Vec<Address> {
     fn pretty_print(&self) {
         for addr in self {
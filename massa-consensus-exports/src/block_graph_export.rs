@@ -27,3 +27,60 @@ pub struct BlockGraphExport {
     /// List of maximal cliques of compatible blocks.
     pub max_cliques: Vec<Clique>,
 }
+
+impl BlockGraphExport {
+    /// Renders the exported graph in Graphviz DOT format, one node per block with edges to its parents.
+    ///
+    /// Active blocks are colored by status: final, in the blockclique, or in an alternative clique.
+    /// Discarded blocks are drawn in red when stale and in grey otherwise.
+    pub fn to_dot(&self) -> String {
+        let blockclique = self.max_cliques.iter().find(|clique| clique.is_blockclique);
+
+        // sort blocks by slot so that the output is deterministic
+        let mut blocks: Vec<(Slot, BlockId, &'static str, &Vec<BlockId>)> =
+            self.active_blocks
+                .iter()
+                .map(|(id, block)| {
+                    let color = if block.is_final {
+                        "palegreen"
+                    } else if blockclique.map_or(false, |clique| clique.block_ids.contains(id)) {
+                        "lightblue"
+                    } else {
+                        "lightyellow"
+                    };
+                    (
+                        block.header.content.slot,
+                        *id,
+                        color,
+                        &block.header.content.parents,
+                    )
+                })
+                .chain(self.discarded_blocks.iter().map(
+                    |(id, (reason, (slot, _creator, parents)))| {
+                        let color = match reason {
+                            DiscardReason::Stale => "salmon",
+                            _ => "lightgrey",
+                        };
+                        (*slot, *id, color, parents)
+                    },
+                ))
+                .collect();
+        blocks.sort_unstable_by_key(|(slot, id, _, _)| (*slot, *id));
+
+        let mut dot =
+            String::from("digraph BlockGraph {\n    rankdir=LR;\n    node [style=filled];\n");
+        for (slot, id, color, _) in &blocks {
+            dot.push_str(&format!(
+                "    \"{}\" [label=\"{}\\n{}\", fillcolor={}];\n",
+                id, slot, id, color
+            ));
+        }
+        for (_, id, _, parents) in &blocks {
+            for parent in parents.iter() {
+                dot.push_str(&format!("    \"{}\" -> \"{}\";\n", id, parent));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
@@ -3,10 +3,11 @@ use crate::{bootstrapable_graph::BootstrapableGraph, error::ConsensusError};
 use massa_models::prehash::PreHashSet;
 use massa_models::streaming_step::StreamingStep;
 use massa_models::{
-    block::BlockGraphStatus, block_header::BlockHeader, block_id::BlockId, clique::Clique,
-    secure_share::SecureShare, slot::Slot, stats::ConsensusStats,
+    address::Address, block::BlockGraphStatus, block_header::BlockHeader, block_id::BlockId,
+    clique::Clique, secure_share::SecureShare, slot::Slot, stats::ConsensusStats,
 };
 use massa_storage::Storage;
+use massa_time::MassaTime;
 
 /// Interface that communicates with the graph worker thread
 #[cfg_attr(feature = "test-exports", mockall::automock)]
@@ -71,12 +72,53 @@ pub trait ConsensusController: Send + Sync {
     /// The stats of the consensus
     fn get_stats(&self) -> Result<ConsensusStats, ConsensusError>;
 
+    /// Get the history of the consensus stats
+    ///
+    /// # Arguments
+    /// * `duration`: how far back in time to look for past snapshots
+    ///
+    /// # Returns
+    /// The past stats snapshots ended within `duration`, oldest first, followed by the current stats
+    fn get_stats_history(&self, duration: MassaTime)
+        -> Result<Vec<ConsensusStats>, ConsensusError>;
+
     /// Get the best parents for the next block to be produced
     ///
     /// # Returns
     /// The id of best parents for the next block to be produced along with their period
     fn get_best_parents(&self) -> Vec<(BlockId, u64)>;
 
+    /// Get the most recently discarded stale blocks
+    ///
+    /// # Arguments
+    /// * `limit`: maximum number of blocks to return
+    ///
+    /// # Returns
+    /// The stale blocks, most recent first, in exported version `(block_id, (slot, creator_address, parents))`
+    #[allow(clippy::type_complexity)]
+    fn get_recent_stale_blocks(
+        &self,
+        limit: usize,
+    ) -> Vec<(BlockId, (Slot, Address, Vec<BlockId>))>;
+
+    /// Get the latest blocks of the blockclique, final blocks included
+    ///
+    /// # Arguments
+    /// * `count`: maximum number of blocks to return
+    ///
+    /// # Returns
+    /// The latest blocks with their slot and the time at which they reached the node if known, oldest first
+    fn get_recent_blockclique_blocks(
+        &self,
+        count: usize,
+    ) -> Vec<(BlockId, Slot, Option<MassaTime>)>;
+
+    /// Get the latest final slot of each thread
+    ///
+    /// # Returns
+    /// The slot of the latest final block, for each thread
+    fn get_latest_final_slots(&self) -> Vec<Slot>;
+
     /// Get the block id of the block at a specific slot in the blockclique
     ///
     /// # Arguments
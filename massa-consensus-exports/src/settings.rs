@@ -1,6 +1,7 @@
 use massa_signature::KeyPair;
-use massa_time::MassaTime;
+use massa_time::{Clock, MassaTime, SystemClock};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ConsensusConfig {
@@ -38,6 +39,8 @@ pub struct ConsensusConfig {
     pub end_timestamp: Option<MassaTime>,
     /// stats time span
     pub stats_timespan: MassaTime,
+    /// maximum number of consensus stats snapshots kept in history
+    pub stats_history_length: usize,
     /// channel size
     pub channel_size: usize,
     /// size of a consensus bootstrap streaming part
@@ -52,4 +55,12 @@ pub struct ConsensusConfig {
     pub broadcast_filled_blocks_channel_capacity: usize,
     /// last start period
     pub last_start_period: u64,
+    /// source of the current time, from which the slots are computed (not shared with the other workers)
+    #[serde(skip, default = "system_clock")]
+    pub clock: Arc<dyn Clock>,
+}
+
+/// clock of a deserialized config
+fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
 }
@@ -5,7 +5,8 @@ use massa_models::config::{
     },
     CONSENSUS_BOOTSTRAP_PART_SIZE,
 };
-use massa_time::MassaTime;
+use massa_time::{MassaTime, SystemClock};
+use std::sync::Arc;
 
 use crate::ConsensusConfig;
 
@@ -29,6 +30,7 @@ impl Default for ConsensusConfig {
             endorsement_count: ENDORSEMENT_COUNT,
             end_timestamp: None,
             stats_timespan: MassaTime::from_millis(60000),
+            stats_history_length: 60,
             channel_size: CHANNEL_SIZE,
             bootstrap_part_size: CONSENSUS_BOOTSTRAP_PART_SIZE,
             broadcast_enabled: true,
@@ -36,6 +38,7 @@ impl Default for ConsensusConfig {
             broadcast_blocks_channel_capacity: 128,
             broadcast_filled_blocks_channel_capacity: 128,
             last_start_period: 0,
+            clock: Arc::new(SystemClock),
         }
     }
 }
@@ -6,6 +6,7 @@ use massa_consensus_exports::{
     export_active_block::ExportActiveBlock, ConsensusController,
 };
 use massa_models::{
+    address::Address,
     block::{BlockGraphStatus, FilledBlock},
     block_header::BlockHeader,
     block_id::BlockId,
@@ -18,6 +19,7 @@ use massa_models::{
     streaming_step::StreamingStep,
 };
 use massa_storage::Storage;
+use massa_time::MassaTime;
 use parking_lot::RwLock;
 use std::sync::Arc;
 use tracing::log::{debug, trace, warn};
@@ -196,6 +198,14 @@ impl ConsensusController for ConsensusControllerImpl {
         self.shared_state.read().get_stats()
     }
 
+    /// Get the history of the consensus stats
+    fn get_stats_history(
+        &self,
+        duration: MassaTime,
+    ) -> Result<Vec<ConsensusStats>, ConsensusError> {
+        self.shared_state.read().get_stats_history(duration)
+    }
+
     /// Get the current best parents for a block creation
     ///
     /// # Returns:
@@ -204,6 +214,50 @@ impl ConsensusController for ConsensusControllerImpl {
         self.shared_state.read().best_parents.clone()
     }
 
+    /// Get the most recently discarded stale blocks
+    ///
+    /// # Arguments:
+    /// * `limit`: maximum number of blocks to return
+    ///
+    /// # Returns:
+    /// The stale blocks, most recent first, with their slot, creator and parents
+    fn get_recent_stale_blocks(
+        &self,
+        limit: usize,
+    ) -> Vec<(BlockId, (Slot, Address, Vec<BlockId>))> {
+        self.shared_state.read().get_recent_stale_blocks(limit)
+    }
+
+    /// Get the latest blocks of the blockclique, final blocks included
+    ///
+    /// # Arguments:
+    /// * `count`: maximum number of blocks to return
+    ///
+    /// # Returns:
+    /// The latest blocks with their slot and the time at which they reached the node if known, oldest first
+    fn get_recent_blockclique_blocks(
+        &self,
+        count: usize,
+    ) -> Vec<(BlockId, Slot, Option<MassaTime>)> {
+        self.shared_state
+            .read()
+            .get_recent_blockclique_blocks(count)
+    }
+
+    /// Get the latest final slot of each thread
+    ///
+    /// # Returns:
+    /// The slot of the latest final block, for each thread of the graph
+    fn get_latest_final_slots(&self) -> Vec<Slot> {
+        self.shared_state
+            .read()
+            .latest_final_blocks_periods
+            .iter()
+            .enumerate()
+            .map(|(thread, (_, period))| Slot::new(*period, thread as u8))
+            .collect()
+    }
+
     /// Get the block, that is in the blockclique, at a given slot.
     ///
     /// # Arguments:
@@ -5,7 +5,9 @@ use std::{
 
 use massa_consensus_exports::{
     block_graph_export::BlockGraphExport,
-    block_status::{BlockStatus, ExportCompiledBlock, HeaderOrBlock, StorageOrBlock},
+    block_status::{
+        BlockStatus, DiscardReason, ExportCompiledBlock, HeaderOrBlock, StorageOrBlock,
+    },
     error::ConsensusError,
     ConsensusChannels, ConsensusConfig,
 };
@@ -20,6 +22,7 @@ use massa_models::{
     clique::Clique,
     prehash::{CapacityAllocator, PreHashMap, PreHashSet},
     slot::Slot,
+    stats::ConsensusStats,
 };
 use massa_storage::Storage;
 use massa_time::MassaTime;
@@ -76,8 +79,12 @@ pub struct ConsensusState {
     pub final_block_stats: VecDeque<(MassaTime, Address, bool)>,
     /// Blocks that come from protocol used for stats and ids are removed when inserted in `final_block_stats`
     pub protocol_blocks: VecDeque<(MassaTime, BlockId)>,
+    /// Time at which each block still in the graph was received from protocol or produced by this node
+    pub block_arrival_times: PreHashMap<BlockId, MassaTime>,
     /// Stale block timestamp
     pub stale_block_stats: VecDeque<MassaTime>,
+    /// Past stats snapshots, one per `stats_timespan`, oldest first
+    pub stats_snapshots: VecDeque<ConsensusStats>,
     /// the time span considered for stats
     pub stats_history_timespan: MassaTime,
     /// the time span considered for desynchronization detection
@@ -454,6 +461,70 @@ impl ConsensusState {
         Ok(export)
     }
 
+    /// Gets at most `limit` of the most recently discarded stale blocks, most recent first,
+    /// in exported version `(block_id, (slot, creator_address, parents))`.
+    #[allow(clippy::type_complexity)]
+    pub fn get_recent_stale_blocks(
+        &self,
+        limit: usize,
+    ) -> Vec<(BlockId, (Slot, Address, Vec<BlockId>))> {
+        let mut stale_blocks: Vec<(u64, BlockId, (Slot, Address, Vec<BlockId>))> = self
+            .blocks_state
+            .discarded_blocks()
+            .iter()
+            .filter_map(|block_id| match self.blocks_state.get(block_id) {
+                Some(BlockStatus::Discarded {
+                    slot,
+                    creator,
+                    parents,
+                    reason: DiscardReason::Stale,
+                    sequence_number,
+                }) => Some((
+                    *sequence_number,
+                    *block_id,
+                    (*slot, *creator, parents.clone()),
+                )),
+                _ => None,
+            })
+            .collect();
+        // the sequence number increases each time a block changes status
+        stale_blocks.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+        stale_blocks
+            .into_iter()
+            .take(limit)
+            .map(|(_, block_id, exported)| (block_id, exported))
+            .collect()
+    }
+
+    /// Gets at most `count` of the latest blocks of the blockclique, final blocks included,
+    /// oldest first, as `(block_id, slot, arrival_time)`.
+    /// The arrival time is `None` if it is not known, for example for blocks received during bootstrap.
+    pub fn get_recent_blockclique_blocks(
+        &self,
+        count: usize,
+    ) -> Vec<(BlockId, Slot, Option<MassaTime>)> {
+        let blockclique = self.get_blockclique();
+        let mut blocks: Vec<(BlockId, Slot, Option<MassaTime>)> = self
+            .blocks_state
+            .active_blocks()
+            .iter()
+            .filter_map(|block_id| match self.blocks_state.get(block_id) {
+                Some(BlockStatus::Active { a_block, .. })
+                    if a_block.is_final || blockclique.contains(block_id) =>
+                {
+                    Some((
+                        *block_id,
+                        a_block.slot,
+                        self.block_arrival_times.get(block_id).copied(),
+                    ))
+                }
+                _ => None,
+            })
+            .collect();
+        blocks.sort_unstable_by_key(|(_, slot, _)| *slot);
+        blocks.split_off(blocks.len().saturating_sub(count))
+    }
+
     /// Gets all stored final blocks, not only the still-useful ones
     /// This is used when initializing Execution from Consensus.
     /// Since the Execution bootstrap snapshot is older than the Consensus snapshot,
@@ -92,10 +92,12 @@ impl ConsensusState {
         }
 
         // Block is coming from protocol mark it for desync calculation
+        let now = MassaTime::now()?;
         if !created {
-            let now = MassaTime::now()?;
             self.protocol_blocks.push_back((now, block_id));
         }
+        // keep the first time the block reached us, for block interval monitoring
+        self.block_arrival_times.entry(block_id).or_insert(now);
 
         debug!("received block {} for slot {}", block_id, slot);
 
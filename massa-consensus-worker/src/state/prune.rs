@@ -331,6 +331,13 @@ impl ConsensusState {
             .retain(|s, _| s.period > self.latest_final_blocks_periods[s.thread as usize].1);
     }
 
+    /// Forget the arrival times of blocks that are not in the graph anymore.
+    fn prune_block_arrival_times(&mut self) {
+        let blocks_state = &self.blocks_state;
+        self.block_arrival_times
+            .retain(|block_id, _| blocks_state.get(block_id).is_some());
+    }
+
     /// Clear all the caches and blocks waiting to be processed to avoid too much memory usage.
     pub fn prune(&mut self) -> Result<(), ConsensusError> {
         let before = self.max_cliques.len();
@@ -349,6 +356,9 @@ impl ConsensusState {
         // Step 5: prune nonfinal blocks per slot
         self.prune_nonfinal_blocks_per_slot();
 
+        // Step 6: forget the arrival times of blocks that left the graph
+        self.prune_block_arrival_times();
+
         let after = self.max_cliques.len();
         if before != after {
             debug!(
@@ -38,6 +38,24 @@ impl ConsensusState {
         })
     }
 
+    /// Get the stats snapshots whose window ended within the last `duration`, oldest first,
+    /// followed by a snapshot of the current window
+    pub fn get_stats_history(
+        &self,
+        duration: MassaTime,
+    ) -> Result<Vec<ConsensusStats>, ConsensusError> {
+        let current = self.get_stats()?;
+        let start = current.end_timespan.saturating_sub(duration);
+        let mut history: Vec<ConsensusStats> = self
+            .stats_snapshots
+            .iter()
+            .filter(|stats| stats.end_timespan >= start)
+            .cloned()
+            .collect();
+        history.push(current);
+        Ok(history)
+    }
+
     /// Must be called each tick to update stats. Will detect if a desynchronization happened
     pub fn stats_tick(&mut self) -> Result<(), ConsensusError> {
         #[cfg(not(feature = "sandbox"))]
@@ -46,6 +64,27 @@ impl ConsensusState {
         }
         // prune stats
         self.prune_stats()?;
+        // record a snapshot every stats timespan
+        self.snapshot_stats()?;
+        Ok(())
+    }
+
+    /// Helper function for stats_tick. Pushes the current stats to the snapshot history
+    /// once the previous snapshot window is over, keeping at most `stats_history_length` of them
+    fn snapshot_stats(&mut self) -> Result<(), ConsensusError> {
+        let now = MassaTime::now()?;
+        let due = match self.stats_snapshots.back() {
+            Some(last) => now >= last.end_timespan.saturating_add(self.config.stats_timespan),
+            None => now >= self.launch_time.saturating_add(self.config.stats_timespan),
+        };
+        if !due {
+            return Ok(());
+        }
+        let stats = self.get_stats()?;
+        self.stats_snapshots.push_back(stats);
+        while self.stats_snapshots.len() > self.config.stats_history_length {
+            self.stats_snapshots.pop_front();
+        }
         Ok(())
     }
 
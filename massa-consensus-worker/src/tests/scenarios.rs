@@ -1,5 +1,6 @@
 use std::{
     collections::{HashSet, VecDeque},
+    sync::Arc,
     time::Duration,
 };
 
@@ -18,7 +19,7 @@ use massa_pool_exports::MockPoolController;
 use massa_pos_exports::{MockSelectorController, Selection};
 use massa_signature::KeyPair;
 use massa_storage::Storage;
-use massa_test_framework::TestUniverse;
+use massa_test_framework::{MockClock, TestStack, TestUniverse};
 use massa_time::MassaTime;
 use mockall::Sequence;
 
@@ -59,6 +60,134 @@ fn test_genesis_block_creation() {
     assert_eq!(genesis_hashes.len() as u8, thread_count);
 }
 
+#[test]
+fn test_stack_genesis_block_creation() {
+    let stakers = vec![KeyPair::generate(0).unwrap(), KeyPair::generate(0).unwrap()];
+    let thread_count = 2;
+    let cfg = ConsensusConfig {
+        t0: MassaTime::from_millis(1000),
+        thread_count,
+        genesis_timestamp: MassaTime::now().unwrap(),
+        force_keep_final_periods: 50,
+        force_keep_final_periods_without_ops: 128,
+        max_future_processing_blocks: 10,
+        genesis_key: stakers[0].clone(),
+        ..ConsensusConfig::default()
+    };
+    let stack = ConsensusTestUniverse::start_stack(&stakers, cfg);
+    let genesis_hashes = stack
+        .module_controller
+        .get_block_graph_status(None, None)
+        .expect("could not get block graph status")
+        .genesis_blocks;
+    assert_eq!(genesis_hashes.len() as u8, thread_count);
+}
+
+#[test]
+fn test_genesis_block_graph_dot() {
+    let stakers = vec![KeyPair::generate(0).unwrap(), KeyPair::generate(0).unwrap()];
+    let cfg = ConsensusConfig {
+        t0: MassaTime::from_millis(1000),
+        thread_count: 2,
+        genesis_timestamp: MassaTime::now().unwrap(),
+        force_keep_final_periods: 50,
+        force_keep_final_periods_without_ops: 128,
+        max_future_processing_blocks: 10,
+        genesis_key: stakers[0].clone(),
+        ..ConsensusConfig::default()
+    };
+    let stack = ConsensusTestUniverse::start_stack(&stakers, cfg);
+    let graph = stack
+        .module_controller
+        .get_block_graph_status(None, None)
+        .expect("could not get block graph status");
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph BlockGraph {"));
+    // genesis blocks are final and have no parents
+    for genesis_id in graph.genesis_blocks.iter() {
+        assert!(dot.contains(&format!("\"{}\" [label=", genesis_id)));
+        assert!(!dot.contains(&format!("\"{}\" ->", genesis_id)));
+    }
+    assert_eq!(dot.matches("fillcolor=palegreen").count(), 2);
+}
+
+/// Blocks in the future are processed only once the test advances the consensus clock to their slot.
+#[test]
+fn test_mock_clock_slot_progression() {
+    let staking_key: KeyPair = KeyPair::generate(0).unwrap();
+    // with the system clock, the slots of the test blocks would all be reached already
+    let genesis_timestamp = MassaTime::now()
+        .unwrap()
+        .saturating_sub(MassaTime::from_millis(3_600_000));
+    let clock = MockClock::new(genesis_timestamp);
+    let cfg = ConsensusConfig {
+        t0: MassaTime::from_millis(1000),
+        thread_count: 2,
+        genesis_timestamp,
+        force_keep_final_periods: 50,
+        force_keep_final_periods_without_ops: 128,
+        max_future_processing_blocks: 10,
+        genesis_key: staking_key.clone(),
+        clock: Arc::new(clock.clone()),
+        ..ConsensusConfig::default()
+    };
+    let universe = ConsensusTestUniverse::start_stack(&[staking_key.clone()], cfg);
+    let storage = Storage::create_root();
+    let genesis_hashes = universe
+        .module_controller
+        .get_block_graph_status(None, None)
+        .expect("could not get block graph status")
+        .genesis_blocks;
+
+    let t0s1 = create_block(Slot::new(1, 0), genesis_hashes.clone(), &staking_key);
+    let t1s1 = create_block(
+        Slot::new(1, 1),
+        vec![t0s1.id, genesis_hashes[1]],
+        &staking_key,
+    );
+    let t0s2 = create_block(Slot::new(2, 0), vec![t0s1.id, t1s1.id], &staking_key);
+    register_block(&universe.module_controller, t0s1.clone(), storage.clone());
+    register_block(&universe.module_controller, t1s1.clone(), storage.clone());
+    register_block(&universe.module_controller, t0s2.clone(), storage.clone());
+
+    // the clock does not move by itself
+    assert!(!universe.wait_for_slot(Slot::new(1, 0), Duration::from_millis(300)));
+    assert_eq!(
+        universe
+            .module_controller
+            .get_block_statuses(&[t0s1.id, t1s1.id, t0s2.id]),
+        vec![
+            BlockGraphStatus::WaitingForSlot,
+            BlockGraphStatus::WaitingForSlot,
+            BlockGraphStatus::WaitingForSlot
+        ],
+        "blocks processed before their slot"
+    );
+
+    // reaching the slot of t0s1 only processes t0s1
+    clock.advance(Duration::from_millis(1000));
+    assert!(universe.wait_for_slot(Slot::new(1, 0), Duration::from_secs(10)));
+    assert!(!universe.wait_for_slot(Slot::new(1, 1), Duration::from_millis(300)));
+    assert_eq!(
+        universe
+            .module_controller
+            .get_block_statuses(&[t1s1.id, t0s2.id]),
+        vec![
+            BlockGraphStatus::WaitingForSlot,
+            BlockGraphStatus::WaitingForSlot
+        ],
+        "blocks processed before their slot"
+    );
+
+    // jumping past several slots processes every block reached
+    clock.set(genesis_timestamp.saturating_add(MassaTime::from_millis(2000)));
+    assert!(universe.wait_for_slot(Slot::new(2, 0), Duration::from_secs(10)));
+    assert_eq!(
+        universe.module_controller.get_best_parents(),
+        vec![(t0s2.id, 2), (t1s1.id, 1)]
+    );
+}
+
 /// This test tests that the blocks are well processed by consensus even if they are not sent in a sorted way.
 #[test]
 fn test_unsorted_block() {
@@ -255,6 +384,18 @@ fn test_parallel_incompatibility() {
 
             assert_eq!(status.max_cliques.len(), 2);
 
+            // exactly one clique is the blockclique, and it has the highest fitness
+            let blockcliques: Vec<_> = consensus_controller
+                .get_cliques()
+                .into_iter()
+                .filter(|clique| clique.is_blockclique)
+                .collect();
+            assert_eq!(blockcliques.len(), 1, "wrong blockclique count");
+            assert!(status
+                .max_cliques
+                .iter()
+                .all(|clique| clique.fitness <= blockcliques[0].fitness));
+
             for clique in status.max_cliques.clone() {
                 if clique.block_ids.contains(&block_3.id) && clique.block_ids.contains(&block_4.id)
                 {
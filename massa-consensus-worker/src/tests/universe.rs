@@ -6,12 +6,17 @@ use massa_consensus_exports::{
 };
 use massa_execution_exports::MockExecutionController;
 use massa_metrics::MassaMetrics;
-use massa_models::config::THREAD_COUNT;
+use massa_models::{
+    address::Address,
+    config::{ENDORSEMENT_COUNT, THREAD_COUNT},
+    slot::Slot,
+};
 use massa_pool_exports::MockPoolController;
-use massa_pos_exports::MockSelectorController;
+use massa_pos_exports::{MockSelectorController, Selection};
 use massa_protocol_exports::MockProtocolController;
+use massa_signature::KeyPair;
 use massa_storage::Storage;
-use massa_test_framework::TestUniverse;
+use massa_test_framework::{TestStack, TestUniverse};
 
 use crate::start_consensus_worker;
 
@@ -93,4 +98,60 @@ impl TestUniverse for ConsensusTestUniverse {
         universe.initialize();
         universe
     }
+
+    /// Highest slot among the best parents of each thread.
+    fn current_slot(&self) -> Option<Slot> {
+        self.module_controller
+            .get_best_parents()
+            .iter()
+            .enumerate()
+            .map(|(thread, (_, period))| Slot::new(*period, thread as u8))
+            .max()
+    }
+}
+
+impl TestStack for ConsensusTestUniverse {
+    /// Real consensus with mocked execution and pool, the stakers being drawn in turn slot after slot.
+    fn stack_controllers(keypairs: &[KeyPair], config: &Self::Config) -> Self::ForeignControllers {
+        assert!(!keypairs.is_empty(), "at least one staker is needed");
+        let mut foreign_controllers = ConsensusForeignControllers::new_with_mocks();
+        foreign_controllers
+            .execution_controller
+            .expect_update_blockclique_status()
+            .returning(|_, _, _| {});
+        foreign_controllers
+            .pool_controller
+            .expect_notify_final_cs_periods()
+            .returning(|_| {});
+        foreign_controllers
+            .pool_controller
+            .expect_add_denunciation_precursor()
+            .returning(|_| {});
+
+        let stakers: Vec<Address> = keypairs
+            .iter()
+            .map(|keypair| Address::from_public_key(&keypair.get_public_key()))
+            .collect();
+        let thread_count = config.thread_count as u64;
+        let producer = move |slot: Slot| {
+            let index = slot.period * thread_count + slot.thread as u64;
+            stakers[(index % stakers.len() as u64) as usize]
+        };
+        let selection_producer = producer.clone();
+        foreign_controllers
+            .selector_controller
+            .expect_get_producer()
+            .returning(move |slot| Ok(producer(slot)));
+        foreign_controllers
+            .selector_controller
+            .expect_get_selection()
+            .returning(move |slot| {
+                let producer = selection_producer(slot);
+                Ok(Selection {
+                    producer,
+                    endorsements: vec![producer; ENDORSEMENT_COUNT as usize],
+                })
+            });
+        foreign_controllers
+    }
 }
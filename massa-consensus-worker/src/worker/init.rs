@@ -19,7 +19,6 @@ use massa_models::{
     timeslots::{get_block_slot_timestamp, get_latest_block_slot_at_timestamp},
 };
 use massa_storage::Storage;
-use massa_time::MassaTime;
 use parking_lot::RwLock;
 use std::{
     collections::{HashMap, VecDeque},
@@ -88,7 +87,7 @@ impl ConsensusWorker {
         init_graph: Option<BootstrapableGraph>,
         storage: Storage,
     ) -> Result<Self, ConsensusError> {
-        let now = MassaTime::now().expect("Couldn't init timer consensus");
+        let now = config.clock.now().expect("Couldn't init timer consensus");
         let previous_slot = get_latest_block_slot_at_timestamp(
             config.thread_count,
             config.t0,
@@ -129,13 +128,12 @@ impl ConsensusWorker {
         let next_slot = previous_slot.map_or(Ok(Slot::new(0u64, 0u8)), |s| {
             s.get_next_slot(config.thread_count)
         })?;
-        let next_instant = get_block_slot_timestamp(
+        let next_instant = config.clock.wake_up_instant(get_block_slot_timestamp(
             config.thread_count,
             config.t0,
             config.genesis_timestamp,
             next_slot,
-        )?
-        .estimate_instant()?;
+        )?)?;
 
         info!(
             "Started node at time {}, cycle {}, period {}, thread {}",
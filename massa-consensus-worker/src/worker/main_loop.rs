@@ -5,7 +5,6 @@ use massa_models::{
     slot::Slot,
     timeslots::{get_block_slot_timestamp, get_closest_slot_to_timestamp},
 };
-use massa_time::MassaTime;
 use tracing::log::{info, warn};
 
 use crate::commands::ConsensusCommand;
@@ -77,7 +76,7 @@ impl ConsensusWorker {
     /// Extra safety against double-production caused by clock adjustments (this is the role of the `previous_slot` parameter).
     fn get_next_slot(&self, previous_slot: Option<Slot>) -> (Slot, Instant) {
         // get current absolute time
-        let now = MassaTime::now().expect("could not get current time");
+        let now = self.config.clock.now().expect("could not get current time");
 
         // get closest slot according to the current absolute time
         let mut next_slot = get_closest_slot_to_timestamp(
@@ -97,17 +96,36 @@ impl ConsensusWorker {
         }
 
         // get the timestamp of the target slot
-        let next_instant = get_block_slot_timestamp(
+        let next_instant = self.slot_wake_up_instant(next_slot);
+
+        (next_slot, next_instant)
+    }
+
+    /// Instant at which to check again whether the clock has reached `slot`
+    fn slot_wake_up_instant(&self, slot: Slot) -> Instant {
+        let timestamp = get_block_slot_timestamp(
             self.config.thread_count,
             self.config.t0,
             self.config.genesis_timestamp,
-            next_slot,
+            slot,
         )
-        .expect("could not get block slot timestamp")
-        .estimate_instant()
-        .expect("could not estimate block slot instant");
+        .expect("could not get block slot timestamp");
+        self.config
+            .clock
+            .wake_up_instant(timestamp)
+            .expect("could not estimate block slot instant")
+    }
 
-        (next_slot, next_instant)
+    /// Whether the clock has reached the start of `slot`
+    fn is_slot_reached(&self, slot: Slot) -> bool {
+        let timestamp = get_block_slot_timestamp(
+            self.config.thread_count,
+            self.config.t0,
+            self.config.genesis_timestamp,
+            slot,
+        )
+        .expect("could not get block slot timestamp");
+        self.config.clock.now().expect("could not get current time") >= timestamp
     }
 
     /// Runs in loop forever. This loop must stop every slot to perform operations on stats and graph
@@ -118,6 +136,11 @@ impl ConsensusWorker {
             match self.wait_slot_or_command(self.next_instant) {
                 // When we reached the instant of the next slot
                 WaitingStatus::Ended => {
+                    // the wake up instant is an estimation: wait again until the clock reaches the slot
+                    if !self.is_slot_reached(self.next_slot) {
+                        self.next_instant = self.slot_wake_up_instant(self.next_slot);
+                        continue;
+                    }
                     if let Some(end) = self.config.end_timestamp {
                         // The testnet has ended. Will be removed for mainnet.
                         if self.next_instant > end.estimate_instant().unwrap() {
@@ -86,7 +86,9 @@ pub fn start_consensus_worker(
         gi_head: Default::default(),
         final_block_stats: Default::default(),
         stale_block_stats: Default::default(),
+        stats_snapshots: Default::default(),
         protocol_blocks: Default::default(),
+        block_arrival_times: Default::default(),
         wishlist: Default::default(),
         launch_time: MassaTime::now().unwrap(),
         stats_desync_detection_timespan,
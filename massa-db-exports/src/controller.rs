@@ -7,6 +7,13 @@ use std::{fmt::Debug, sync::Arc};
 
 pub type ShareableMassaDBController = Arc<RwLock<Box<dyn MassaDBController>>>;
 
+/// Observer of the writes to the state column family
+pub trait StateChangeObserver: Send + Sync {
+    /// Called once a key of the state column family is written to disk,
+    /// with its previous and new values (`None` if the key was absent or is deleted)
+    fn on_state_change(&self, key: &[u8], previous: Option<&[u8]>, new: Option<&[u8]>);
+}
+
 /// Controller trait for the MassaDB
 pub trait MassaDBController: Send + Sync + Debug {
     /// Creates a new hard copy of the DB, for the given slot
@@ -33,6 +40,9 @@ pub trait MassaDBController: Send + Sync + Debug {
     /// Reset the database, and attach it to the given slot.
     fn reset(&mut self, slot: Slot);
 
+    /// Register an observer notified of every write to the state column family, bootstrap writes included
+    fn add_state_change_observer(&mut self, observer: Arc<dyn StateChangeObserver>);
+
     /// Exposes RocksDB's "get_cf" function
     fn get_cf(&self, handle_cf: &str, key: Key) -> Result<Option<Value>, MassaDBError>;
 
@@ -1,8 +1,8 @@
 use massa_db_exports::{
     DBBatch, Key, MassaDBConfig, MassaDBController, MassaDBError, MassaDirection,
-    MassaIteratorMode, StreamBatch, Value, CF_ERROR, CHANGE_ID_DESER_ERROR, CHANGE_ID_KEY,
-    CHANGE_ID_SER_ERROR, CRUD_ERROR, METADATA_CF, OPEN_ERROR, STATE_CF, STATE_HASH_ERROR,
-    STATE_HASH_INITIAL_BYTES, STATE_HASH_KEY, VERSIONING_CF,
+    MassaIteratorMode, StateChangeObserver, StreamBatch, Value, CF_ERROR, CHANGE_ID_DESER_ERROR,
+    CHANGE_ID_KEY, CHANGE_ID_SER_ERROR, CRUD_ERROR, METADATA_CF, OPEN_ERROR, STATE_CF,
+    STATE_HASH_ERROR, STATE_HASH_INITIAL_BYTES, STATE_HASH_KEY, VERSIONING_CF,
 };
 use massa_hash::{HashXof, HASH_XOF_SIZE_BYTES};
 use massa_models::{
@@ -55,6 +55,8 @@ pub struct RawMassaDB<
     pub change_id_deserializer: ChangeIDDeserializer,
     /// The current RocksDB batch of the database, in a Mutex to share it
     pub current_batch: Arc<Mutex<WriteBatch>>,
+    /// Observers notified of the writes to the state column family
+    pub state_change_observers: Vec<Arc<dyn StateChangeObserver>>,
 }
 
 impl<ChangeID, ChangeIDSerializer, ChangeIDDeserializer> std::fmt::Debug
@@ -333,27 +335,29 @@ where
 
         *self.current_batch.lock() = WriteBatch::default();
 
+        // previous values of the written keys, kept for the state change observers
+        let mut prev_values = Vec::new();
+
         for (key, value) in changes.iter() {
+            // Compute the XOR in all cases
+            let prev_value = self.db.get_cf(handle_state, key).ok().flatten();
+            if let Some(prev_value) = &prev_value {
+                let prev_hash =
+                    HashXof::compute_from_tuple(&[key.as_slice(), prev_value.as_slice()]);
+                current_xor_hash ^= prev_hash;
+            }
+
             if let Some(value) = value {
                 self.current_batch.lock().put_cf(handle_state, key, value);
 
-                // Compute the XOR in all cases
-                if let Ok(Some(prev_value)) = self.db.get_cf(handle_state, key) {
-                    let prev_hash =
-                        HashXof::compute_from_tuple(&[key.as_slice(), prev_value.as_slice()]);
-                    current_xor_hash ^= prev_hash;
-                };
                 let new_hash = HashXof::compute_from_tuple(&[key.as_slice(), value.as_slice()]);
                 current_xor_hash ^= new_hash;
             } else {
                 self.current_batch.lock().delete_cf(handle_state, key);
+            }
 
-                // Compute the XOR in all cases
-                if let Ok(Some(prev_value)) = self.db.get_cf(handle_state, key) {
-                    let prev_hash =
-                        HashXof::compute_from_tuple(&[key.as_slice(), prev_value.as_slice()]);
-                    current_xor_hash ^= prev_hash;
-                };
+            if !self.state_change_observers.is_empty() {
+                prev_values.push(prev_value);
             }
         }
 
@@ -388,6 +392,14 @@ where
             })?;
         }
 
+        if !self.state_change_observers.is_empty() {
+            for ((key, value), prev_value) in changes.iter().zip(prev_values) {
+                for observer in &self.state_change_observers {
+                    observer.on_state_change(key, prev_value.as_deref(), value.as_deref());
+                }
+            }
+        }
+
         match self
             .change_history
             .entry(self.get_change_id().expect(CHANGE_ID_DESER_ERROR))
@@ -584,6 +596,7 @@ impl RawMassaDB<Slot, SlotSerializer, SlotDeserializer> {
             change_id_serializer: SlotSerializer::new(),
             change_id_deserializer,
             current_batch,
+            state_change_observers: Vec::new(),
         };
 
         if massa_db.get_change_id().is_err() {
@@ -695,6 +708,10 @@ impl MassaDBController for RawMassaDB<Slot, SlotSerializer, SlotDeserializer> {
         self.change_history.clear();
     }
 
+    fn add_state_change_observer(&mut self, observer: Arc<dyn StateChangeObserver>) {
+        self.state_change_observers.push(observer);
+    }
+
     fn get_cf(&self, handle_cf: &str, key: Key) -> Result<Option<Value>, MassaDBError> {
         let db = &self.db;
         let handle = db.cf_handle(handle_cf).expect(CF_ERROR);
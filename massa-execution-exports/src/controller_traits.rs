@@ -7,6 +7,7 @@ use crate::types::{
 };
 use crate::ExecutionError;
 use crate::{ExecutionAddressInfo, ReadOnlyExecutionOutput};
+use massa_ledger_exports::LedgerStats;
 use massa_models::address::Address;
 use massa_models::amount::Amount;
 use massa_models::block_id::BlockId;
@@ -18,6 +19,7 @@ use massa_models::prehash::PreHashMap;
 use massa_models::slot::Slot;
 use massa_models::stats::ExecutionStats;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 
 #[cfg_attr(feature = "test-exports", mockall::automock)]
@@ -47,6 +49,17 @@ pub trait ExecutionController: Send + Sync {
     /// * operation id
     fn get_filtered_sc_output_event(&self, filter: EventFilter) -> Vec<SCOutputEvent>;
 
+    /// Get a page of at most `limit` execution events matching `filter`,
+    /// among the ones that come after the `(slot, index_in_slot)` position `after`.
+    /// The next page starts after the position of the last returned event,
+    /// so that all the events can be read without holding them at once.
+    fn get_filtered_sc_output_event_page(
+        &self,
+        filter: EventFilter,
+        after: Option<(Slot, u64)>,
+        limit: usize,
+    ) -> Vec<SCOutputEvent>;
+
     /// Get the final and active values of balance.
     ///
     /// # Return value
@@ -104,9 +117,31 @@ pub trait ExecutionController: Send + Sync {
     /// Gets information about a batch of addresses
     fn get_addresses_infos(&self, addresses: &[Address]) -> Vec<ExecutionAddressInfo>;
 
+    /// Get the final and candidate datastore keys of each address merged together, in key order.
+    /// Returns `None` as soon as more than `max_keys` keys are listed in total, without listing the others.
+    fn get_bounded_datastore_keys(
+        &self,
+        addresses: &[Address],
+        max_keys: usize,
+    ) -> Option<Vec<BTreeSet<Vec<u8>>>>;
+
     /// Get execution statistics
     fn get_stats(&self) -> ExecutionStats;
 
+    /// Get up to `limit` addresses of the final ledger having a datastore key starting with `prefix`,
+    /// visiting at most `max_visited` addresses after `after`.
+    /// Also returns, unless the end of the ledger was reached, the last visited address from which to resume
+    fn find_addresses_with_key_prefix(
+        &self,
+        prefix: &[u8],
+        after: Option<Address>,
+        max_visited: usize,
+        limit: usize,
+    ) -> (Vec<Address>, Option<Address>);
+
+    /// Get the address count, datastore entry count and byte size of the final ledger
+    fn get_ledger_stats(&self) -> LedgerStats;
+
     /// Returns a boxed clone of self.
     /// Useful to allow cloning `Box<dyn ExecutionController>`.
     fn clone_box(&self) -> Box<dyn ExecutionController>;
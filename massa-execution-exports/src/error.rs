@@ -3,6 +3,8 @@
 //! this file defines all possible execution error categories
 
 use displaydoc::Display;
+use massa_models::address::Address;
+use massa_models::execution::ExecutionTraceStep;
 use massa_module_cache::error::CacheError;
 use massa_sc_runtime::VMError;
 use massa_versioning::versioning_factory::FactoryError;
@@ -62,11 +64,22 @@ pub enum ExecutionError {
         error: VMError,
     },
 
+    /// Target address {0} is not a smart contract: it has no bytecode
+    NoBytecode(Address),
+
     /// Cache error: {0}
     CacheError(#[from] CacheError),
 
     /// Factory error: {0}
     FactoryError(#[from] FactoryError),
+
+    /// {error}
+    TracedError {
+        /// error the read-only execution failed with
+        error: Box<ExecutionError>,
+        /// call trace recorded up to the failure
+        trace: Vec<ExecutionTraceStep>,
+    },
 }
 
 /// Execution query errors
@@ -74,4 +87,6 @@ pub enum ExecutionError {
 pub enum ExecutionQueryError {
     /// Not found: {0}
     NotFound(String),
+    /// Slot unavailable: {0}
+    SlotUnavailable(String),
 }
@@ -5,6 +5,7 @@
 
 use massa_models::execution::EventFilter;
 use massa_models::output_event::SCOutputEvent;
+use massa_models::slot::Slot;
 use std::collections::VecDeque;
 
 /// Store for events emitted by smart contracts
@@ -54,51 +55,76 @@ impl EventStore {
     /// * operation id
     /// * is final
     pub fn get_filtered_sc_output_events(&self, filter: &EventFilter) -> VecDeque<SCOutputEvent> {
+        self.0
+            .iter()
+            .filter(|x| event_matches_filter(x, filter))
+            .cloned()
+            .collect()
+    }
+
+    /// Get at most `limit` events matching `filter` (see `get_filtered_sc_output_events`),
+    /// among the ones that come after the `(slot, index_in_slot)` position `after`.
+    /// The next page starts after the position of the last returned event.
+    pub fn get_filtered_sc_output_events_page(
+        &self,
+        filter: &EventFilter,
+        after: Option<(Slot, u64)>,
+        limit: usize,
+    ) -> VecDeque<SCOutputEvent> {
         self.0
             .iter()
             .filter(|x| {
-                if let Some(start) = filter.start {
-                    if x.context.slot < start {
-                        return false;
-                    }
-                }
-                if let Some(end) = filter.end {
-                    if x.context.slot >= end {
-                        return false;
-                    }
-                }
-                if let Some(is_final) = filter.is_final {
-                    if x.context.is_final != is_final {
-                        return false;
-                    }
-                }
-                if let Some(is_error) = filter.is_error {
-                    if x.context.is_error != is_error {
-                        return false;
-                    }
-                }
-                match (filter.emitter_address, x.context.call_stack.front()) {
-                    (Some(addr1), Some(addr2)) if addr1 != *addr2 => return false,
-                    (Some(_), None) => return false,
-                    _ => (),
-                }
-                match (filter.original_caller_address, x.context.call_stack.back()) {
-                    (Some(addr1), Some(addr2)) if addr1 != *addr2 => return false,
-                    (Some(_), None) => return false,
-                    _ => (),
-                }
-                match (filter.original_operation_id, x.context.origin_operation_id) {
-                    (Some(addr1), Some(addr2)) if addr1 != addr2 => return false,
-                    (Some(_), None) => return false,
-                    _ => (),
-                }
-                true
+                after.map_or(true, |position| {
+                    (x.context.slot, x.context.index_in_slot) > position
+                })
             })
+            .filter(|x| event_matches_filter(x, filter))
+            .take(limit)
             .cloned()
             .collect()
     }
 }
 
+/// Check whether an event matches all the criteria of a filter
+fn event_matches_filter(x: &SCOutputEvent, filter: &EventFilter) -> bool {
+    if let Some(start) = filter.start {
+        if x.context.slot < start {
+            return false;
+        }
+    }
+    if let Some(end) = filter.end {
+        if x.context.slot >= end {
+            return false;
+        }
+    }
+    if let Some(is_final) = filter.is_final {
+        if x.context.is_final != is_final {
+            return false;
+        }
+    }
+    if let Some(is_error) = filter.is_error {
+        if x.context.is_error != is_error {
+            return false;
+        }
+    }
+    match (filter.emitter_address, x.context.call_stack.front()) {
+        (Some(addr1), Some(addr2)) if addr1 != *addr2 => return false,
+        (Some(_), None) => return false,
+        _ => (),
+    }
+    match (filter.original_caller_address, x.context.call_stack.back()) {
+        (Some(addr1), Some(addr2)) if addr1 != *addr2 => return false,
+        (Some(_), None) => return false,
+        _ => (),
+    }
+    match (filter.original_operation_id, x.context.origin_operation_id) {
+        (Some(addr1), Some(addr2)) if addr1 != addr2 => return false,
+        (Some(_), None) => return false,
+        _ => (),
+    }
+    true
+}
+
 #[test]
 fn test_prune() {
     use massa_models::output_event::{EventExecutionContext, SCOutputEvent};
@@ -127,3 +153,50 @@ fn test_prune() {
     assert_eq!(store.0[1].data, "8");
     assert_eq!(store.0[0].data, "7");
 }
+
+#[test]
+fn test_filtered_events_page() {
+    use massa_models::output_event::{EventExecutionContext, SCOutputEvent};
+
+    let mut store = EventStore(VecDeque::new());
+    for i in 0..10 {
+        store.push(SCOutputEvent {
+            context: EventExecutionContext {
+                slot: Slot::new(i / 2, 0),
+                block: None,
+                read_only: false,
+                index_in_slot: i % 2,
+                call_stack: VecDeque::new(),
+                origin_operation_id: None,
+                is_final: false,
+                is_error: i == 4,
+            },
+            data: i.to_string(),
+        });
+    }
+    let filter = EventFilter {
+        is_error: Some(false),
+        ..Default::default()
+    };
+
+    // walk the pages, each one starting after the last event of the previous one
+    let mut after = None;
+    let mut pages = Vec::new();
+    loop {
+        let page = store.get_filtered_sc_output_events_page(&filter, after, 4);
+        let last = match page.back() {
+            Some(last) => last,
+            None => break,
+        };
+        after = Some((last.context.slot, last.context.index_in_slot));
+        pages.push(page.into_iter().map(|e| e.data).collect::<Vec<_>>());
+    }
+    assert_eq!(
+        pages,
+        vec![
+            vec!["0", "1", "2", "3"],
+            vec!["5", "6", "7", "8"],
+            vec!["9"]
+        ]
+    );
+}
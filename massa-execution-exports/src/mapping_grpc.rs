@@ -349,6 +349,11 @@ impl From<ExecutionQueryError> for grpc_model::Error {
                 code: 404,
                 message: error,
             },
+            ExecutionQueryError::SlotUnavailable(error) => grpc_model::Error {
+                //TODO to be defined
+                code: 410,
+                message: error,
+            },
         }
     }
 }
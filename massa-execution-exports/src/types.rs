@@ -10,12 +10,13 @@ use massa_models::block_id::BlockId;
 use massa_models::bytecode::Bytecode;
 use massa_models::datastore::Datastore;
 use massa_models::denunciation::DenunciationIndex;
-use massa_models::execution::EventFilter;
+use massa_models::execution::{EventFilter, ExecutionTraceStep};
 use massa_models::operation::OperationId;
 use massa_models::output_event::SCOutputEvent;
 use massa_models::prehash::PreHashSet;
 use massa_models::{
-    address::Address, address::ExecutionAddressCycleInfo, amount::Amount, slot::Slot,
+    address::Address, address::ExecutionAddressCycleInfo, address::ScheduledRollCount,
+    amount::Amount, slot::Slot,
 };
 use massa_pos_exports::ProductionStats;
 use massa_storage::Storage;
@@ -58,6 +59,14 @@ pub enum ExecutionQueryRequestItem {
     AddressBalanceCandidate(Address),
     /// gets the balance (final) of an address, returns ExecutionQueryResponseItem::Amount(balance) or an error if the address is not found
     AddressBalanceFinal(Address),
+    /// gets the balance of an address as of the end of a given slot, returns ExecutionQueryResponseItem::Amount(balance),
+    /// or an error if the address is not found or if the state at that slot is not retained
+    AddressBalanceAtSlot {
+        /// Address for which to query the balance
+        addr: Address,
+        /// Slot after which the balance is read. Must lie between the final and the candidate cursors.
+        slot: Slot,
+    },
     /// gets the bytecode (candidate) of an address, returns ExecutionQueryResponseItem::Bytecode(bytecode) or an error if the address is not found
     AddressBytecodeCandidate(Address),
     /// gets the bytecode (final) of an address, returns ExecutionQueryResponseItem::Bytecode(bytecode) or an error if the address is not found
@@ -196,11 +205,17 @@ pub struct ExecutionAddressInfo {
     /// candidate datastore keys of the address
     pub candidate_datastore_keys: BTreeSet<Vec<u8>>,
 
+    /// whether the address has a non-empty bytecode, final or candidate
+    pub has_bytecode: bool,
+
     /// future deferred credits
     pub future_deferred_credits: BTreeMap<Slot, Amount>,
 
     /// cycle information
     pub cycle_infos: Vec<ExecutionAddressCycleInfo>,
+
+    /// active roll counts scheduled for the upcoming cycles
+    pub roll_schedule: Vec<ScheduledRollCount>,
 }
 
 /// structure describing the output of the execution of a slot
@@ -246,6 +261,8 @@ pub struct ReadOnlyExecutionOutput {
     pub gas_cost: u64,
     /// Returned value from the module call
     pub call_result: Vec<u8>,
+    /// Call trace of the execution, if requested
+    pub trace: Option<Vec<ExecutionTraceStep>>,
 }
 
 /// structure describing different types of read-only execution request
@@ -265,8 +282,19 @@ pub struct ReadOnlyExecutionRequest {
     ///
     /// Whether to start execution from final or active state
     pub is_final: bool,
+    /// Label of the request, attached to the tracing span of the execution
+    pub label: Option<String>,
+    /// Whether to record the call trace of the execution (calls, returns and coin transfers)
+    pub trace: bool,
+    /// Channel on which the events are sent as soon as they are emitted.
+    /// Streamed events are not buffered in the execution output.
+    /// The execution fails if an event does not fit in the channel or if the channel is closed.
+    pub event_sender: Option<ReadOnlyEventSender>,
 }
 
+/// Bounded sender of the events emitted by a streamed read-only execution
+pub type ReadOnlyEventSender = tokio::sync::mpsc::Sender<SCOutputEvent>;
+
 /// structure describing different possible targets of a read-only execution request
 #[derive(Debug, Clone)]
 pub enum ReadOnlyExecutionTarget {
@@ -130,6 +130,28 @@ impl ActiveHistory {
         HistorySearchResult::NoInfo
     }
 
+    /// Lazily query (from `slot` to beginning) the balance of an address as of the end of `slot`.
+    ///
+    /// Returns a `HistorySearchResult`.
+    pub fn fetch_balance_at_slot(
+        &self,
+        addr: &Address,
+        slot: &Slot,
+    ) -> HistorySearchResult<Amount> {
+        for output in self.0.iter().rev().skip_while(|output| output.slot > *slot) {
+            match output.state_changes.ledger_changes.0.get(addr) {
+                Some(SetUpdateOrDelete::Set(v)) => return HistorySearchResult::Present(v.balance),
+                Some(SetUpdateOrDelete::Update(LedgerEntryUpdate {
+                    balance: SetOrKeep::Set(v),
+                    ..
+                })) => return HistorySearchResult::Present(*v),
+                Some(SetUpdateOrDelete::Delete) => return HistorySearchResult::Absent,
+                _ => (),
+            }
+        }
+        HistorySearchResult::NoInfo
+    }
+
     /// Lazily query (from end to beginning) the active bytecode of an address after a given index.
     ///
     /// Returns a `HistorySearchResult`.
@@ -18,7 +18,7 @@ use massa_async_pool::{AsyncMessageId, AsyncMessageInfo};
 use massa_executed_ops::{ExecutedDenunciationsChanges, ExecutedOpsChanges};
 use massa_execution_exports::{
     EventStore, ExecutedBlockInfo, ExecutionConfig, ExecutionError, ExecutionOutput,
-    ExecutionStackElement,
+    ExecutionStackElement, ReadOnlyEventSender,
 };
 use massa_final_state::{FinalState, StateChanges};
 use massa_hash::Hash;
@@ -32,6 +32,7 @@ use massa_models::{
     address::Address,
     amount::Amount,
     block_id::BlockId,
+    execution::ExecutionTraceStep,
     operation::OperationId,
     output_event::{EventExecutionContext, SCOutputEvent},
     slot::Slot,
@@ -174,6 +175,15 @@ pub struct ExecutionContext {
 
     /// Address factory
     pub address_factory: AddressFactory,
+
+    /// call trace being recorded, only set for read-only executions that request it
+    pub trace: Option<Vec<ExecutionTraceStep>>,
+
+    /// where emitted events are streamed instead of being stored, only set for streamed read-only executions
+    pub event_sender: Option<ReadOnlyEventSender>,
+
+    /// why an event could not be streamed, see `check_event_stream`
+    event_stream_error: Option<String>,
 }
 
 impl ExecutionContext {
@@ -237,6 +247,9 @@ impl ExecutionContext {
             config,
             address_factory: AddressFactory { mip_store },
             execution_trail_hash,
+            trace: Default::default(),
+            event_sender: Default::default(),
+            event_stream_error: Default::default(),
         }
     }
 
@@ -687,7 +700,22 @@ impl ExecutionContext {
 
         // do the transfer
         self.speculative_ledger
-            .transfer_coins(from_addr, to_addr, amount)
+            .transfer_coins(from_addr, to_addr, amount)?;
+
+        self.push_trace_step(ExecutionTraceStep::Transfer {
+            depth: self.stack.len(),
+            from: from_addr,
+            to: to_addr,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Appends a step to the call trace, if one is being recorded
+    pub fn push_trace_step(&mut self, step: ExecutionTraceStep) {
+        if let Some(trace) = self.trace.as_mut() {
+            trace.push(step);
+        }
     }
 
     /// Add a new asynchronous message to speculative pool
@@ -972,10 +1000,28 @@ impl ExecutionContext {
         // Increment the event counter fot this slot
         self.created_event_index += 1;
 
+        // Stream the event if requested. The execution does not wait for a slow listener:
+        // a full or closed stream makes it fail, see `check_event_stream`
+        if let Some(sender) = &self.event_sender {
+            if let Err(err) = sender.try_send(event) {
+                self.event_stream_error = Some(format!("could not stream an event: {}", err));
+            }
+            return;
+        }
+
         // Add the event to the context store
         self.events.push(event);
     }
 
+    /// Fails if an emitted event could not be streamed,
+    /// because the listener does not keep up with the events or has left
+    pub fn check_event_stream(&self) -> Result<(), ExecutionError> {
+        match &self.event_stream_error {
+            Some(err) => Err(ExecutionError::ChannelError(err.clone())),
+            None => Ok(()),
+        }
+    }
+
     /// Check if an operation was previously executed (to prevent reuse)
     pub fn is_op_executed(&self, op_id: &OperationId) -> bool {
         self.speculative_executed_ops.is_op_executed(op_id)
@@ -12,6 +12,7 @@ use massa_execution_exports::{
     ExecutionQueryRequest, ExecutionQueryRequestItem, ExecutionQueryResponse,
     ExecutionQueryResponseItem, ReadOnlyExecutionOutput, ReadOnlyExecutionRequest,
 };
+use massa_ledger_exports::LedgerStats;
 use massa_models::denunciation::DenunciationIndex;
 use massa_models::execution::EventFilter;
 use massa_models::output_event::SCOutputEvent;
@@ -20,7 +21,7 @@ use massa_models::stats::ExecutionStats;
 use massa_models::{address::Address, amount::Amount, operation::OperationId};
 use massa_models::{block_id::BlockId, slot::Slot};
 use parking_lot::{Condvar, Mutex, RwLock};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::Display;
 use std::sync::Arc;
 use tracing::info;
@@ -172,6 +173,24 @@ impl ExecutionController for ExecutionControllerImpl {
                         None => Err(ExecutionQueryError::NotFound(format!("Account {}", addr))),
                     }
                 }
+                ExecutionQueryRequestItem::AddressBalanceAtSlot { addr, slot } => {
+                    if slot < execution_lock.final_cursor {
+                        Err(ExecutionQueryError::SlotUnavailable(format!(
+                            "state at slot {} has been pruned, the oldest retained slot is {}",
+                            slot, execution_lock.final_cursor
+                        )))
+                    } else if slot > execution_lock.active_cursor {
+                        Err(ExecutionQueryError::SlotUnavailable(format!(
+                            "slot {} is not executed yet, the latest executed slot is {}",
+                            slot, execution_lock.active_cursor
+                        )))
+                    } else {
+                        match execution_lock.get_balance_at_slot(&addr, &slot) {
+                            Some(balance) => Ok(ExecutionQueryResponseItem::Amount(balance)),
+                            None => Err(ExecutionQueryError::NotFound(format!("Account {}", addr))),
+                        }
+                    }
+                }
                 ExecutionQueryRequestItem::AddressBytecodeCandidate(addr) => {
                     let (_final_v, speculative_v) =
                         execution_lock.get_final_and_active_bytecode(&addr);
@@ -342,6 +361,19 @@ impl ExecutionController for ExecutionControllerImpl {
             .get_filtered_sc_output_event(filter)
     }
 
+    /// Get a page of at most `limit` generated execution events matching `filter`,
+    /// among the ones that come after the `(slot, index_in_slot)` position `after`
+    fn get_filtered_sc_output_event_page(
+        &self,
+        filter: EventFilter,
+        after: Option<(Slot, u64)>,
+        limit: usize,
+    ) -> Vec<SCOutputEvent> {
+        self.execution_state
+            .read()
+            .get_filtered_sc_output_event_page(filter, after, limit)
+    }
+
     /// Get the final and candidate values of balance.
     ///
     /// # Return value
@@ -441,25 +473,75 @@ impl ExecutionController for ExecutionControllerImpl {
                 exec_state.get_final_and_candidate_balance(addr);
             let (final_roll_count, candidate_roll_count) =
                 exec_state.get_final_and_candidate_rolls(addr);
+            let (final_bytecode, candidate_bytecode) =
+                exec_state.get_final_and_active_bytecode(addr);
             res.push(ExecutionAddressInfo {
                 final_datastore_keys: final_datastore_keys.unwrap_or_default(),
                 candidate_datastore_keys: candidate_datastore_keys.unwrap_or_default(),
+                has_bytecode: [final_bytecode, candidate_bytecode]
+                    .iter()
+                    .flatten()
+                    .any(|bytecode| !bytecode.0.is_empty()),
                 final_balance: final_balance.unwrap_or_default(),
                 candidate_balance: candidate_balance.unwrap_or_default(),
                 final_roll_count,
                 candidate_roll_count,
                 future_deferred_credits: exec_state.get_address_future_deferred_credits(addr),
                 cycle_infos: exec_state.get_address_cycle_infos(addr),
+                roll_schedule: exec_state.get_address_roll_schedule(addr),
             });
         }
         res
     }
 
+    /// Get the final and candidate datastore keys of each address merged together, in key order.
+    /// Returns `None` as soon as more than `max_keys` keys are listed in total, without listing the others.
+    fn get_bounded_datastore_keys(
+        &self,
+        addresses: &[Address],
+        max_keys: usize,
+    ) -> Option<Vec<BTreeSet<Vec<u8>>>> {
+        let exec_state = self.execution_state.read();
+        let mut remaining_keys = max_keys;
+        let mut res = Vec::with_capacity(addresses.len());
+        for addr in addresses {
+            let keys = exec_state.get_bounded_datastore_keys(addr, remaining_keys)?;
+            remaining_keys -= keys.len();
+            res.push(keys);
+        }
+        Some(res)
+    }
+
     /// Get execution statistics
     fn get_stats(&self) -> ExecutionStats {
         self.execution_state.read().get_stats()
     }
 
+    /// Get up to `limit` addresses of the final ledger having a datastore key starting with `prefix`,
+    /// visiting at most `max_visited` addresses after `after`
+    fn find_addresses_with_key_prefix(
+        &self,
+        prefix: &[u8],
+        after: Option<Address>,
+        max_visited: usize,
+        limit: usize,
+    ) -> (Vec<Address>, Option<Address>) {
+        // the execution lock is released before the scan, which only blocks final state writes
+        let final_state = self.execution_state.read().get_final_state();
+        let final_state = final_state.read();
+        final_state.ledger.find_addresses_with_key_prefix(
+            prefix,
+            after.as_ref(),
+            max_visited,
+            limit,
+        )
+    }
+
+    /// Get the size counters of the final ledger
+    fn get_ledger_stats(&self) -> LedgerStats {
+        self.execution_state.read().get_ledger_stats()
+    }
+
     /// Returns a boxed clone of self.
     /// Allows cloning `Box<dyn ExecutionController>`,
     /// see `massa-execution-exports/controller_traits.rs`
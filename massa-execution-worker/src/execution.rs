@@ -20,13 +20,13 @@ use massa_execution_exports::{
     ReadOnlyExecutionTarget, SlotExecutionOutput,
 };
 use massa_final_state::FinalState;
-use massa_ledger_exports::{SetOrDelete, SetUpdateOrDelete};
+use massa_ledger_exports::{LedgerStats, SetOrDelete, SetUpdateOrDelete};
 use massa_metrics::MassaMetrics;
-use massa_models::address::ExecutionAddressCycleInfo;
+use massa_models::address::{ExecutionAddressCycleInfo, ScheduledRollCount};
 use massa_models::bytecode::Bytecode;
 use massa_models::datastore::get_prefix_bounds;
 use massa_models::denunciation::{Denunciation, DenunciationIndex};
-use massa_models::execution::EventFilter;
+use massa_models::execution::{EventFilter, ExecutionTraceStep};
 use massa_models::output_event::SCOutputEvent;
 use massa_models::prehash::PreHashSet;
 use massa_models::stats::ExecutionStats;
@@ -186,6 +186,16 @@ impl ExecutionState {
             .get_stats(self.active_cursor, self.final_cursor)
     }
 
+    /// Get a handle on the final state, to read it without holding the execution state lock
+    pub fn get_final_state(&self) -> Arc<RwLock<FinalState>> {
+        self.final_state.clone()
+    }
+
+    /// Get the size counters of the final ledger
+    pub fn get_ledger_stats(&self) -> LedgerStats {
+        self.final_state.read().ledger.get_ledger_stats()
+    }
+
     /// Applies the output of an execution to the final execution state.
     /// The newly applied final output should be from the slot just after the last executed final slot
     ///
@@ -1378,10 +1388,29 @@ impl ExecutionState {
     /// * `req`: a read-only execution request
     ///
     /// # Returns
-    ///  `ExecutionOutput` describing the output of the execution, or an error
+    ///  `ExecutionOutput` describing the output of the execution, or an error.
+    ///  If a call trace was requested, a failed execution returns the trace recorded up to the failure
+    ///  in an `ExecutionError::TracedError`
     pub(crate) fn execute_readonly_request(
         &self,
         req: ReadOnlyExecutionRequest,
+    ) -> Result<ReadOnlyExecutionOutput, ExecutionError> {
+        self.run_readonly_request(req).map_err(|error| {
+            // the trace is only set if the failed execution recorded one
+            match context_guard!(self).trace.take() {
+                Some(trace) => ExecutionError::TracedError {
+                    error: Box::new(error),
+                    trace,
+                },
+                None => error,
+            }
+        })
+    }
+
+    /// Runs a read-only execution request, see `execute_readonly_request`
+    fn run_readonly_request(
+        &self,
+        req: ReadOnlyExecutionRequest,
     ) -> Result<ReadOnlyExecutionOutput, ExecutionError> {
         // TODO ensure that speculative things are reset after every execution ends (incl. on error and readonly)
         // otherwise, on prod stats accumulation etc... from the API we might be counting the remainder of this speculative execution
@@ -1406,7 +1435,7 @@ impl ExecutionState {
         };
 
         // create a readonly execution context
-        let execution_context = ExecutionContext::readonly(
+        let mut execution_context = ExecutionContext::readonly(
             self.config.clone(),
             slot,
             req.max_gas,
@@ -1416,6 +1445,10 @@ impl ExecutionState {
             self.module_cache.clone(),
             self.mip_store.clone(),
         );
+        if req.trace {
+            execution_context.trace = Some(Vec::new());
+        }
+        execution_context.event_sender = req.event_sender;
 
         // run the interpreter according to the target type
         let exec_response = match req.target {
@@ -1454,11 +1487,14 @@ impl ExecutionState {
                 target_func,
                 parameter,
             } => {
-                // get the bytecode, default to an empty vector
+                // get the bytecode, calling a function of an address without bytecode cannot succeed
                 let bytecode = execution_context
                     .get_bytecode(&target_addr)
                     .unwrap_or_default()
                     .0;
+                if bytecode.is_empty() {
+                    return Err(ExecutionError::NoBytecode(target_addr));
+                }
 
                 {
                     let mut context = context_guard!(self);
@@ -1477,6 +1513,16 @@ impl ExecutionState {
                     {
                         context.transfer_coins(Some(*from), Some(*to), coins, false)?;
                     }
+
+                    if let Some(caller) = call_stack_addr.get(0) {
+                        context.push_trace_step(ExecutionTraceStep::Call {
+                            depth: call_stack_addr.len(),
+                            caller: *caller,
+                            target: target_addr,
+                            function: Some(target_func.clone()),
+                            coins: req.coins.unwrap_or_default(),
+                        });
+                    }
                 }
 
                 // load and execute the compiled module
@@ -1502,19 +1548,33 @@ impl ExecutionState {
                     }
                     _ => (),
                 }
-                response.map_err(|error| ExecutionError::VMError {
+                let response = response.map_err(|error| ExecutionError::VMError {
                     context: "ReadOnlyExecutionTarget::FunctionCall".to_string(),
                     error,
-                })?
+                })?;
+                {
+                    let mut context = context_guard!(self);
+                    let depth = context.stack.len();
+                    context.push_trace_step(ExecutionTraceStep::Return {
+                        depth,
+                        address: target_addr,
+                    });
+                }
+                response
             }
         };
 
         // return the execution output
-        let execution_output = context_guard!(self).settle_slot(None);
+        let mut context = context_guard!(self);
+        let trace = context.trace.take();
+        // close the event stream
+        context.event_sender = None;
+        let execution_output = context.settle_slot(None);
         Ok(ReadOnlyExecutionOutput {
             out: execution_output,
             gas_cost: req.max_gas.saturating_sub(exec_response.remaining_gas),
             call_result: exec_response.ret,
+            trace,
         })
     }
 
@@ -1535,6 +1595,21 @@ impl ExecutionState {
         )
     }
 
+    /// Gets the balance of an address as of the end of `slot`.
+    /// The slot must lie between the final and the active cursors: older states are not retained.
+    pub fn get_balance_at_slot(&self, address: &Address, slot: &Slot) -> Option<Amount> {
+        let final_balance = self.final_state.read().ledger.get_balance(address);
+        match self
+            .active_history
+            .read()
+            .fetch_balance_at_slot(address, slot)
+        {
+            HistorySearchResult::Present(active_balance) => Some(active_balance),
+            HistorySearchResult::NoInfo => final_balance,
+            HistorySearchResult::Absent => None,
+        }
+    }
+
     /// Gets a balance both at the latest final and candidate executed slots
     pub fn get_final_and_active_bytecode(
         &self,
@@ -1595,9 +1670,48 @@ impl ExecutionState {
             .final_state
             .read()
             .ledger
-            .get_datastore_keys(addr, prefix);
+            .get_datastore_keys(addr, prefix, None);
 
-        let mut candidate_keys = final_keys.clone();
+        let candidate_keys =
+            self.apply_active_history_to_datastore_keys(addr, prefix, final_keys.clone());
+
+        (final_keys, candidate_keys)
+    }
+
+    /// Get the final and candidate datastore keys of the given address merged together,
+    /// or `None` if there are more than `max_keys` of them.
+    /// At most `max_keys + 1` final keys are read from the final ledger.
+    pub fn get_bounded_datastore_keys(
+        &self,
+        addr: &Address,
+        max_keys: usize,
+    ) -> Option<BTreeSet<Vec<u8>>> {
+        let final_keys = self.final_state.read().ledger.get_datastore_keys(
+            addr,
+            &[],
+            Some(max_keys.saturating_add(1)),
+        );
+        if final_keys.as_ref().map_or(0, |keys| keys.len()) > max_keys {
+            return None;
+        }
+
+        // the final keys are complete here, so the candidate keys are exact
+        let candidate_keys =
+            self.apply_active_history_to_datastore_keys(addr, &[], final_keys.clone());
+        let mut keys = final_keys.unwrap_or_default();
+        keys.extend(candidate_keys.unwrap_or_default());
+        (keys.len() <= max_keys).then_some(keys)
+    }
+
+    /// Apply the datastore changes of the active history to the final keys of the given address
+    /// that start with `prefix`, to get its candidate keys.
+    fn apply_active_history_to_datastore_keys(
+        &self,
+        addr: &Address,
+        prefix: &[u8],
+        final_keys: Option<BTreeSet<Vec<u8>>>,
+    ) -> Option<BTreeSet<Vec<u8>>> {
+        let mut candidate_keys = final_keys;
 
         // compute prefix range
         let prefix_range = get_prefix_bounds(prefix);
@@ -1640,13 +1754,45 @@ impl ExecutionState {
             }
         }
 
-        (final_keys, candidate_keys)
+        candidate_keys
     }
 
     pub fn get_address_cycle_infos(&self, address: &Address) -> Vec<ExecutionAddressCycleInfo> {
         context_guard!(self).get_address_cycle_infos(address, self.config.periods_per_cycle)
     }
 
+    /// Get the active roll counts of an address for the next 3 cycles,
+    /// whose lookback roll counts are already known.
+    /// Counts taken from a cycle that is not final yet use the candidate roll count.
+    pub fn get_address_roll_schedule(&self, address: &Address) -> Vec<ScheduledRollCount> {
+        let periods_per_cycle = self.config.periods_per_cycle;
+        let active_cycle = self.active_cursor.get_cycle(periods_per_cycle);
+        let final_cycle = self.final_cursor.get_cycle(periods_per_cycle);
+        let (_, candidate_rolls) = self.get_final_and_candidate_rolls(address);
+        let final_state = self.final_state.read();
+        (active_cycle.saturating_add(1)..=active_cycle.saturating_add(3))
+            .map(|cycle| {
+                // the lookback cycle is final once the final cursor went past it
+                let is_final = cycle
+                    .checked_sub(3)
+                    .map_or(true, |lookback_cycle| lookback_cycle < final_cycle);
+                let active_rolls = if is_final {
+                    final_state
+                        .pos_state
+                        .get_address_active_rolls(address, cycle)
+                        .unwrap_or_default()
+                } else {
+                    candidate_rolls
+                };
+                ScheduledRollCount {
+                    cycle,
+                    active_rolls,
+                    is_final,
+                }
+            })
+            .collect()
+    }
+
     /// Returns for a given cycle the stakers taken into account
     /// by the selector. That correspond to the `roll_counts` in `cycle - 3`.
     ///
@@ -1694,6 +1840,37 @@ impl ExecutionState {
         }
     }
 
+    /// Gets a page of at most `limit` execution events matching `filter`, among the ones that
+    /// come after the `(slot, index_in_slot)` position `after`: final events first, then candidate ones.
+    /// See `EventStore::get_filtered_sc_output_events_page`.
+    pub fn get_filtered_sc_output_event_page(
+        &self,
+        filter: EventFilter,
+        after: Option<(Slot, u64)>,
+        limit: usize,
+    ) -> Vec<SCOutputEvent> {
+        let mut events = Vec::new();
+        if filter.is_final != Some(false) {
+            events.extend(
+                self.final_events
+                    .get_filtered_sc_output_events_page(&filter, after, limit),
+            );
+        }
+        if filter.is_final != Some(true) {
+            for item in self.active_history.read().0.iter() {
+                if events.len() >= limit {
+                    break;
+                }
+                events.extend(item.events.get_filtered_sc_output_events_page(
+                    &filter,
+                    after,
+                    limit - events.len(),
+                ));
+            }
+        }
+        events
+    }
+
     /// Check if a denunciation has been executed given a `DenunciationIndex`
     /// Returns a tuple of booleans:
     /// * first boolean is true if the denunciation has been executed speculatively
@@ -13,6 +13,7 @@ use massa_execution_exports::ExecutionStackElement;
 use massa_models::bytecode::Bytecode;
 use massa_models::config::MAX_DATASTORE_KEY_LENGTH;
 use massa_models::datastore::get_prefix_bounds;
+use massa_models::execution::ExecutionTraceStep;
 use massa_models::{
     address::{Address, SCAddress, UserAddress},
     amount::Amount,
@@ -257,6 +258,14 @@ impl Interface for InterfaceImpl {
             owned_addresses: vec![to_address],
             operation_datastore: None,
         });
+        let depth = context.stack.len();
+        context.push_trace_step(ExecutionTraceStep::Call {
+            depth,
+            caller: from_address,
+            target: to_address,
+            function: None,
+            coins,
+        });
 
         // return the target bytecode
         Ok(bytecode.0)
@@ -267,9 +276,14 @@ impl Interface for InterfaceImpl {
     fn finish_call(&self) -> Result<()> {
         let mut context = context_guard!(self);
 
-        if context.stack.pop().is_none() {
+        let depth = context.stack.len();
+        let Some(element) = context.stack.pop() else {
             bail!("call stack out of bounds")
-        }
+        };
+        context.push_trace_step(ExecutionTraceStep::Return {
+            depth,
+            address: element.address,
+        });
 
         Ok(())
     }
@@ -1050,6 +1064,7 @@ impl Interface for InterfaceImpl {
         let mut context = context_guard!(self);
         let event = context.event_create(data, false);
         context.event_emit(event);
+        context.check_event_stream()?;
         Ok(())
     }
 
@@ -1066,6 +1081,7 @@ impl Interface for InterfaceImpl {
         let mut context = context_guard!(self);
         let event = context.event_create(data_str, false);
         context.event_emit(event);
+        context.check_event_stream()?;
 
         Ok(())
     }
@@ -1341,6 +1357,14 @@ impl Interface for InterfaceImpl {
             owned_addresses: vec![to_address],
             operation_datastore: None,
         });
+        let depth = context.stack.len();
+        context.push_trace_step(ExecutionTraceStep::Call {
+            depth,
+            caller: from_address,
+            target: to_address,
+            function: None,
+            coins,
+        });
 
         // return the target bytecode
         Ok(bytecode.0)
@@ -371,7 +371,7 @@ impl SpeculativeLedger {
             .final_state
             .read()
             .ledger
-            .get_datastore_keys(addr, prefix);
+            .get_datastore_keys(addr, prefix, None);
 
         // here, traverse the history from oldest to newest with added_changes at the end, applying additions and deletions
         let active_history = self.active_history.read();
@@ -9,7 +9,7 @@ mod tests {
     use massa_db_exports::DBBatch;
     use massa_execution_exports::{
         ExecutionBlockMetadata, ExecutionChannels, ExecutionConfig, ExecutionController,
-        ExecutionError, ReadOnlyExecutionRequest, ReadOnlyExecutionTarget,
+        ExecutionError, ExecutionStackElement, ReadOnlyExecutionRequest, ReadOnlyExecutionTarget,
     };
     use massa_hash::Hash;
     use massa_metrics::MassaMetrics;
@@ -27,7 +27,7 @@ mod tests {
         block_id::BlockId,
         datastore::Datastore,
         denunciation::Denunciation,
-        execution::EventFilter,
+        execution::{EventFilter, ExecutionTraceStep},
         operation::{Operation, OperationSerializer, OperationType, SecureShareOperation},
         secure_share::SecureShareContent,
     };
@@ -182,6 +182,9 @@ mod tests {
                 is_final: true,
                 coins: None,
                 fee: None,
+                label: None,
+                trace: false,
+                event_sender: None,
             })
             .expect("readonly execution failed");
 
@@ -199,10 +202,122 @@ mod tests {
                 is_final: false,
                 coins: None,
                 fee: None,
+                label: None,
+                trace: false,
+                event_sender: None,
             })
             .expect("readonly execution failed");
 
         assert!(res.out.slot.period > 8);
+        assert!(res.trace.is_none());
+
+        // the fee paid by the caller is part of the recorded trace
+        let caller =
+            Address::from_public_key(&KeyPair::from_str(TEST_SK_1).unwrap().get_public_key());
+        let fee = Amount::from_str("0.01").unwrap();
+        let res = controller
+            .execute_readonly_request(ReadOnlyExecutionRequest {
+                max_gas: 1_000_000,
+                call_stack: vec![ExecutionStackElement {
+                    address: caller,
+                    coins: Amount::zero(),
+                    owned_addresses: vec![caller],
+                    operation_datastore: None,
+                }],
+                target: ReadOnlyExecutionTarget::BytecodeExecution(
+                    include_bytes!("./wasm/event_test.wasm").to_vec(),
+                ),
+                is_final: true,
+                coins: None,
+                fee: Some(fee),
+                label: None,
+                trace: true,
+                event_sender: None,
+            })
+            .expect("readonly execution failed");
+
+        assert_eq!(
+            res.trace,
+            Some(vec![ExecutionTraceStep::Transfer {
+                depth: 1,
+                from: Some(caller),
+                to: None,
+                amount: fee,
+            }])
+        );
+
+        // a failed execution returns the trace recorded up to the failure, here the fee paid before the invalid bytecode is loaded
+        let res = controller.execute_readonly_request(ReadOnlyExecutionRequest {
+            max_gas: 1_000_000,
+            call_stack: vec![ExecutionStackElement {
+                address: caller,
+                coins: Amount::zero(),
+                owned_addresses: vec![caller],
+                operation_datastore: None,
+            }],
+            target: ReadOnlyExecutionTarget::BytecodeExecution(vec![1, 2, 3]),
+            is_final: true,
+            coins: None,
+            fee: Some(fee),
+            label: None,
+            trace: true,
+            event_sender: None,
+        });
+        match res {
+            Err(ExecutionError::TracedError { trace, .. }) => assert_eq!(
+                trace,
+                vec![ExecutionTraceStep::Transfer {
+                    depth: 1,
+                    from: Some(caller),
+                    to: None,
+                    amount: fee,
+                }]
+            ),
+            _ => panic!("the execution should fail with its trace"),
+        }
+
+        // streamed events are sent as they are emitted and not kept in the output
+        let (event_sender, mut event_receiver) = tokio::sync::mpsc::channel(16);
+        let mut res = controller
+            .execute_readonly_request(ReadOnlyExecutionRequest {
+                max_gas: 1_000_000,
+                call_stack: vec![],
+                target: ReadOnlyExecutionTarget::BytecodeExecution(
+                    include_bytes!("./wasm/event_test.wasm").to_vec(),
+                ),
+                is_final: true,
+                coins: None,
+                fee: None,
+                label: None,
+                trace: false,
+                event_sender: Some(event_sender),
+            })
+            .expect("readonly execution failed");
+
+        assert!(res.out.events.take().is_empty());
+        assert!(event_receiver.try_recv().is_ok());
+        assert!(event_receiver.try_recv().is_err(), "wrong number of events");
+
+        // the execution fails when an event cannot be streamed, here because nobody listens anymore
+        let (event_sender, event_receiver) = tokio::sync::mpsc::channel(16);
+        drop(event_receiver);
+        let res = controller.execute_readonly_request(ReadOnlyExecutionRequest {
+            max_gas: 1_000_000,
+            call_stack: vec![],
+            target: ReadOnlyExecutionTarget::BytecodeExecution(
+                include_bytes!("./wasm/event_test.wasm").to_vec(),
+            ),
+            is_final: true,
+            coins: None,
+            fee: None,
+            label: None,
+            trace: false,
+            event_sender: Some(event_sender),
+        });
+        assert!(
+            res.is_err(),
+            "the execution should fail on a closed event stream"
+        );
 
         manager.stop();
     }
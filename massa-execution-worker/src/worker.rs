@@ -24,7 +24,7 @@ use massa_wallet::Wallet;
 use parking_lot::{Condvar, Mutex, RwLock};
 use std::sync::Arc;
 use std::thread;
-use tracing::debug;
+use tracing::{debug, info_span};
 
 /// Structure gathering all elements needed by the execution thread
 pub(crate) struct ExecutionThread {
@@ -95,6 +95,11 @@ impl ExecutionThread {
         if let Some(req_resp) = self.readonly_requests.pop() {
             let (req, resp_tx) = req_resp.into_request_sender_pair();
 
+            // Tag the logs of the execution with the label of the request, if any
+            let span = info_span!("read_only_execution", label = req.label.as_deref());
+            let _enter = span.enter();
+            debug!("executing read-only request");
+
             // Acquire write access to the execution state (for cache updates) and execute the read-only request
             let outcome = self.execution_state.write().execute_readonly_request(req);
 
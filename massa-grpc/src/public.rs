@@ -131,6 +131,9 @@ pub(crate) fn execute_read_only_call(
                     .map_err(|_| GrpcError::InvalidArgument("invalid amount".to_string()))
             })
             .transpose()?,
+        label: None,
+        trace: false,
+        event_sender: None,
     };
 
     let output = grpc
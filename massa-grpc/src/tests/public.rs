@@ -354,6 +354,7 @@ async fn execute_read_only_call() {
                 },
                 gas_cost: 100,
                 call_result: "toto".as_bytes().to_vec(),
+                trace: None,
             })
         });
 
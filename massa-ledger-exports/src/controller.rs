@@ -2,7 +2,7 @@ use massa_models::{address::Address, amount::Amount, bytecode::Bytecode};
 use std::collections::BTreeSet;
 use std::fmt::Debug;
 
-use crate::{LedgerChanges, LedgerError};
+use crate::{LedgerChanges, LedgerError, LedgerStats};
 use massa_db_exports::DBBatch;
 
 pub trait LedgerController: Send + Sync + Debug {
@@ -37,11 +37,38 @@ pub trait LedgerController: Send + Sync + Debug {
     /// A copy of the datastore value, or `None` if the ledger entry or datastore entry was not found
     fn get_data_entry(&self, addr: &Address, key: &[u8]) -> Option<Vec<u8>>;
 
-    /// Get every key of the datastore for a given address.
+    /// Get the keys of the datastore for a given address, in key order,
+    /// stopping after `max_keys` keys if set.
     ///
     /// # Returns
     /// A `BTreeSet` of the datastore keys
-    fn get_datastore_keys(&self, addr: &Address, prefix: &[u8]) -> Option<BTreeSet<Vec<u8>>>;
+    fn get_datastore_keys(
+        &self,
+        addr: &Address,
+        prefix: &[u8],
+        max_keys: Option<usize>,
+    ) -> Option<BTreeSet<Vec<u8>>>;
+
+    /// Get the addresses having at least one datastore key starting with `prefix`, in address order.
+    ///
+    /// The scan starts after the address `after` and visits at most `max_visited` addresses.
+    ///
+    /// # Returns
+    /// Up to `limit` matching addresses and, unless the end of the ledger was reached,
+    /// the last visited address from which to resume the scan
+    fn find_addresses_with_key_prefix(
+        &self,
+        prefix: &[u8],
+        after: Option<&Address>,
+        max_visited: usize,
+        limit: usize,
+    ) -> (Vec<Address>, Option<Address>);
+
+    /// Get the address count, datastore entry count and byte size of the ledger.
+    ///
+    /// The counters are computed when the ledger is loaded and maintained as the ledger is written,
+    /// bootstrap included, so they are read without scanning the ledger.
+    fn get_ledger_stats(&self) -> LedgerStats;
 
     /// Reset the ledger
     ///
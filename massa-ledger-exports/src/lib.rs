@@ -25,7 +25,7 @@ pub use ledger_changes::{
 };
 pub use ledger_entry::{LedgerEntry, LedgerEntryDeserializer, LedgerEntrySerializer};
 pub use types::{
-    Applicable, SetOrDelete, SetOrKeep, SetOrKeepDeserializer, SetOrKeepSerializer,
+    Applicable, LedgerStats, SetOrDelete, SetOrKeep, SetOrKeepDeserializer, SetOrKeepSerializer,
     SetUpdateOrDelete, SetUpdateOrDeleteDeserializer, SetUpdateOrDeleteSerializer,
 };
 
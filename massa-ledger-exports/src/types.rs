@@ -369,3 +369,14 @@ impl<T: Clone> Default for SetOrKeep<T> {
         SetOrKeep::Keep
     }
 }
+
+/// Size counters of the ledger
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LedgerStats {
+    /// number of addresses in the ledger
+    pub address_count: u64,
+    /// number of datastore entries across all addresses
+    pub datastore_entry_count: u64,
+    /// approximate size of the ledger in bytes, counting keys and values as stored
+    pub byte_size: u64,
+}
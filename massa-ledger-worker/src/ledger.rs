@@ -5,7 +5,7 @@
 use crate::ledger_db::{LedgerDB, LedgerSubEntry};
 use massa_db_exports::{DBBatch, ShareableMassaDBController};
 use massa_ledger_exports::{
-    LedgerChanges, LedgerConfig, LedgerController, LedgerEntry, LedgerError,
+    LedgerChanges, LedgerConfig, LedgerController, LedgerEntry, LedgerError, LedgerStats,
 };
 use massa_models::{
     address::Address,
@@ -134,12 +134,37 @@ impl LedgerController for FinalLedger {
             .get_sub_entry(addr, LedgerSubEntry::Datastore(key.to_owned()))
     }
 
-    /// Get every key of the datastore for a given address.
+    /// Get the keys of the datastore for a given address, in key order,
+    /// stopping after `max_keys` keys if set.
     ///
     /// # Returns
     /// A `BTreeSet` of the datastore keys
-    fn get_datastore_keys(&self, addr: &Address, prefix: &[u8]) -> Option<BTreeSet<Vec<u8>>> {
-        self.sorted_ledger.get_datastore_keys(addr, prefix)
+    fn get_datastore_keys(
+        &self,
+        addr: &Address,
+        prefix: &[u8],
+        max_keys: Option<usize>,
+    ) -> Option<BTreeSet<Vec<u8>>> {
+        self.sorted_ledger
+            .get_datastore_keys(addr, prefix, max_keys)
+    }
+
+    /// Get the addresses having at least one datastore key starting with `prefix`, up to `limit` of them,
+    /// visiting at most `max_visited` addresses after `after`.
+    fn find_addresses_with_key_prefix(
+        &self,
+        prefix: &[u8],
+        after: Option<&Address>,
+        max_visited: usize,
+        limit: usize,
+    ) -> (Vec<Address>, Option<Address>) {
+        self.sorted_ledger
+            .find_addresses_with_key_prefix(prefix, after, max_visited, limit)
+    }
+
+    /// Get the size counters of the ledger.
+    fn get_ledger_stats(&self) -> LedgerStats {
+        self.sorted_ledger.get_ledger_stats()
     }
 
     /// Reset the disk ledger.
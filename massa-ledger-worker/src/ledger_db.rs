@@ -3,8 +3,8 @@
 //! Module to interact with the disk ledger
 
 use massa_db_exports::{
-    DBBatch, MassaDirection, MassaIteratorMode, ShareableMassaDBController, CRUD_ERROR,
-    KEY_SER_ERROR, LEDGER_PREFIX, STATE_CF,
+    DBBatch, MassaDirection, MassaIteratorMode, ShareableMassaDBController, StateChangeObserver,
+    CRUD_ERROR, KEY_SER_ERROR, LEDGER_PREFIX, STATE_CF,
 };
 use massa_ledger_exports::*;
 use massa_models::amount::AmountDeserializer;
@@ -18,6 +18,7 @@ use massa_serialization::{
 };
 use std::collections::{BTreeSet, HashMap};
 use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
 
 use massa_models::amount::Amount;
 use std::ops::Bound;
@@ -45,6 +46,68 @@ impl LedgerSubEntry {
     }
 }
 
+/// Size counters of the ledger, updated as ledger keys are written to the state db
+struct LedgerStatsCounter {
+    key_deserializer_db: KeyDeserializer,
+    stats: Mutex<LedgerStats>,
+}
+
+impl LedgerStatsCounter {
+    /// Add (or remove) a stored sub-entry to (or from) the size counters.
+    ///
+    /// # Arguments
+    /// * `stats`: counters to update
+    /// * `serialized_key`: key of the sub-entry in the state db
+    /// * `value`: stored value of the sub-entry
+    /// * `add`: whether the sub-entry is added or removed
+    fn count_sub_entry(
+        &self,
+        stats: &mut LedgerStats,
+        serialized_key: &[u8],
+        value: &[u8],
+        add: bool,
+    ) {
+        let (address_count, datastore_entry_count) = match self
+            .key_deserializer_db
+            .deserialize::<DeserializeError>(serialized_key)
+            .map(|(_rest, key)| key.key_type)
+        {
+            Ok(KeyType::VERSION) => (1, 0),
+            Ok(KeyType::DATASTORE(_)) => (0, 1),
+            _ => (0, 0),
+        };
+        let byte_size = (serialized_key.len() + value.len()) as u64;
+        if add {
+            stats.address_count = stats.address_count.saturating_add(address_count);
+            stats.datastore_entry_count = stats
+                .datastore_entry_count
+                .saturating_add(datastore_entry_count);
+            stats.byte_size = stats.byte_size.saturating_add(byte_size);
+        } else {
+            stats.address_count = stats.address_count.saturating_sub(address_count);
+            stats.datastore_entry_count = stats
+                .datastore_entry_count
+                .saturating_sub(datastore_entry_count);
+            stats.byte_size = stats.byte_size.saturating_sub(byte_size);
+        }
+    }
+}
+
+impl StateChangeObserver for LedgerStatsCounter {
+    fn on_state_change(&self, key: &[u8], previous: Option<&[u8]>, new: Option<&[u8]>) {
+        if !key.starts_with(LEDGER_PREFIX.as_bytes()) {
+            return;
+        }
+        let mut stats = self.stats.lock().expect("double-lock on ledger stats");
+        if let Some(previous) = previous {
+            self.count_sub_entry(&mut stats, key, previous, false);
+        }
+        if let Some(new) = new {
+            self.count_sub_entry(&mut stats, key, new, true);
+        }
+    }
+}
+
 /// Disk ledger DB module
 ///
 /// Contains a `RocksDB` DB instance
@@ -61,6 +124,8 @@ pub struct LedgerDB {
     bytecode_deserializer: BytecodeDeserializer,
     max_datastore_value_length: u64,
     max_datastore_key_length: u8,
+    /// size counters of the ledger, computed when the ledger is loaded and updated on every write
+    stats: Arc<LedgerStatsCounter>,
 }
 
 impl Debug for LedgerDB {
@@ -81,6 +146,25 @@ impl LedgerDB {
         max_datastore_key_length: u8,
         max_datastore_value_length: u64,
     ) -> Self {
+        let stats = Arc::new(LedgerStatsCounter {
+            key_deserializer_db: KeyDeserializer::new(max_datastore_key_length, false),
+            stats: Mutex::new(LedgerStats::default()),
+        });
+        {
+            // count the ledger already on disk, the observer then follows every write, bootstrap included
+            let mut db = db.write();
+            {
+                let mut counters = stats.stats.lock().expect("double-lock on ledger stats");
+                for (serialized_key, value) in db
+                    .prefix_iterator_cf(STATE_CF, LEDGER_PREFIX.as_bytes())
+                    .take_while(|(key, _)| key.starts_with(LEDGER_PREFIX.as_bytes()))
+                {
+                    stats.count_sub_entry(&mut counters, &serialized_key, &value, true);
+                }
+            }
+            db.add_state_change_observer(stats.clone());
+        }
+
         LedgerDB {
             db,
             thread_count,
@@ -100,6 +184,7 @@ impl LedgerDB {
             ),
             max_datastore_value_length,
             max_datastore_key_length,
+            stats,
         }
     }
 
@@ -126,27 +211,43 @@ impl LedgerDB {
     /// * changes: ledger changes to be applied
     /// * batch: the batch to apply the changes to
     pub fn apply_changes_to_batch(&self, changes: LedgerChanges, batch: &mut DBBatch) {
+        let mut ledger_batch = DBBatch::new();
+
         // for all incoming changes
         for (addr, change) in changes.0 {
             match change {
                 // the incoming change sets a ledger entry to a new one
                 SetUpdateOrDelete::Set(new_entry) => {
                     // inserts/overwrites the entry with the incoming one
-                    self.put_entry(&addr, new_entry, batch);
+                    self.put_entry(&addr, new_entry, &mut ledger_batch);
                 }
                 // the incoming change updates an existing ledger entry
                 SetUpdateOrDelete::Update(entry_update) => {
                     // applies the updates to the entry
                     // if the entry does not exist, inserts a default one and applies the updates to it
-                    self.update_entry(&addr, entry_update, batch);
+                    self.update_entry(&addr, entry_update, &mut ledger_batch);
                 }
                 // the incoming change deletes a ledger entry
                 SetUpdateOrDelete::Delete => {
                     // delete the entry, if it exists
-                    self.delete_entry(&addr, batch);
+                    self.delete_entry(&addr, &mut ledger_batch);
                 }
             }
         }
+
+        batch.extend(ledger_batch);
+    }
+
+    /// Get the size counters of the ledger.
+    ///
+    /// The counters are computed when the ledger is loaded and updated when ledger keys are written
+    /// to the state db, so this does not access the db.
+    pub fn get_ledger_stats(&self) -> LedgerStats {
+        *self
+            .stats
+            .stats
+            .lock()
+            .expect("double-lock on ledger stats")
     }
 
     /// Get the given sub-entry of a given address.
@@ -167,11 +268,17 @@ impl LedgerDB {
         db.get_cf(STATE_CF, serialized_key).expect(CRUD_ERROR)
     }
 
-    /// Get every key of the datastore for a given address.
+    /// Get the keys of the datastore for a given address, in key order,
+    /// stopping after `max_keys` keys if set.
     ///
     /// # Returns
     /// A `BTreeSet` of the datastore keys
-    pub fn get_datastore_keys(&self, addr: &Address, prefix: &[u8]) -> Option<BTreeSet<Vec<u8>>> {
+    pub fn get_datastore_keys(
+        &self,
+        addr: &Address,
+        prefix: &[u8],
+        max_keys: Option<usize>,
+    ) -> Option<BTreeSet<Vec<u8>>> {
         let db = self.db.read();
 
         // check if address exists, return None if it does not
@@ -206,10 +313,84 @@ impl LedgerDB {
                     _ => None,
                 }
             })
+            .take(max_keys.unwrap_or(usize::MAX))
             .collect(),
         )
     }
 
+    /// Get the addresses having at least one datastore key starting with `prefix`, in address order.
+    /// The scan starts after the address `after` (or at the beginning of the ledger),
+    /// and stops once `limit` addresses are found or `max_visited` addresses are visited.
+    ///
+    /// Each visited address costs a few seeks in the database, whatever the size of its datastore.
+    ///
+    /// # Returns
+    /// The matching addresses and, unless the end of the ledger was reached, the last visited address
+    /// to be passed as `after` to resume the scan
+    pub fn find_addresses_with_key_prefix(
+        &self,
+        prefix: &[u8],
+        after: Option<&Address>,
+        max_visited: usize,
+        limit: usize,
+    ) -> (Vec<Address>, Option<Address>) {
+        let db = self.db.read();
+        let mut addresses = Vec::new();
+        let mut cursor = match after {
+            // the datastore keys of an address come after its other sub-entries
+            Some(address) => match end_prefix(&datastore_prefix_from_address(address, &[])) {
+                Some(end) => end,
+                None => return (addresses, None),
+            },
+            None => LEDGER_PREFIX.as_bytes().to_vec(),
+        };
+        let mut last_visited = after.copied();
+        let mut visited = 0;
+        while addresses.len() < limit && visited < max_visited {
+            // first key of the next address
+            let Some((serialized_key, _)) = db
+                .iterator_cf(
+                    STATE_CF,
+                    MassaIteratorMode::From(&cursor, MassaDirection::Forward),
+                )
+                .next()
+            else {
+                return (addresses, None);
+            };
+            if !serialized_key.starts_with(LEDGER_PREFIX.as_bytes()) {
+                return (addresses, None);
+            }
+            let (_rest, key) = self
+                .key_deserializer_db
+                .deserialize::<DeserializeError>(&serialized_key)
+                .expect("could not deserialize ledger key from state db");
+
+            // first datastore key of the address starting with the prefix
+            let start_prefix = datastore_prefix_from_address(&key.address, prefix);
+            if db
+                .iterator_cf(
+                    STATE_CF,
+                    MassaIteratorMode::From(&start_prefix, MassaDirection::Forward),
+                )
+                .next()
+                .map_or(false, |(serialized_key, _)| {
+                    serialized_key.starts_with(&start_prefix)
+                })
+            {
+                addresses.push(key.address);
+            }
+            visited += 1;
+            last_visited = Some(key.address);
+
+            // skip the rest of the address: its datastore keys come after its other sub-entries
+            match end_prefix(&datastore_prefix_from_address(&key.address, &[])) {
+                Some(end) => cursor = end,
+                None => return (addresses, None),
+            }
+        }
+        (addresses, last_visited)
+    }
+
     pub fn reset(&self) {
         self.db.write().delete_prefix(LEDGER_PREFIX, STATE_CF, None);
     }
@@ -560,7 +741,7 @@ mod tests {
     use massa_db_exports::{MassaDBConfig, MassaDBController, STATE_HASH_INITIAL_BYTES};
     use massa_db_worker::MassaDB;
     use massa_hash::HashXof;
-    use massa_ledger_exports::{LedgerEntry, LedgerEntryUpdate, SetOrKeep};
+    use massa_ledger_exports::{LedgerEntry, LedgerEntryUpdate, SetOrDelete, SetOrKeep};
     use massa_models::{
         address::Address,
         amount::{Amount, AmountDeserializer},
@@ -674,6 +855,171 @@ mod tests {
         assert!(ledger_db.get_entire_datastore(&addr).is_empty());
     }
 
+    /// Check that the ledger stats follow the applied changes
+    #[test]
+    fn test_ledger_stats() {
+        let addr = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+        let (ledger_db, _data) = init_test_ledger(addr);
+
+        let stats = ledger_db.get_ledger_stats();
+        assert_eq!(stats.address_count, 1);
+        assert_eq!(stats.datastore_entry_count, 3);
+        assert!(stats.byte_size > 0);
+
+        // add a datastore entry and delete another one
+        let mut changes = LedgerChanges::default();
+        changes.0.insert(
+            addr,
+            SetUpdateOrDelete::Update(LedgerEntryUpdate {
+                datastore: BTreeMap::from([
+                    (b"1".to_vec(), SetOrDelete::Delete),
+                    (b"4".to_vec(), SetOrDelete::Set(b"dd".to_vec())),
+                    (b"5".to_vec(), SetOrDelete::Set(b"e".to_vec())),
+                ]),
+                ..Default::default()
+            }),
+        );
+        let mut batch = DBBatch::new();
+        ledger_db.apply_changes_to_batch(changes, &mut batch);
+        ledger_db
+            .db
+            .write()
+            .write_batch(batch, Default::default(), None);
+
+        // the maintained counters match the ones counted when loading the ledger
+        let stats = ledger_db.get_ledger_stats();
+        assert_eq!(stats.datastore_entry_count, 4);
+        let reloaded_ledger_db = LedgerDB::new(ledger_db.db.clone(), 32, 255, 1000);
+        assert_eq!(stats, reloaded_ledger_db.get_ledger_stats());
+
+        // delete the address
+        let mut changes = LedgerChanges::default();
+        changes.0.insert(addr, SetUpdateOrDelete::Delete);
+        let mut batch = DBBatch::new();
+        ledger_db.apply_changes_to_batch(changes, &mut batch);
+        // the counters only change once the batch is written
+        assert_eq!(ledger_db.get_ledger_stats(), stats);
+        ledger_db
+            .db
+            .write()
+            .write_batch(batch, Default::default(), None);
+        assert_eq!(ledger_db.get_ledger_stats(), LedgerStats::default());
+    }
+
+    #[test]
+    fn test_find_addresses_with_key_prefix() {
+        let addr = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+        let (ledger_db, _data) = init_test_ledger(addr);
+
+        // a second address without matching keys
+        let other = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+        let mut batch = DBBatch::new();
+        ledger_db.put_entry(
+            &other,
+            LedgerEntry {
+                datastore: BTreeMap::from([(b"a".to_vec(), b"x".to_vec())]),
+                ..Default::default()
+            },
+            &mut batch,
+        );
+        ledger_db
+            .db
+            .write()
+            .write_batch(batch, Default::default(), None);
+
+        assert_eq!(
+            ledger_db.find_addresses_with_key_prefix(b"2", None, 10, 10),
+            (vec![addr], None)
+        );
+        assert_eq!(
+            ledger_db.find_addresses_with_key_prefix(b"a", None, 10, 10),
+            (vec![other], None)
+        );
+        assert_eq!(
+            ledger_db.find_addresses_with_key_prefix(b"4", None, 10, 10),
+            (vec![], None)
+        );
+        assert_eq!(
+            ledger_db
+                .find_addresses_with_key_prefix(b"", None, 10, 10)
+                .0
+                .len(),
+            2
+        );
+        assert_eq!(
+            ledger_db
+                .find_addresses_with_key_prefix(b"", None, 10, 1)
+                .0
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_find_addresses_with_key_prefix_bounded_scan() {
+        let addr = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+        let (ledger_db, _data) = init_test_ledger(addr);
+        let other = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+        let mut batch = DBBatch::new();
+        ledger_db.put_entry(
+            &other,
+            LedgerEntry {
+                datastore: BTreeMap::from([(b"a".to_vec(), b"x".to_vec())]),
+                ..Default::default()
+            },
+            &mut batch,
+        );
+        ledger_db
+            .db
+            .write()
+            .write_batch(batch, Default::default(), None);
+
+        // a prefix matching nothing stops after the visit budget, and the scan resumes from there
+        let (found, Some(first)) = ledger_db.find_addresses_with_key_prefix(b"4", None, 1, 10)
+        else {
+            panic!("the scan should stop after visiting one address");
+        };
+        assert!(found.is_empty());
+        let second = if first == addr { other } else { addr };
+        assert_eq!(
+            ledger_db.find_addresses_with_key_prefix(b"4", Some(&first), 1, 10),
+            (vec![], Some(second))
+        );
+        assert_eq!(
+            ledger_db.find_addresses_with_key_prefix(b"4", Some(&second), 1, 10),
+            (vec![], None)
+        );
+
+        // resuming after the first address only finds the second one
+        assert_eq!(
+            ledger_db.find_addresses_with_key_prefix(b"", Some(&first), 10, 10),
+            (vec![second], None)
+        );
+    }
+
+    #[test]
+    fn test_get_datastore_keys_max_keys() {
+        let addr = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+        let (ledger_db, data) = init_test_ledger(addr);
+
+        assert_eq!(
+            ledger_db.get_datastore_keys(&addr, b"", None),
+            Some(data.keys().cloned().collect())
+        );
+        // the listing stops after the first keys, in key order
+        assert_eq!(
+            ledger_db.get_datastore_keys(&addr, b"", Some(2)),
+            Some(BTreeSet::from([b"1".to_vec(), b"2".to_vec()]))
+        );
+        assert_eq!(
+            ledger_db.get_datastore_keys(&addr, b"3", Some(2)),
+            Some(BTreeSet::from([b"3".to_vec()]))
+        );
+        // an address without ledger entry has no datastore
+        let other = Address::from_public_key(&KeyPair::generate(0).unwrap().get_public_key());
+        assert_eq!(ledger_db.get_datastore_keys(&other, b"", Some(2)), None);
+    }
+
     #[test]
     fn test_end_prefix() {
         assert_eq!(end_prefix(&[5, 6, 7]), Some(vec![5, 6, 8]));
@@ -660,6 +660,19 @@ pub struct ExecutionAddressCycleInfo {
     pub active_rolls: Option<u64>,
 }
 
+/// Number of active rolls an address is scheduled to have at an upcoming cycle.
+/// The active rolls of a cycle are the roll count at the end of the cycle 3 cycles before it,
+/// so roll buys and sells show up here before they take effect in the draws.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScheduledRollCount {
+    /// upcoming cycle number
+    pub cycle: u64,
+    /// number of active rolls the address will have at that cycle
+    pub active_rolls: u64,
+    /// true if the roll count it is taken from is final, false if it is the current candidate roll count
+    pub is_final: bool,
+}
+
 #[cfg(test)]
 mod test {
     use crate::config::THREAD_COUNT;
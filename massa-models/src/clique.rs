@@ -25,10 +25,13 @@ use std::ops::Bound::{Excluded, Included};
 pub struct Clique {
     /// the block ids of the blocks in that clique
     pub block_ids: PreHashSet<BlockId>,
-    /// Fitness used to compute finality
-    /// Depends on descendants and endorsement count
+    /// Fitness used to compute finality and to choose the blockclique:
+    /// sum of the fitness of the blocks of the clique,
+    /// each block having a fitness of 1 + its endorsement count
     pub fitness: u64,
-    /// True if it is the clique of higher fitness
+    /// True if it is the clique of higher fitness (the blockclique).
+    /// Exactly one clique of the graph is the blockclique, fitness ties are broken
+    /// in favor of the clique with the smallest sum of block id hashes
     pub is_blockclique: bool,
 }
 
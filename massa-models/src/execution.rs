@@ -1,6 +1,6 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
-use crate::{address::Address, operation::OperationId, slot::Slot};
+use crate::{address::Address, amount::Amount, operation::OperationId, slot::Slot};
 use serde::{Deserialize, Serialize};
 
 /// filter used when retrieving SC output events
@@ -29,3 +29,40 @@ pub struct EventFilter {
     /// None means both
     pub is_error: Option<bool>,
 }
+
+/// step of the call trace recorded during a read-only execution, in execution order
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ExecutionTraceStep {
+    /// a smart contract is entered, `depth` being the call stack size once it is entered
+    Call {
+        /// call stack size including the called address
+        depth: usize,
+        /// address at the top of the call stack before the call
+        caller: Address,
+        /// called address
+        target: Address,
+        /// called function, only known for the function targeted by the request:
+        /// the runtime does not report the function name of sub-calls
+        function: Option<String>,
+        /// coins sent along with the call
+        coins: Amount,
+    },
+    /// the smart contract at the top of the call stack returns
+    Return {
+        /// call stack size before returning
+        depth: usize,
+        /// returning address
+        address: Address,
+    },
+    /// coins are moved, `None` meaning coins created or destroyed (e.g. fees)
+    Transfer {
+        /// call stack size at the time of the transfer
+        depth: usize,
+        /// debited address
+        from: Option<Address>,
+        /// credited address
+        to: Option<Address>,
+        /// transferred amount
+        amount: Amount,
+    },
+}
@@ -114,12 +114,15 @@ impl std::fmt::Display for ConsensusStats {
 }
 
 /// stats produced by pool module
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolStats {
     /// number of operations in the pool
     pub operation_count: u64,
     /// number of endorsement in the pool
     pub endorsement_count: u64,
+    /// number of operations in the pool, per thread
+    #[serde(default)]
+    pub operation_count_per_thread: Vec<u64>,
 }
 
 impl std::fmt::Display for PoolStats {
@@ -127,6 +130,11 @@ impl std::fmt::Display for PoolStats {
         writeln!(f, "Pool stats:")?;
         writeln!(f, "\tOperations: {}", self.operation_count)?;
         writeln!(f, "\tEndorsements: {}", self.endorsement_count)?;
+        writeln!(
+            f,
+            "\tOperations per thread: {:?}",
+            self.operation_count_per_thread
+        )?;
         Ok(())
     }
 }
@@ -13,7 +13,7 @@ use crate::survey::MassaSurvey;
 use clap::{crate_version, Parser};
 use crossbeam_channel::TryRecvError;
 use dialoguer::Password;
-use massa_api::{ApiServer, ApiV2, Private, Public, RpcServer, StopHandle, API};
+use massa_api::{ApiServer, ApiV2, Private, Public, RequestAdmission, RpcServer, StopHandle, API};
 use massa_api_exports::config::APIConfig;
 use massa_async_pool::AsyncPoolConfig;
 use massa_bootstrap::BootstrapError;
@@ -87,7 +87,7 @@ use massa_protocol_exports::{ProtocolConfig, ProtocolManager, TransportType};
 use massa_protocol_worker::{create_protocol_controller, start_protocol_controller};
 use massa_signature::KeyPair;
 use massa_storage::Storage;
-use massa_time::MassaTime;
+use massa_time::{MassaTime, SystemClock};
 use massa_versioning::keypair_factory::KeyPairFactory;
 use massa_versioning::mips::get_mip_list;
 use massa_versioning::versioning::{MipStatsConfig, MipStore};
@@ -537,6 +537,7 @@ async fn launch(
         max_block_size: MAX_BLOCK_SIZE,
         max_block_gas: MAX_GAS_PER_BLOCK,
         roll_price: ROLL_PRICE,
+        minimal_fees: SETTINGS.pool.minimal_fees,
         max_block_endorsement_count: ENDORSEMENT_COUNT,
         operation_validity_periods: OPERATION_VALIDITY_PERIODS,
         max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
@@ -692,6 +693,7 @@ async fn launch(
         operation_validity_periods: OPERATION_VALIDITY_PERIODS,
         periods_per_cycle: PERIODS_PER_CYCLE,
         stats_timespan: SETTINGS.consensus.stats_timespan,
+        stats_history_length: SETTINGS.consensus.stats_history_length,
         force_keep_final_periods: SETTINGS.consensus.force_keep_final_periods,
         endorsement_count: ENDORSEMENT_COUNT,
         block_db_prune_interval: SETTINGS.consensus.block_db_prune_interval,
@@ -710,6 +712,7 @@ async fn launch(
         force_keep_final_periods_without_ops: SETTINGS
             .consensus
             .force_keep_final_periods_without_ops,
+        clock: Arc::new(SystemClock),
     };
 
     let (consensus_event_sender, consensus_event_receiver) =
@@ -813,12 +816,27 @@ async fn launch(
         bind_api: SETTINGS.api.bind_api,
         draw_lookahead_period_count: SETTINGS.api.draw_lookahead_period_count,
         max_arguments: SETTINGS.api.max_arguments,
+        max_request_bytes: SETTINGS.api.max_request_bytes,
+        max_graph_interval_slots: SETTINGS.api.max_graph_interval_slots,
+        max_key_prefix_scan: SETTINGS.api.max_key_prefix_scan,
+        enable_response_compression: SETTINGS.api.enable_response_compression,
+        compression_min_response_size: SETTINGS.api.compression_min_response_size,
+        rate_limits: SETTINGS.api.rate_limits.clone(),
+        status_cache_ttl: SETTINGS.api.status_cache_ttl,
+        status_retry_count: SETTINGS.api.status_retry_count,
+        read_only_deadline: SETTINGS.api.read_only_deadline,
+        pool_add_timeout: SETTINGS.api.pool_add_timeout,
+        read_only_default_caller: SETTINGS.api.read_only_default_caller,
+        max_concurrent_requests: SETTINGS.api.max_concurrent_requests,
+        expose_staking_addresses: SETTINGS.api.expose_staking_addresses,
+        expose_pool_operations: SETTINGS.api.expose_pool_operations,
         openrpc_spec_path: SETTINGS.api.openrpc_spec_path.clone(),
         bootstrap_whitelist_path: SETTINGS.bootstrap.bootstrap_whitelist_path.clone(),
         bootstrap_blacklist_path: SETTINGS.bootstrap.bootstrap_blacklist_path.clone(),
         max_request_body_size: SETTINGS.api.max_request_body_size,
         max_response_body_size: SETTINGS.api.max_response_body_size,
         max_connections: SETTINGS.api.max_connections,
+        worker_threads: SETTINGS.api.worker_threads,
         max_subscriptions_per_connection: SETTINGS.api.max_subscriptions_per_connection,
         max_log_length: SETTINGS.api.max_log_length,
         allow_hosts: SETTINGS.api.allow_hosts.clone(),
@@ -831,6 +849,9 @@ async fn launch(
         max_op_datastore_key_length: MAX_OPERATION_DATASTORE_KEY_LENGTH,
         max_op_datastore_value_length: MAX_OPERATION_DATASTORE_VALUE_LENGTH,
         max_gas_per_block: MAX_GAS_PER_BLOCK,
+        max_read_only_gas: SETTINGS.execution.max_read_only_gas,
+        ledger_entry_base_cost: storage_costs_constants.ledger_entry_base_cost,
+        minimal_fees: SETTINGS.pool.minimal_fees,
         max_function_name_length: MAX_FUNCTION_NAME_LENGTH,
         max_parameter_size: MAX_PARAMETERS_SIZE,
         thread_count: THREAD_COUNT,
@@ -841,6 +862,9 @@ async fn launch(
         last_start_period: final_state.read().last_start_period,
     };
 
+    // the public API and the API v2 share their rate limits and in-flight request permits
+    let api_admission = RequestAdmission::new(api_config.max_concurrent_requests);
+
     // spawn Massa API
     let api = API::<ApiV2>::new(
         consensus_controller.clone(),
@@ -849,6 +873,7 @@ async fn launch(
         pool_channels.broadcasts.clone(),
         api_config.clone(),
         *VERSION,
+        api_admission.clone(),
     );
     let api_handle = api
         .serve(&SETTINGS.api.bind_api, &api_config)
@@ -959,11 +984,12 @@ async fn launch(
 
     // spawn private API
     let api_private = API::<Private>::new(
+        consensus_controller.clone(),
         protocol_controller.clone(),
         execution_controller.clone(),
         api_config.clone(),
         sig_int_toggled,
-        node_wallet,
+        node_wallet.clone(),
     );
     let api_private_handle = api_private
         .serve(&SETTINGS.api.bind_private, &api_config)
@@ -987,6 +1013,11 @@ async fn launch(
         node_id,
         shared_storage.clone(),
         mip_store.clone(),
+        node_wallet,
+        bootstrap_manager
+            .as_ref()
+            .map(|manager| manager.active_sessions()),
+        api_admission,
     );
     let api_public_handle = api_public
         .serve(&SETTINGS.api.bind_public, &api_config)
@@ -1185,15 +1216,16 @@ async fn stop(
     info!("API | PRIVATE gRPC | stopped");
 
     // stop Massa API
-    api_handle.stop().await;
+    let api_stop_timeout = SETTINGS.api.stop_timeout.to_duration();
+    api_handle.stop_graceful(api_stop_timeout).await;
     info!("API | EXPERIMENTAL JsonRPC | stopped");
 
     // stop public API
-    api_public_handle.stop().await;
+    api_public_handle.stop_graceful(api_stop_timeout).await;
     info!("API | PUBLIC JsonRPC | stopped");
 
     // stop private API
-    api_private_handle.stop().await;
+    api_private_handle.stop_graceful(api_stop_timeout).await;
     info!("API | PRIVATE JsonRPC | stopped");
 
     // stop metrics
@@ -1,10 +1,13 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 //! Build here the default node settings from the configuration file toml
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+};
 
 use massa_bootstrap::IpType;
-use massa_models::{config::build_massa_settings, node::NodeId};
+use massa_models::{address::Address, amount::Amount, config::build_massa_settings, node::NodeId};
 use massa_protocol_exports::PeerCategoryInfo;
 use massa_time::MassaTime;
 use serde::Deserialize;
@@ -94,6 +97,8 @@ pub struct PoolSettings {
     pub operation_pool_refresh_interval: MassaTime,
     pub max_endorsements_pool_size_per_thread: usize,
     pub max_item_return_count: usize,
+    /// minimal fee of the operations accepted by the pool
+    pub minimal_fees: Amount,
     /// endorsements channel capacity
     pub broadcast_endorsements_channel_capacity: usize,
     /// operations channel capacity
@@ -108,10 +113,26 @@ pub struct APISettings {
     pub bind_public: SocketAddr,
     pub bind_api: SocketAddr,
     pub max_arguments: u64,
+    pub max_request_bytes: u64,
+    pub max_graph_interval_slots: u64,
+    pub max_key_prefix_scan: u64,
+    pub enable_response_compression: bool,
+    pub compression_min_response_size: u16,
+    pub rate_limits: BTreeMap<String, u32>,
+    pub status_cache_ttl: MassaTime,
+    pub status_retry_count: u32,
+    pub read_only_deadline: MassaTime,
+    pub pool_add_timeout: MassaTime,
+    pub read_only_default_caller: Address,
+    pub max_concurrent_requests: usize,
+    pub stop_timeout: MassaTime,
+    pub expose_staking_addresses: bool,
+    pub expose_pool_operations: bool,
     pub openrpc_spec_path: PathBuf,
     pub max_request_body_size: u32,
     pub max_response_body_size: u32,
     pub max_connections: u32,
+    pub worker_threads: usize,
     pub max_subscriptions_per_connection: u32,
     pub max_log_length: u32,
     pub allow_hosts: Vec<String>,
@@ -153,6 +174,8 @@ pub struct ConsensusSettings {
     pub max_dependency_blocks: usize,
     /// stats time span
     pub stats_timespan: MassaTime,
+    /// maximum number of consensus stats snapshots kept in history
+    pub stats_history_length: usize,
     /// force keep at least this number of final periods in RAM for each thread
     pub force_keep_final_periods: u64,
     /// force keep at least this number of final periods without operations in RAM for each thread
@@ -15,6 +15,8 @@ pub struct PoolConfig {
     pub max_block_gas: u64,
     /// cost (in coins) of a single roll
     pub roll_price: Amount,
+    /// minimal fee of the operations accepted by the pool
+    pub minimal_fees: Amount,
     /// operation validity periods
     pub operation_validity_periods: u64,
     /// operation pool refresh interval
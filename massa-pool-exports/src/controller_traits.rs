@@ -5,6 +5,7 @@ use massa_models::{
     denunciation::{Denunciation, DenunciationPrecursor},
     endorsement::EndorsementId,
     operation::OperationId,
+    prehash::PreHashSet,
     slot::Slot,
 };
 use massa_storage::Storage;
@@ -18,6 +19,11 @@ pub trait PoolController: Send + Sync {
     /// Asynchronously add operations to pool. Simply print a warning on failure.
     fn add_operations(&mut self, ops: Storage);
 
+    /// Synchronously add operations to pool.
+    /// Returns the ids of the given operations that are in the pool after the call,
+    /// the other ones were rejected.
+    fn add_operations_sync(&mut self, ops: Storage) -> PreHashSet<OperationId>;
+
     /// Asynchronously add endorsements to pool. Simply print a warning on failure.
     fn add_endorsements(&mut self, endorsements: Storage);
 
@@ -46,6 +52,12 @@ pub trait PoolController: Send + Sync {
     /// Get the number of operations in the pool
     fn get_operation_count(&self) -> usize;
 
+    /// Get the number of operations in the pool for each thread
+    fn get_operation_count_per_thread(&self) -> Vec<usize>;
+
+    /// Get the ids of at most `limit` operations of the pool, by decreasing priority
+    fn get_operation_ids(&self, limit: usize) -> Vec<OperationId>;
+
     /// Check if the pool contains a list of endorsements. Returns one boolean per item.
     fn contains_endorsements(&self, endorsements: &[EndorsementId]) -> Vec<bool>;
 
@@ -55,6 +67,9 @@ pub trait PoolController: Send + Sync {
     /// Get the number of denunciations in the pool
     fn get_denunciation_count(&self) -> usize;
 
+    /// Get at most `limit` denunciations of the pool that are not executed yet, by increasing slot
+    fn get_pending_denunciations(&self, limit: usize) -> Vec<Denunciation>;
+
     /// Returns a boxed clone of self.
     /// Useful to allow cloning `Box<dyn PoolController>`.
     fn clone_box(&self) -> Box<dyn PoolController>;
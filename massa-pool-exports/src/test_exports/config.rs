@@ -1,9 +1,12 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
-use massa_models::config::{
-    DENUNCIATION_EXPIRE_PERIODS, ENDORSEMENT_COUNT, MAX_BLOCK_SIZE,
-    MAX_DENUNCIATIONS_PER_BLOCK_HEADER, MAX_GAS_PER_BLOCK, MAX_OPERATIONS_PER_BLOCK,
-    OPERATION_VALIDITY_PERIODS, PERIODS_PER_CYCLE, ROLL_PRICE, T0, THREAD_COUNT,
+use massa_models::{
+    amount::Amount,
+    config::{
+        DENUNCIATION_EXPIRE_PERIODS, ENDORSEMENT_COUNT, MAX_BLOCK_SIZE,
+        MAX_DENUNCIATIONS_PER_BLOCK_HEADER, MAX_GAS_PER_BLOCK, MAX_OPERATIONS_PER_BLOCK,
+        OPERATION_VALIDITY_PERIODS, PERIODS_PER_CYCLE, ROLL_PRICE, T0, THREAD_COUNT,
+    },
 };
 use massa_time::MassaTime;
 
@@ -16,6 +19,7 @@ impl Default for PoolConfig {
             operation_validity_periods: OPERATION_VALIDITY_PERIODS,
             max_block_gas: MAX_GAS_PER_BLOCK,
             roll_price: ROLL_PRICE,
+            minimal_fees: Amount::zero(),
             max_block_size: MAX_BLOCK_SIZE,
             max_operation_pool_size: 32000,
             max_operation_pool_excess_items: 10000,
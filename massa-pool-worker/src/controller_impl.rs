@@ -4,12 +4,12 @@
 
 use massa_models::{
     block_id::BlockId, denunciation::Denunciation, denunciation::DenunciationPrecursor,
-    endorsement::EndorsementId, operation::OperationId, slot::Slot,
+    endorsement::EndorsementId, operation::OperationId, prehash::PreHashSet, slot::Slot,
 };
 use massa_pool_exports::{PoolConfig, PoolController, PoolManager};
 use massa_storage::Storage;
 use parking_lot::RwLock;
-use std::sync::mpsc::TrySendError;
+use std::sync::mpsc::{sync_channel, TrySendError};
 use std::sync::{mpsc::SyncSender, Arc};
 use tracing::{info, warn};
 
@@ -23,6 +23,8 @@ use crate::{
 pub enum Command {
     /// Add items to the pool
     AddItems(Storage),
+    /// Add operations to the pool and send back the ids of the accepted ones
+    AddOperationsSync(Storage, SyncSender<PreHashSet<OperationId>>),
     /// Add denunciation precursor to the pool
     AddDenunciationPrecursor(DenunciationPrecursor),
     /// Notify of new final consensus periods
@@ -69,6 +71,25 @@ impl PoolController for PoolControllerImpl {
         }
     }
 
+    /// Synchronously add operations to pool through the pool worker.
+    /// Returns the ids of the given operations that are in the pool after the call,
+    /// the other ones were rejected. Returns an empty set if the worker is unreachable.
+    fn add_operations_sync(&mut self, ops: Storage) -> PreHashSet<OperationId> {
+        let (response_tx, response_rx) = sync_channel(1);
+        if self
+            .operations_input_sender
+            .send(Command::AddOperationsSync(ops, response_tx))
+            .is_err()
+        {
+            warn!("Could not add operations to pool: worker is unreachable.");
+            return PreHashSet::default();
+        }
+        response_rx.recv().unwrap_or_else(|_| {
+            warn!("Could not add operations to pool: worker did not respond.");
+            PreHashSet::default()
+        })
+    }
+
     /// Asynchronously add endorsements to pool. Simply print a warning on failure.
     fn add_endorsements(&mut self, endorsements: Storage) {
         // Send endorsements to the denunciation pool - so we got unfiltered endorsements
@@ -204,6 +225,16 @@ impl PoolController for PoolControllerImpl {
         self.operation_pool.read().len()
     }
 
+    /// Get the number of operations in the pool for each thread
+    fn get_operation_count_per_thread(&self) -> Vec<usize> {
+        self.operation_pool.read().len_per_thread()
+    }
+
+    /// Get the ids of at most `limit` operations of the pool, by decreasing priority
+    fn get_operation_ids(&self, limit: usize) -> Vec<OperationId> {
+        self.operation_pool.read().get_operation_ids(limit)
+    }
+
     /// Check if the pool contains a list of endorsements. Returns one boolean per item.
     fn contains_endorsements(&self, endorsements: &[EndorsementId]) -> Vec<bool> {
         let lck = self.endorsement_pool.read();
@@ -221,6 +252,13 @@ impl PoolController for PoolControllerImpl {
         self.denunciation_pool.read().len()
     }
 
+    /// Get at most `limit` denunciations of the pool that are not executed yet, by increasing slot
+    fn get_pending_denunciations(&self, limit: usize) -> Vec<Denunciation> {
+        self.denunciation_pool
+            .read()
+            .get_pending_denunciations(limit)
+    }
+
     /// Returns a boxed clone of self.
     /// Allows cloning `Box<dyn PoolController>`,
     fn clone_box(&self) -> Box<dyn PoolController> {
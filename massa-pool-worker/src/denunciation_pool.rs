@@ -226,6 +226,26 @@ impl DenunciationPool {
         res
     }
 
+    /// get at most `limit` denunciations of the pool that are not executed yet, by increasing slot
+    pub fn get_pending_denunciations(&self, limit: usize) -> Vec<Denunciation> {
+        self.denunciations_cache
+            .iter()
+            .filter_map(|(de_idx, de_status)| match de_status {
+                DenunciationStatus::DenunciationEmitted(de)
+                    if !self
+                        .channels
+                        .execution_controller
+                        .get_denunciation_execution_status(de_idx)
+                        .0 =>
+                {
+                    Some(de.clone())
+                }
+                _ => None,
+            })
+            .take(limit)
+            .collect()
+    }
+
     /// Notify of final periods
     pub(crate) fn notify_final_cs_periods(&mut self, final_cs_periods: &[u64]) {
         // update internal final CS period counter
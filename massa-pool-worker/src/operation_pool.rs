@@ -364,6 +364,25 @@ impl OperationPool {
         self.sorted_ops.len()
     }
 
+    /// Get the number of stored elements in each thread
+    pub fn len_per_thread(&self) -> Vec<usize> {
+        let mut counts = vec![0usize; self.config.thread_count as usize];
+        for op_info in &self.sorted_ops {
+            counts[op_info.thread as usize] += 1;
+        }
+        counts
+    }
+
+    /// Get the ids of at most `limit` operations, in the order of the last scoring (highest score first).
+    /// Operations added since the last refresh come last.
+    pub fn get_operation_ids(&self, limit: usize) -> Vec<OperationId> {
+        self.sorted_ops
+            .iter()
+            .take(limit)
+            .map(|op_info| op_info.id)
+            .collect()
+    }
+
     /// Checks whether an element is stored in the pool.
     pub fn contains(&self, id: &OperationId) -> bool {
         self.storage.get_op_refs().contains(id)
@@ -381,9 +400,43 @@ impl OperationPool {
 
     /// Add a list of operations to the end of the pool.
     /// They will be cleaned up at the next refresh.
-    pub(crate) fn add_operations(&mut self, mut ops_storage: Storage) {
+    ///
+    /// Returns the ids of the given operations that are in the pool after the call.
+    /// The other ones were rejected because they use more gas or space than a block allows,
+    /// because their validity period ended before the last final period of their thread,
+    /// or because the pool was full.
+    pub(crate) fn add_operations(&mut self, mut ops_storage: Storage) -> PreHashSet<OperationId> {
         // List all the new operations
         let mut new_op_ids = ops_storage.get_op_refs() - self.storage.get_op_refs();
+        let mut accepted_op_ids = ops_storage.get_op_refs().clone();
+
+        // Compute the info of the new operations and reject the ones that can never be included in a block
+        let mut new_op_infos = Vec::with_capacity(new_op_ids.len());
+        {
+            let ops = ops_storage.read_operations();
+            new_op_ids.retain(|new_op_id| {
+                let op = ops
+                    .get(new_op_id)
+                    .expect("operation not found in storage but listed as owned");
+                let op_info = OperationInfo::from_op(
+                    op,
+                    self.config.operation_validity_periods,
+                    self.config.roll_price,
+                    self.config.thread_count,
+                );
+                let retain = op_info.max_gas <= self.config.max_block_gas
+                    && op_info.fee >= self.config.minimal_fees
+                    && op_info.size <= self.config.max_block_size as usize
+                    && *op_info.validity_period_range.end()
+                        > self.last_cs_final_periods[op_info.thread as usize];
+                if retain {
+                    new_op_infos.push(op_info);
+                } else {
+                    accepted_op_ids.remove(new_op_id);
+                }
+                retain
+            });
+        }
 
         // If there are too many extra operations,
         // we don't want the container to fill up too much in-between refreshes so we drop any excess.
@@ -395,8 +448,9 @@ impl OperationPool {
             .saturating_sub(self.config.max_operation_pool_size)
             .saturating_sub(self.config.max_operation_pool_excess_items);
         for _ in 0..dropped_items {
-            if let Some(id) = new_op_ids.iter().next().copied() {
-                new_op_ids.remove(&id);
+            if let Some(op_info) = new_op_infos.pop() {
+                new_op_ids.remove(&op_info.id);
+                accepted_op_ids.remove(&op_info.id);
             } else {
                 break;
             }
@@ -414,24 +468,18 @@ impl OperationPool {
         // because in that case we don't know anything about their quality.
         {
             let ops = ops_storage.read_operations();
-            for new_op_id in &new_op_ids {
-                let op = ops
-                    .get(new_op_id)
-                    .expect("operation not found in storage but listed as owned");
-
+            for op_info in new_op_infos {
                 // Broadcast operations to active channel subscribers.
                 if self.config.broadcast_enabled {
+                    let op = ops
+                        .get(&op_info.id)
+                        .expect("operation not found in storage but listed as owned");
                     if let Err(err) = self.channels.broadcasts.operation_sender.send(op.clone()) {
                         trace!("error, failed to broadcast operations {}: {}", op.id, err);
                     }
                 }
 
-                self.sorted_ops.push(OperationInfo::from_op(
-                    op,
-                    self.config.operation_validity_periods,
-                    self.config.roll_price,
-                    self.config.thread_count,
-                ));
+                self.sorted_ops.push(op_info);
             }
         }
 
@@ -446,6 +494,8 @@ impl OperationPool {
             &new_op_ids,
             &Default::default(),
         ));
+
+        accepted_op_ids
     }
 
     /// get operations for block creation
@@ -7,11 +7,24 @@
 //! Function: [`test_add_operation`]
 //! Classic usage of internal `add_operations` function from the [`OperationPool`].
 //!
+//! # List operation ids
+//! Function: [`test_get_operation_ids`]
+//! Same as classic, listing the ids of the added operations with a limit.
+//!
+//! # Count operations per thread
+//! Function: [`test_get_operation_count_per_thread`]
+//! Same as classic, checking that each operation is counted in the thread of its creator.
+//!
 //! # Add irrelevant operation
 //! Function: [`test_add_irrelevant_operation`]
 //! Same as classic but we try to add irrelevant operation. (See the definition
 //! chapter below)
 //!
+//! # Add operations synchronously
+//! Function: [`test_add_operations_sync_reports_rejected`]
+//! Same as irrelevant but through `add_operations_sync`, checking that only the
+//! relevant operations are reported as accepted.
+//!
 //! # Definition
 //! Relevant operation: Operation with a validity range corresponding to the
 //! latest period given his own thread. All operation which doesn't fit these
@@ -20,9 +33,13 @@
 use crate::tests::tools::OpGenerator;
 
 use super::tools::{
-    create_some_operations, default_mock_execution_controller, pool_test, PoolTestBoilerPlate,
+    create_some_operations, default_mock_execution_controller, default_mock_selector_controller,
+    pool_test, PoolTestBoilerPlate,
+};
+use massa_models::{
+    amount::Amount, config::ENDORSEMENT_COUNT, operation::OperationId, prehash::PreHashSet,
+    slot::Slot,
 };
-use massa_models::{amount::Amount, config::ENDORSEMENT_COUNT, operation::OperationId, slot::Slot};
 use massa_pool_exports::PoolConfig;
 use massa_pos_exports::{MockSelectorController, Selection};
 use std::{collections::BTreeMap, time::Duration};
@@ -79,6 +96,58 @@ fn test_add_operation() {
     );
 }
 
+/// Test that the ids of the operations of the pool are listed, up to the given limit.
+#[test]
+fn test_get_operation_ids() {
+    pool_test(
+        PoolConfig::default(),
+        default_mock_execution_controller(),
+        default_mock_selector_controller(),
+        None,
+        |mut operation_pool, mut storage| {
+            let op_gen = OpGenerator::default().expirery(2);
+            let operations = create_some_operations(10, &op_gen);
+            let ids: PreHashSet<OperationId> = operations.iter().map(|op| op.id).collect();
+            storage.store_operations(operations);
+            operation_pool.add_operations(storage);
+            // Allow some time for the pool to add the operations
+            std::thread::sleep(Duration::from_secs(3));
+            let listed = operation_pool.get_operation_ids(4);
+            assert_eq!(listed.len(), 4);
+            assert!(listed.iter().all(|id| ids.contains(id)));
+            let listed: PreHashSet<OperationId> =
+                operation_pool.get_operation_ids(20).into_iter().collect();
+            assert_eq!(listed, ids);
+        },
+    );
+}
+
+/// Test that the operations of the pool are counted in their thread.
+#[test]
+fn test_get_operation_count_per_thread() {
+    let pool_config = PoolConfig::default();
+    let thread_count = pool_config.thread_count;
+    pool_test(
+        pool_config,
+        default_mock_execution_controller(),
+        default_mock_selector_controller(),
+        None,
+        |mut operation_pool, mut storage| {
+            let op_gen = OpGenerator::default().expirery(2);
+            let operations = create_some_operations(10, &op_gen);
+            let mut expected = vec![0; thread_count as usize];
+            for op in &operations {
+                expected[op.content_creator_address.get_thread(thread_count) as usize] += 1;
+            }
+            storage.store_operations(operations);
+            operation_pool.add_operations(storage);
+            // Allow some time for the pool to add the operations
+            std::thread::sleep(Duration::from_secs(3));
+            assert_eq!(operation_pool.get_operation_count_per_thread(), expected);
+        },
+    );
+}
+
 /// Test if adding irrelevant operations make simply skip the add.
 /// # Initialization
 #[test]
@@ -136,6 +205,103 @@ fn test_add_irrelevant_operation() {
     );
 }
 
+/// Test that synchronously added operations report which ones were accepted.
+#[test]
+fn test_add_operations_sync_reports_rejected() {
+    let pool_config = PoolConfig::default();
+    let thread_count = pool_config.thread_count;
+    let execution_controller = default_mock_execution_controller();
+    let selector_controller = {
+        let mut res = Box::new(MockSelectorController::new());
+        res.expect_clone_box().times(2).returning(|| {
+            let mut story = MockSelectorController::new();
+            story
+                .expect_get_available_selections_in_range()
+                .returning(|slot_range, opt_addrs| {
+                    let mut all_slots = BTreeMap::new();
+                    let addr = *opt_addrs
+                        .expect("No addresses filter given")
+                        .iter()
+                        .next()
+                        .expect("No addresses given");
+                    for i in 0..15 {
+                        for j in 0..32 {
+                            let s = Slot::new(i, j);
+                            if slot_range.contains(&s) {
+                                all_slots.insert(
+                                    s,
+                                    Selection {
+                                        producer: addr,
+                                        endorsements: vec![addr; ENDORSEMENT_COUNT as usize],
+                                    },
+                                );
+                            }
+                        }
+                    }
+                    Ok(all_slots)
+                });
+            Box::new(story)
+        });
+        res
+    };
+    pool_test(
+        pool_config,
+        execution_controller,
+        selector_controller,
+        None,
+        |mut operation_pool, mut storage| {
+            let expired_ops = create_some_operations(5, &OpGenerator::default().expirery(2));
+            let valid_ops = create_some_operations(5, &OpGenerator::default().expirery(12));
+            storage.store_operations(expired_ops.clone());
+            storage.store_operations(valid_ops.clone());
+            operation_pool.notify_final_cs_periods(&vec![5; thread_count.into()]);
+            let accepted = operation_pool.add_operations_sync(storage);
+            assert_eq!(accepted.len(), valid_ops.len());
+            assert!(valid_ops.iter().all(|op| accepted.contains(&op.id)));
+            assert!(expired_ops.iter().all(|op| !accepted.contains(&op.id)));
+            assert_eq!(operation_pool.get_operation_count(), valid_ops.len());
+        },
+    );
+}
+
+/// Test that operations paying less than the minimal fees are rejected.
+#[test]
+fn test_add_operations_below_minimal_fees() {
+    let pool_config = PoolConfig {
+        minimal_fees: Amount::from_raw(1000),
+        ..Default::default()
+    };
+    let thread_count = pool_config.thread_count;
+    pool_test(
+        pool_config,
+        default_mock_execution_controller(),
+        default_mock_selector_controller(),
+        None,
+        |mut operation_pool, mut storage| {
+            let cheap_ops = create_some_operations(
+                5,
+                &OpGenerator::default()
+                    .expirery(12)
+                    .fee(Amount::from_raw(999)),
+            );
+            let paying_ops = create_some_operations(
+                5,
+                &OpGenerator::default()
+                    .expirery(12)
+                    .fee(Amount::from_raw(1000)),
+            );
+            storage.store_operations(cheap_ops.clone());
+            storage.store_operations(paying_ops.clone());
+            operation_pool.notify_final_cs_periods(&vec![5; thread_count.into()]);
+            let accepted = operation_pool.add_operations_sync(storage);
+            assert_eq!(accepted.len(), paying_ops.len());
+            assert!(paying_ops.iter().all(|op| accepted.contains(&op.id)));
+            assert!(cheap_ops.iter().all(|op| !accepted.contains(&op.id)));
+            assert_eq!(operation_pool.get_operation_count(), paying_ops.len());
+        },
+    );
+}
+
 #[test]
 fn test_pool() {
     let pool_config = PoolConfig {
@@ -1,5 +1,6 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::start_pool_controller;
@@ -10,6 +11,7 @@ use massa_models::{
     address::Address,
     amount::Amount,
     block_id::BlockId,
+    config::ENDORSEMENT_COUNT,
     endorsement::{Endorsement, EndorsementSerializer, SecureShareEndorsement},
     operation::{Operation, OperationSerializer, OperationType, SecureShareOperation},
     prehash::PreHashMap,
@@ -17,7 +19,7 @@ use massa_models::{
     slot::Slot,
 };
 use massa_pool_exports::{PoolBroadcasts, PoolChannels, PoolConfig, PoolController, PoolManager};
-use massa_pos_exports::MockSelectorController as AutoMockSelectorController;
+use massa_pos_exports::{MockSelectorController as AutoMockSelectorController, Selection};
 use massa_signature::KeyPair;
 use massa_storage::Storage;
 use massa_wallet::test_exports::create_test_wallet;
@@ -211,3 +213,39 @@ pub fn default_mock_execution_controller() -> Box<MockExecutionController> {
     });
     res
 }
+
+// Create a selector controller that selects the address it is asked about for every slot
+// of the first periods, so that every operation is relevant to the pool
+pub fn default_mock_selector_controller() -> Box<AutoMockSelectorController> {
+    let mut res = Box::new(AutoMockSelectorController::new());
+    res.expect_clone_box().times(2).returning(|| {
+        let mut story = AutoMockSelectorController::new();
+        story
+            .expect_get_available_selections_in_range()
+            .returning(|slot_range, opt_addrs| {
+                let mut all_slots = BTreeMap::new();
+                let addr = *opt_addrs
+                    .expect("No addresses filter given")
+                    .iter()
+                    .next()
+                    .expect("No addresses given");
+                for i in 0..15 {
+                    for j in 0..32 {
+                        let s = Slot::new(i, j);
+                        if slot_range.contains(&s) {
+                            all_slots.insert(
+                                s,
+                                Selection {
+                                    producer: addr,
+                                    endorsements: vec![addr; ENDORSEMENT_COUNT as usize],
+                                },
+                            );
+                        }
+                    }
+                }
+                Ok(all_slots)
+            });
+        Box::new(story)
+    });
+    res
+}
@@ -107,7 +107,11 @@ impl OperationPoolThread {
                 match self.receiver.recv_timeout(duration) {
                     Err(RecvTimeoutError::Disconnected) | Ok(Command::Stop) => break,
                     Ok(Command::AddItems(operations)) => {
-                        self.operation_pool.write().add_operations(operations)
+                        self.operation_pool.write().add_operations(operations);
+                    }
+                    Ok(Command::AddOperationsSync(operations, response_tx)) => {
+                        let accepted = self.operation_pool.write().add_operations(operations);
+                        let _ = response_tx.send(accepted);
                     }
                     Ok(Command::NotifyFinalCsPeriods(final_cs_periods)) => self
                         .operation_pool
@@ -175,6 +179,9 @@ impl DenunciationPoolThread {
                     .denunciation_pool
                     .write()
                     .notify_final_cs_periods(&final_cs_periods),
+                Ok(Command::AddOperationsSync(..)) => {
+                    warn!("DenunciationPoolThread received an unexpected command");
+                }
             };
         }
     }
@@ -51,6 +51,10 @@ pub trait SelectorController: Send + Sync {
     /// Get [Address] of the selected block producer for a given slot
     fn get_producer(&self, slot: Slot) -> PosResult<Address>;
 
+    /// Get the look back seed the draws of a cycle were performed with.
+    /// Fails with `CycleUnavailable` if the draws of the cycle are not in the selector history.
+    fn get_cycle_draw_seed(&self, cycle: u64) -> PosResult<Hash>;
+
     /// Get selections computed for a slot range (only returns available selections):
     /// # Arguments
     /// * `slot_range`: range of slots to get the selection for
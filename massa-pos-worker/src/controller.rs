@@ -109,6 +109,17 @@ impl SelectorController for SelectorControllerImpl {
         self.get_selection(slot).map(|selection| selection.producer)
     }
 
+    /// Get the look back seed the draws of a cycle were performed with
+    fn get_cycle_draw_seed(&self, cycle: u64) -> PosResult<Hash> {
+        let (_cache_cv, cache_lock) = &*self.cache;
+        let cache_guard = cache_lock.read();
+        let cache = cache_guard.as_ref().map_err(|err| err.clone())?;
+        cache
+            .get(cycle)
+            .map(|cycle_draws| cycle_draws.seed)
+            .ok_or(PosError::CycleUnavailable(cycle))
+    }
+
     /// Get selections computed for a slot range (only lists available selections):
     /// # Arguments
     /// * `slot_range`: target slot of the selection (from included, to included)
@@ -54,6 +54,7 @@ pub(crate) fn perform_draws(
         })?;
     let mut cycle_draws = CycleDraws {
         cycle,
+        seed: lookback_seed,
         draws: HashMap::with_capacity(
             (cfg.periods_per_cycle as usize) * (cfg.thread_count as usize),
         ),
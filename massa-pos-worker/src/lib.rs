@@ -78,6 +78,8 @@ impl DrawCache {
 pub(crate) struct CycleDraws {
     /// cycle number
     pub cycle: u64,
+    /// look back seed the draws were performed with
+    pub seed: Hash,
     /// cache of draws
     pub draws: HashMap<Slot, Selection>,
 }
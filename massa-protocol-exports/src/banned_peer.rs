@@ -0,0 +1,18 @@
+use std::net::IpAddr;
+
+use massa_time::MassaTime;
+
+use crate::PeerId;
+
+/// A peer currently banned by the node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BannedPeer {
+    /// id of the banned peer
+    pub peer_id: PeerId,
+    /// IPs the peer last announced it listens on
+    pub ips: Vec<IpAddr>,
+    /// time at which the peer was banned, if known
+    pub banned_at: Option<MassaTime>,
+    /// why the peer was banned, if known
+    pub reason: Option<String>,
+}
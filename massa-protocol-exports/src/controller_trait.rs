@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use std::net::SocketAddr;
 
 use crate::error::ProtocolError;
+use crate::BannedPeer;
 use crate::BootstrapPeers;
 
 use crate::PeerId;
@@ -78,6 +79,9 @@ pub trait ProtocolController: Send + Sync {
     /// Unban a list of Peer Id
     fn unban_peers(&self, peer_ids: Vec<PeerId>) -> Result<(), ProtocolError>;
 
+    /// Get the peers currently banned, with the time and reason of their ban
+    fn get_banned_peers(&self) -> Result<Vec<BannedPeer>, ProtocolError>;
+
     /// Returns a boxed clone of self.
     /// Useful to allow cloning `Box<dyn ProtocolController>`.
     fn clone_box(&self) -> Box<dyn ProtocolController>;
@@ -1,9 +1,11 @@
+mod banned_peer;
 mod bootstrap_peers;
 mod controller_trait;
 mod error;
 mod peer_id;
 mod settings;
 
+pub use banned_peer::BannedPeer;
 pub use bootstrap_peers::{
     BootstrapPeers, BootstrapPeersDeserializer, BootstrapPeersSerializer, PeerData,
 };
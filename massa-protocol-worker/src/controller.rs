@@ -7,7 +7,9 @@ use massa_models::{
     prehash::{PreHashMap, PreHashSet},
     stats::NetworkStats,
 };
-use massa_protocol_exports::{BootstrapPeers, PeerId, ProtocolController, ProtocolError};
+use massa_protocol_exports::{
+    BannedPeer, BootstrapPeers, PeerId, ProtocolController, ProtocolError,
+};
 use massa_storage::Storage;
 use peernet::peer::PeerConnectionType;
 
@@ -160,7 +162,10 @@ impl ProtocolController for ProtocolControllerImpl {
         self.sender_peer_management_thread
             .as_ref()
             .unwrap()
-            .try_send(PeerManagementCmd::Ban(peer_ids))
+            .try_send(PeerManagementCmd::Ban(
+                peer_ids,
+                "banned by the node operator".to_string(),
+            ))
             .map_err(|_| ProtocolError::ChannelError("ban_peers command send error".into()))
     }
 
@@ -172,6 +177,20 @@ impl ProtocolController for ProtocolControllerImpl {
             .map_err(|_| ProtocolError::ChannelError("unban_peers command send error".into()))
     }
 
+    fn get_banned_peers(&self) -> Result<Vec<BannedPeer>, ProtocolError> {
+        let (sender, receiver) = MassaChannel::new("get_banned_peers".to_string(), Some(1));
+        self.sender_peer_management_thread
+            .as_ref()
+            .unwrap()
+            .try_send(PeerManagementCmd::GetBannedPeers { responder: sender })
+            .map_err(|_| {
+                ProtocolError::ChannelError("get_banned_peers command send error".into())
+            })?;
+        receiver.recv_timeout(Duration::from_secs(10)).map_err(|_| {
+            ProtocolError::ChannelError("get_banned_peers command receive error".into())
+        })
+    }
+
     fn get_bootstrap_peers(&self) -> Result<BootstrapPeers, ProtocolError> {
         let (sender, receiver) = MassaChannel::new("get_bootstrap_peers".to_string(), Some(1));
         self.sender_peer_management_thread
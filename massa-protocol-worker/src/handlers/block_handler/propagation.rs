@@ -122,7 +122,7 @@ impl PropagationThread {
                                     }
                                 })
                                 .collect();
-                            self.ban_peers(&peers_to_ban);
+                            self.ban_peers(&peers_to_ban, "knows an attack block");
                         }
                         BlockHandlerPropagationCommand::Stop => {
                             info!("Stop block propagation thread");
@@ -203,10 +203,13 @@ impl PropagationThread {
     }
 
     /// try to ban a list of peers
-    fn ban_peers(&mut self, peer_ids: &[PeerId]) {
+    fn ban_peers(&mut self, peer_ids: &[PeerId], reason: &str) {
         if let Err(err) = self
             .peer_cmd_sender
-            .try_send(PeerManagementCmd::Ban(peer_ids.to_vec()))
+            .try_send(PeerManagementCmd::Ban(
+                peer_ids.to_vec(),
+                reason.to_string(),
+            ))
             .map_err(|err| ProtocolError::SendError(err.to_string()))
         {
             warn!("could not send Ban command to peer manager: {}", err);
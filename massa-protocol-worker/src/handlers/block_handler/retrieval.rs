@@ -430,7 +430,7 @@ impl RetrievalThread {
                     "peer {} sent us critically incorrect header: {}",
                     &from_peer_id, err
                 );
-                if let Err(err) = self.ban_peers(&[from_peer_id]) {
+                if let Err(err) = self.ban_peers(&[from_peer_id], "sent an invalid block header") {
                     warn!("Error while banning peer {} err: {:?}", &from_peer_id, err);
                 }
                 return;
@@ -654,9 +654,12 @@ impl RetrievalThread {
     }
 
     /// send a ban peer command to the peer handler
-    fn ban_peers(&mut self, peer_ids: &[PeerId]) -> Result<(), ProtocolError> {
+    fn ban_peers(&mut self, peer_ids: &[PeerId], reason: &str) -> Result<(), ProtocolError> {
         self.peer_cmd_sender
-            .try_send(PeerManagementCmd::Ban(peer_ids.to_vec()))
+            .try_send(PeerManagementCmd::Ban(
+                peer_ids.to_vec(),
+                reason.to_string(),
+            ))
             .map_err(|err| ProtocolError::SendError(err.to_string()))
     }
 
@@ -691,7 +694,7 @@ impl RetrievalThread {
             }
         }
         if !peers_to_ban.is_empty() {
-            if let Err(err) = self.ban_peers(&peers_to_ban) {
+            if let Err(err) = self.ban_peers(&peers_to_ban, "knows an invalid block") {
                 warn!(
                     "Error while banning peers {:?} err: {:?}",
                     peers_to_ban, err
@@ -762,7 +765,9 @@ impl RetrievalThread {
             != computed_operations_hash
         {
             warn!("Peer id {} sent us a operation list for block id {} but the hash in the header doesn't match.", from_peer_id, block_id);
-            if let Err(err) = self.ban_peers(&[from_peer_id]) {
+            if let Err(err) =
+                self.ban_peers(&[from_peer_id], "sent a mismatching block operation list")
+            {
                 warn!("Error while banning peer {} err: {:?}", from_peer_id, err);
             }
             return;
@@ -887,7 +892,7 @@ impl RetrievalThread {
                 "Peer id {} sent us operations for block id {} but they failed validity checks: {}",
                 from_peer_id, block_id, err
             );
-            if let Err(err) = self.ban_peers(&[from_peer_id]) {
+            if let Err(err) = self.ban_peers(&[from_peer_id], "sent invalid block operations") {
                 warn!("Error while banning peer {} err: {:?}", from_peer_id, err);
             }
             return;
@@ -134,7 +134,7 @@ impl RetrievalThread {
                         loss of sync between us and the remote node. Err = {}",
                         peer_id, err
                     );
-                    if let Err(err) = self.ban_peer(&peer_id) {
+                    if let Err(err) = self.ban_peer(&peer_id, "sent invalid endorsements") {
                         warn!("Error while banning peer {} err: {:?}", peer_id, err);
                     }
                 }
@@ -143,10 +143,10 @@ impl RetrievalThread {
     }
 
     /// send a ban peer command to the peer handler
-    fn ban_peer(&mut self, peer_id: &PeerId) -> Result<(), ProtocolError> {
+    fn ban_peer(&mut self, peer_id: &PeerId, reason: &str) -> Result<(), ProtocolError> {
         massa_trace!("ban node from retrieval thread", { "peer_id": peer_id.to_string() });
         self.peer_cmd_sender
-            .try_send(PeerManagementCmd::Ban(vec![*peer_id]))
+            .try_send(PeerManagementCmd::Ban(vec![*peer_id], reason.to_string()))
             .map_err(|err| ProtocolError::SendError(err.to_string()))
     }
 }
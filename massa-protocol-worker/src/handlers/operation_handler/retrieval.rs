@@ -110,7 +110,7 @@ impl RetrievalThread {
                                     ) {
                                         warn!("peer {} sent us critically incorrect operation, which may be an attack attempt by the remote peer or a loss of sync between us and the remote peer. Err = {}", peer_id, err);
 
-                                        if let Err(e) = self.ban_node(&peer_id) {
+                                        if let Err(e) = self.ban_node(&peer_id, "sent invalid operations") {
                                             warn!("Error when banning node: {}", e);
                                         }
                                     }
@@ -351,10 +351,10 @@ impl RetrievalThread {
     }
 
     /// send a ban peer command to the peer handler
-    fn ban_node(&mut self, peer_id: &PeerId) -> Result<(), ProtocolError> {
+    fn ban_node(&mut self, peer_id: &PeerId, reason: &str) -> Result<(), ProtocolError> {
         massa_trace!("ban node from retrieval thread", { "peer_id": peer_id.to_string() });
         self.peer_cmd_sender
-            .try_send(PeerManagementCmd::Ban(vec![*peer_id]))
+            .try_send(PeerManagementCmd::Ban(vec![*peer_id], reason.to_string()))
             .map_err(|err| ProtocolError::SendError(err.to_string()))
     }
 }
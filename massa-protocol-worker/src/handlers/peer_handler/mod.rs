@@ -132,19 +132,25 @@ impl PeerManagementHandler {
                             receiver_cmd.update_metrics();
                             // internal command
                            match cmd {
-                             Ok(PeerManagementCmd::Ban(peer_ids)) => {
+                             Ok(PeerManagementCmd::Ban(peer_ids, reason)) => {
                                 // remove running handshake ?
                                 for peer_id in peer_ids {
                                     active_connections.shutdown_connection(&peer_id);
 
                                     // update peer_db
-                                    peer_db.write().ban_peer(&peer_id);
+                                    peer_db.write().ban_peer(&peer_id, &reason);
                                 }
                             },
                              Ok(PeerManagementCmd::Unban(peer_ids)) => {
                                 for peer_id in peer_ids {
                                     peer_db.write().unban_peer(&peer_id);
                                 }
+                            },
+                             Ok(PeerManagementCmd::GetBannedPeers { responder }) => {
+                                let banned_peers = peer_db.read().get_banned_peers();
+                                if let Err(err) = responder.try_send(banned_peers) {
+                                    warn!("error sending banned peers: {:?}", err);
+                                }
                             },
                              Ok(PeerManagementCmd::GetBootstrapPeers { responder }) => {
                                 let mut peers = peer_db.read().get_rand_peers_to_send(100);
@@ -1,14 +1,18 @@
 use massa_channel::sender::MassaSender;
-use massa_protocol_exports::{BootstrapPeers, PeerId};
+use massa_protocol_exports::{BannedPeer, BootstrapPeers, PeerId};
 use massa_time::MassaTime;
 use parking_lot::RwLock;
 use peernet::transports::TransportType;
 use rand::seq::SliceRandom;
 use rand::{thread_rng, Rng};
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::time::Duration;
-use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
 use tracing::log::info;
 
 use crate::wrap_peer_db::PeerDBTrait;
@@ -156,6 +160,8 @@ pub struct PeerDB {
     pub try_connect_history: HashMap<SocketAddr, ConnectionMetadata>,
     /// peers currently tested
     pub peers_in_test: HashSet<SocketAddr>,
+    /// time and reason of the last ban of peers. A peer is only banned while its state is `PeerState::Banned`
+    pub bans: HashMap<PeerId, (MassaTime, String)>,
 }
 
 pub type SharedPeerDB = Arc<RwLock<dyn PeerDBTrait>>;
@@ -179,11 +185,15 @@ pub enum PeerState {
 
 #[derive(Clone)]
 pub enum PeerManagementCmd {
-    Ban(Vec<PeerId>),
+    /// ban peers, with the reason of the ban
+    Ban(Vec<PeerId>, String),
     Unban(Vec<PeerId>),
     GetBootstrapPeers {
         responder: MassaSender<BootstrapPeers>,
     },
+    GetBannedPeers {
+        responder: MassaSender<Vec<BannedPeer>>,
+    },
     Stop,
 }
 
@@ -193,10 +203,19 @@ pub struct PeerManagementChannel {
 }
 
 impl PeerDBTrait for PeerDB {
-    fn ban_peer(&mut self, peer_id: &PeerId) {
+    fn ban_peer(&mut self, peer_id: &PeerId, reason: &str) {
         if let Some(peer) = self.peers.get_mut(peer_id) {
             peer.state = PeerState::Banned;
-            info!("Banned peer: {:?}", peer_id);
+            let now = MassaTime::now().expect("Unable to get MassaTime::now");
+            self.bans.insert(*peer_id, (now, reason.to_string()));
+            // forget the bans of the peers that left the banned state since
+            let peers = &self.peers;
+            self.bans.retain(|peer_id, _| {
+                peers
+                    .get(peer_id)
+                    .map_or(false, |peer| peer.state == PeerState::Banned)
+            });
+            info!("Banned peer: {:?} ({})", peer_id, reason);
         } else {
             info!("Tried to ban unknown peer: {:?}", peer_id);
         };
@@ -206,6 +225,7 @@ impl PeerDBTrait for PeerDB {
         if let Some(peer) = self.peers.get_mut(peer_id) {
             // We set the state to HandshakeFailed to force the peer to be tested again
             peer.state = PeerState::HandshakeFailed;
+            self.bans.remove(peer_id);
             info!("Unbanned peer: {:?}", peer_id);
         } else {
             info!("Tried to unban unknown peer: {:?}", peer_id);
@@ -291,6 +311,31 @@ impl PeerDBTrait for PeerDB {
             .count() as u64
     }
 
+    fn get_banned_peers(&self) -> Vec<BannedPeer> {
+        // the state of the peers is the reference, like in `get_banned_peer_count`
+        self.peers
+            .iter()
+            .filter(|(_, peer)| peer.state == PeerState::Banned)
+            .map(|(peer_id, peer)| {
+                let ban = self.bans.get(peer_id);
+                BannedPeer {
+                    peer_id: *peer_id,
+                    ips: peer
+                        .last_announce
+                        .as_ref()
+                        .map(|announce| {
+                            let ips: BTreeSet<IpAddr> =
+                                announce.listeners.keys().map(|addr| addr.ip()).collect();
+                            ips.into_iter().collect()
+                        })
+                        .unwrap_or_default(),
+                    banned_at: ban.map(|(banned_at, _)| *banned_at),
+                    reason: ban.map(|(_, reason)| reason.clone()),
+                }
+            })
+            .collect()
+    }
+
     fn get_known_peer_count(&self) -> u64 {
         self.peers.len() as u64
     }
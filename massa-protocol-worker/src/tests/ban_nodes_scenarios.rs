@@ -14,9 +14,10 @@ use massa_test_framework::{Breakpoint, TestUniverse};
 use massa_time::MassaTime;
 use mockall::predicate;
 
-use crate::handlers::peer_handler::models::{PeerInfo, PeerState};
+use crate::handlers::peer_handler::models::{PeerDB, PeerInfo, PeerState};
 use crate::wrap_network::ActiveConnectionsTrait;
 use crate::wrap_network::MockActiveConnectionsTraitWrapper;
+use crate::wrap_peer_db::PeerDBTrait;
 use crate::{
     handlers::{
         block_handler::{BlockInfoReply, BlockMessage},
@@ -39,7 +40,11 @@ fn test_protocol_bans_node_sending_block_header_with_invalid_signature() {
     let mut foreign_controllers = ProtocolForeignControllers::new_with_mocks();
 
     let block_creator = KeyPair::generate(0).unwrap();
-    let block = ProtocolTestUniverse::create_block(&block_creator);
+    let block = ProtocolTestUniverse::create_block_with_thread_count(
+        &block_creator,
+        protocol_config.thread_count,
+    );
+    ProtocolTestUniverse::assert_block_valid(&block, protocol_config.thread_count);
     let mut block_bad_public_key = block.clone();
     block_bad_public_key.content.header.content_creator_pub_key =
         KeyPair::generate(0).unwrap().get_public_key();
@@ -71,7 +76,7 @@ fn test_protocol_bans_node_sending_block_header_with_invalid_signature() {
         .peer_db
         .write()
         .expect_ban_peer()
-        .returning(move |peer_id| {
+        .returning(move |peer_id, _reason| {
             assert_eq!(peer_id, &node_a_peer_id);
             ban_breakpoint_trigger_handle.trigger();
         });
@@ -494,3 +499,70 @@ fn test_protocol_bans_all_nodes_propagating_an_attack_attempt() {
         },
     )
 }
+
+#[test]
+fn test_peer_db_lists_banned_peers_with_reason() {
+    let known_peer_id = PeerId::from_public_key(KeyPair::generate(0).unwrap().get_public_key());
+    let unknown_peer_id = PeerId::from_public_key(KeyPair::generate(0).unwrap().get_public_key());
+    let mut peer_db = PeerDB::default();
+    peer_db.peers.insert(
+        known_peer_id,
+        PeerInfo {
+            last_announce: None,
+            state: PeerState::Trusted,
+        },
+    );
+
+    let before_ban = MassaTime::now().unwrap();
+    peer_db.ban_peer(&known_peer_id, "sent invalid operations");
+    // only known peers can be banned
+    peer_db.ban_peer(&unknown_peer_id, "sent invalid operations");
+
+    let banned_peers = peer_db.get_banned_peers();
+    assert_eq!(banned_peers.len(), 1);
+    assert_eq!(banned_peers[0].peer_id, known_peer_id);
+    assert_eq!(
+        banned_peers[0].reason.as_deref(),
+        Some("sent invalid operations")
+    );
+    assert!(banned_peers[0].banned_at.unwrap() >= before_ban);
+    assert!(banned_peers[0].ips.is_empty());
+    assert_eq!(peer_db.get_banned_peer_count(), 1);
+
+    peer_db.unban_peer(&known_peer_id);
+    assert!(peer_db.get_banned_peers().is_empty());
+}
+
+#[test]
+fn test_peer_db_lists_banned_peers_from_their_state() {
+    let rehandshaked_peer_id =
+        PeerId::from_public_key(KeyPair::generate(0).unwrap().get_public_key());
+    let banned_peer_id = PeerId::from_public_key(KeyPair::generate(0).unwrap().get_public_key());
+    let mut peer_db = PeerDB::default();
+    peer_db.peers.insert(
+        rehandshaked_peer_id,
+        PeerInfo {
+            last_announce: None,
+            state: PeerState::Trusted,
+        },
+    );
+    // a peer put in the banned state without a recorded ban
+    peer_db.peers.insert(
+        banned_peer_id,
+        PeerInfo {
+            last_announce: None,
+            state: PeerState::Banned,
+        },
+    );
+
+    // a banned peer going through a handshake again is no longer banned
+    peer_db.ban_peer(&rehandshaked_peer_id, "sent invalid operations");
+    peer_db.peers.get_mut(&rehandshaked_peer_id).unwrap().state = PeerState::InHandshake;
+
+    let banned_peers = peer_db.get_banned_peers();
+    assert_eq!(banned_peers.len(), 1);
+    assert_eq!(banned_peers[0].peer_id, banned_peer_id);
+    assert_eq!(banned_peers[0].banned_at, None);
+    assert_eq!(banned_peers[0].reason, None);
+    assert_eq!(peer_db.get_banned_peer_count(), 1);
+}
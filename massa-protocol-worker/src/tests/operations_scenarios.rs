@@ -1,5 +1,6 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
 use massa_consensus_exports::MockConsensusController;
@@ -9,6 +10,7 @@ use massa_pos_exports::MockSelectorController;
 use massa_protocol_exports::PeerId;
 use massa_protocol_exports::{test_exports::tools, ProtocolConfig};
 use massa_signature::KeyPair;
+use massa_test_framework::Breakpoint;
 
 use crate::{
     handlers::{
@@ -18,7 +20,11 @@ use crate::{
     messages::Message,
 };
 
-use super::{context::protocol_test, tools::assert_hash_asked_to_node};
+use super::{
+    context::protocol_test,
+    tools::assert_hash_asked_to_node,
+    universe::{ProtocolForeignControllers, ProtocolTestUniverse},
+};
 
 #[test]
 fn test_protocol_sends_valid_operations_it_receives_to_pool() {
@@ -835,3 +841,71 @@ fn test_protocol_on_ask_operations() {
         },
     )
 }
+
+/// Foreign controllers of a universe meant to be linked to another one,
+/// with the peer database calls of the connectivity thread allowed
+fn linkable_foreign_controllers() -> ProtocolForeignControllers {
+    let foreign_controllers = ProtocolForeignControllers::new_with_mocks();
+    {
+        let mut peer_db = foreign_controllers.peer_db.write();
+        peer_db.expect_get_peers_mut().returning(HashMap::new);
+        peer_db.expect_get_peers().return_const(HashMap::new());
+        peer_db
+            .expect_get_peers_in_test()
+            .return_const(HashSet::default());
+        peer_db.expect_get_oldest_peer().return_const(None);
+        peer_db.expect_get_rand_peers_to_send().return_const(vec![]);
+    }
+    foreign_controllers
+}
+
+#[test]
+fn test_protocol_propagates_operations_between_linked_universes() {
+    let protocol_config = ProtocolConfig {
+        thread_count: 2,
+        initial_peers: "./src/tests/empty_initial_peers.json".to_string().into(),
+        ..Default::default()
+    };
+    let op_creator = KeyPair::generate(0).unwrap();
+    let operation = tools::create_operation_with_expire_period(&op_creator, 1);
+    let operation_id = operation.id;
+
+    // the operation reaches the pool of the second universe
+    let received = Breakpoint::new();
+    let received_trigger = received.get_trigger_handle();
+    let mut controllers_a = linkable_foreign_controllers();
+    controllers_a
+        .pool_controller
+        .set_expectations(|pool_controller| {
+            pool_controller.expect_add_operations().returning(|_| {});
+        });
+    let mut controllers_b = linkable_foreign_controllers();
+    controllers_b
+        .pool_controller
+        .set_expectations(move |pool_controller| {
+            let received_trigger = received_trigger.get_trigger_handle();
+            pool_controller
+                .expect_add_operations()
+                .returning(move |storage| {
+                    if storage.get_op_refs().contains(&operation_id) {
+                        received_trigger.trigger();
+                    }
+                });
+        });
+
+    let (universe_a, _universe_b, link) = ProtocolTestUniverse::new_linked(
+        (controllers_a, protocol_config.clone()),
+        (controllers_b, protocol_config),
+    );
+    assert_ne!(link.peer_id_a(), link.peer_id_b());
+
+    let mut storage = universe_a.storage.clone_without_refs();
+    storage.store_operations(vec![operation]);
+    universe_a
+        .module_controller
+        .propagate_operations(storage)
+        .unwrap();
+    received.wait();
+
+    link.disconnect();
+}
@@ -12,7 +12,13 @@ use massa_storage::Storage;
 use massa_test_framework::TestUniverse;
 use parking_lot::RwLock;
 use peernet::messages::{MessagesHandler as _, MessagesSerializer as _};
-use std::{collections::HashMap, fs::read_to_string, sync::Arc};
+use peernet::peer::PeerConnectionType;
+use std::{
+    collections::{HashMap, HashSet},
+    fs::read_to_string,
+    net::SocketAddr,
+    sync::Arc,
+};
 
 use crate::{
     connectivity::start_connectivity_thread,
@@ -25,7 +31,7 @@ use crate::{
     },
     manager::ProtocolManagerImpl,
     messages::{Message, MessagesHandler, MessagesSerializer},
-    wrap_network::{MockNetworkController, NetworkController},
+    wrap_network::{ActiveConnectionsTrait, MockNetworkController, NetworkController},
     wrap_peer_db::MockPeerDBTrait,
 };
 use massa_metrics::MassaMetrics;
@@ -96,6 +102,47 @@ impl TestUniverse for ProtocolTestUniverse {
 }
 
 impl ProtocolTestUniverse {
+    /// Start two universes whose networks are connected by an in-memory link: each universe sees
+    /// the other as its only connected peer, and the messages it sends to it are handled by the
+    /// other universe as if they were received from the network.
+    /// The network controllers must not already expect `get_active_connections`.
+    pub fn new_linked(
+        (mut controllers_a, config_a): (ProtocolForeignControllers, ProtocolConfig),
+        (mut controllers_b, config_b): (ProtocolForeignControllers, ProtocolConfig),
+    ) -> (Self, Self, UniverseLink) {
+        let peer_id_a = PeerId::from_public_key(KeyPair::generate(0).unwrap().get_public_key());
+        let peer_id_b = PeerId::from_public_key(KeyPair::generate(0).unwrap().get_public_key());
+        let end_a = Arc::new(RwLock::new(LinkEnd {
+            remote_peer_id: peer_id_b,
+            local_peer_id: peer_id_a,
+            remote_handler: None,
+        }));
+        let end_b = Arc::new(RwLock::new(LinkEnd {
+            remote_peer_id: peer_id_a,
+            local_peer_id: peer_id_b,
+            remote_handler: None,
+        }));
+        for (controllers, end) in [(&mut controllers_a, &end_a), (&mut controllers_b, &end_b)] {
+            let connections = LinkedActiveConnections(end.clone());
+            controllers
+                .network_controller
+                .expect_get_active_connections()
+                .returning(move || Box::new(connections.clone()));
+        }
+
+        let universe_a = Self::new(controllers_a, config_a);
+        let universe_b = Self::new(controllers_b, config_b);
+        end_a.write().remote_handler = Some(universe_b.messages_handler.clone());
+        end_b.write().remote_handler = Some(universe_a.messages_handler.clone());
+        (
+            universe_a,
+            universe_b,
+            UniverseLink {
+                ends: [end_a, end_b],
+            },
+        )
+    }
+
     pub fn mock_message_receive(&self, peer_id: &PeerId, message: Message) {
         let mut data = Vec::new();
         self.message_serializer
@@ -109,6 +156,132 @@ impl ProtocolTestUniverse {
     }
 }
 
+/// One side of the link between two universes
+struct LinkEnd {
+    /// peer id of the other universe, as seen by this one
+    remote_peer_id: PeerId,
+    /// peer id of this universe, as seen by the other one
+    local_peer_id: PeerId,
+    /// message handler of the other universe, `None` when disconnected
+    remote_handler: Option<MessagesHandler>,
+}
+
+/// Active connections of a linked universe, the other universe being its only peer
+#[derive(Clone)]
+struct LinkedActiveConnections(Arc<RwLock<LinkEnd>>);
+
+impl LinkedActiveConnections {
+    /// peer id of the other universe, if still connected
+    fn connected_peer(&self) -> Option<PeerId> {
+        let end = self.0.read();
+        end.remote_handler.as_ref().map(|_| end.remote_peer_id)
+    }
+}
+
+impl ActiveConnectionsTrait for LinkedActiveConnections {
+    fn send_to_peer(
+        &self,
+        peer_id: &PeerId,
+        message_serializer: &MessagesSerializer,
+        message: Message,
+        _high_priority: bool,
+    ) -> Result<(), ProtocolError> {
+        let end = self.0.read();
+        match &end.remote_handler {
+            Some(remote_handler) if *peer_id == end.remote_peer_id => {
+                let mut data = Vec::new();
+                message_serializer
+                    .serialize(&message, &mut data)
+                    .map_err(|err| ProtocolError::GeneralProtocolError(err.to_string()))?;
+                remote_handler
+                    .handle(&data, &end.local_peer_id)
+                    .map_err(|err| ProtocolError::GeneralProtocolError(err.to_string()))
+            }
+            _ => Err(ProtocolError::PeerDisconnected(peer_id.to_string())),
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn ActiveConnectionsTrait> {
+        Box::new(self.clone())
+    }
+
+    fn get_peer_ids_connected(&self) -> HashSet<PeerId> {
+        self.connected_peer().into_iter().collect()
+    }
+
+    fn get_peers_connected(
+        &self,
+    ) -> HashMap<PeerId, (SocketAddr, PeerConnectionType, Option<String>)> {
+        self.connected_peer()
+            .into_iter()
+            .map(|peer_id| {
+                (
+                    peer_id,
+                    (
+                        SocketAddr::from(([127, 0, 0, 1], 0)),
+                        PeerConnectionType::OUT,
+                        None,
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn get_peer_ids_out_connection_queue(&self) -> HashSet<SocketAddr> {
+        HashSet::new()
+    }
+
+    fn get_nb_out_connections(&self) -> usize {
+        self.connected_peer().map_or(0, |_| 1)
+    }
+
+    fn get_nb_in_connections(&self) -> usize {
+        0
+    }
+
+    fn shutdown_connection(&mut self, peer_id: &PeerId) {
+        let mut end = self.0.write();
+        if *peer_id == end.remote_peer_id {
+            end.remote_handler = None;
+        }
+    }
+
+    fn get_peers_connections_bandwidth(&self) -> HashMap<String, (u64, u64)> {
+        HashMap::new()
+    }
+}
+
+/// In-memory link between two universes, see `ProtocolTestUniverse::new_linked`.
+/// The universes are disconnected when the link is dropped.
+pub struct UniverseLink {
+    ends: [Arc<RwLock<LinkEnd>>; 2],
+}
+
+impl UniverseLink {
+    /// Peer id of the first universe, as seen by the second one
+    pub fn peer_id_a(&self) -> PeerId {
+        self.ends[0].read().local_peer_id
+    }
+
+    /// Peer id of the second universe, as seen by the first one
+    pub fn peer_id_b(&self) -> PeerId {
+        self.ends[1].read().local_peer_id
+    }
+
+    /// Cut the link: each universe no longer sees any connected peer and messages are not delivered anymore
+    pub fn disconnect(&self) {
+        for end in &self.ends {
+            end.write().remote_handler = None;
+        }
+    }
+}
+
+impl Drop for UniverseLink {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub fn start_protocol_controller_with_mock_network(
     config: ProtocolConfig,
@@ -5,11 +5,11 @@ use std::{
     time::Duration,
 };
 
-use massa_protocol_exports::{PeerId, TransportType};
+use massa_protocol_exports::{BannedPeer, PeerId, TransportType};
 
 #[cfg_attr(test, mockall::automock)]
 pub trait PeerDBTrait: Send + Sync {
-    fn ban_peer(&mut self, peer_id: &PeerId);
+    fn ban_peer(&mut self, peer_id: &PeerId, reason: &str);
     fn unban_peer(&mut self, peer_id: &PeerId);
     fn clone_box(&self) -> Box<dyn PeerDBTrait>;
     fn get_oldest_peer(
@@ -22,6 +22,7 @@ pub trait PeerDBTrait: Send + Sync {
         nb_peers: usize,
     ) -> Vec<(PeerId, HashMap<SocketAddr, TransportType>)>;
     fn get_banned_peer_count(&self) -> u64;
+    fn get_banned_peers(&self) -> Vec<BannedPeer>;
     fn get_known_peer_count(&self) -> u64;
     fn get_peers(&self) -> &HashMap<PeerId, PeerInfo>;
     fn get_peers_mut(&mut self) -> &mut HashMap<PeerId, PeerInfo>;
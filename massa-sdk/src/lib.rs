@@ -22,7 +22,7 @@ use massa_api_exports::{
     datastore::{DatastoreEntryInput, DatastoreEntryOutput},
     endorsement::EndorsementInfo,
     execution::{ExecuteReadOnlyResponse, ReadOnlyBytecodeExecution, ReadOnlyCall},
-    node::NodeStatus,
+    node::{BannedPeerInfo, NodeStatus},
     operation::{OperationInfo, OperationInput},
     TimeInterval,
 };
@@ -221,6 +221,14 @@ impl RpcClient {
             .map_err(|e| to_error_obj(e.to_string()))
     }
 
+    /// Returns the peers currently banned, with the time and reason of their ban
+    pub async fn get_banned_peers(&self) -> RpcResult<Vec<BannedPeerInfo>> {
+        self.http_client
+            .request("get_banned_peers", rpc_params![])
+            .await
+            .map_err(|e| to_error_obj(e.to_string()))
+    }
+
     /// Returns node peers whitelist IP address(es).
     pub async fn node_peers_whitelist(&self) -> RpcResult<Vec<IpAddr>> {
         self.http_client
@@ -407,11 +415,12 @@ impl RpcClient {
 
     // User (interaction with the node)
 
-    /// Adds operations to pool. Returns operations that were ok and sent to pool.
+    /// Adds operations to pool. Returns the ids of the operations that were ok and sent to pool,
+    /// each with a flag telling whether the pool accepted it.
     pub async fn send_operations(
         &self,
         operations: Vec<OperationInput>,
-    ) -> RpcResult<Vec<OperationId>> {
+    ) -> RpcResult<Vec<(OperationId, bool)>> {
         self.http_client
             .request("send_operations", rpc_params![operations])
             .await
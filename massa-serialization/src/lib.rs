@@ -33,6 +33,18 @@ pub struct DeserializeError<'a> {
     errors: VecDeque<(&'a [u8], String)>,
 }
 
+impl<'a> DeserializeError<'a> {
+    /// Input remaining when the innermost error occurred, i.e. the bytes starting where the deserialization failed
+    pub fn failed_input(&self) -> Option<&'a [u8]> {
+        self.errors.back().map(|(input, _)| *input)
+    }
+
+    /// Descriptions of the error, from the outermost context to the innermost error
+    pub fn contexts(&self) -> impl Iterator<Item = &str> {
+        self.errors.iter().map(|(_, ctx)| ctx.as_str())
+    }
+}
+
 impl<'a> ContextError<&'a [u8]> for DeserializeError<'a> {
     fn add_context(input: &'a [u8], ctx: &'static str, mut other: Self) -> Self {
         other.errors.push_front((input, ctx.to_string()));
@@ -721,5 +733,18 @@ mod tests {
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert_eq!(format!("{}", err), "Parsing Error: Ratio<_> deserializer failed / denom deser failed / Failed u64 deserialization / Fail / Input: [4]\n");
+        let nom::Err::Error(err) = err else {
+            panic!("expected a recoverable error");
+        };
+        assert_eq!(err.failed_input(), Some(&buffer[1..]));
+        assert_eq!(
+            err.contexts().collect::<Vec<_>>(),
+            vec![
+                "Ratio<_> deserializer failed",
+                "denom deser failed",
+                "Failed u64 deserialization",
+                "Fail"
+            ]
+        );
     }
 }
@@ -1,4 +1,8 @@
-use std::collections::hash_map::{self, Entry};
+use std::collections::{
+    hash_map::{self, Entry},
+    BTreeMap,
+};
+use std::ops::Bound;
 
 use massa_models::{
     address::Address,
@@ -16,6 +20,8 @@ pub struct OperationIndexes {
     index_by_creator: PreHashMap<Address, PreHashSet<OperationId>>,
     /// Structure indexing operations by ID prefix
     index_by_prefix: PreHashMap<OperationPrefixId, PreHashSet<OperationId>>,
+    /// Structure sorting operations by the string representation of their ID
+    index_by_id_string: BTreeMap<String, OperationId>,
 }
 
 impl OperationIndexes {
@@ -35,6 +41,9 @@ impl OperationIndexes {
                 .entry(operation.id.prefix())
                 .or_default()
                 .insert(operation.id);
+            // update string ID index
+            self.index_by_id_string
+                .insert(operation.id.to_string(), operation.id);
 
             massa_metrics::set_operations_counter(self.operations.len());
         }
@@ -66,6 +75,8 @@ impl OperationIndexes {
                     occ.remove();
                 }
             }
+            // update string ID index
+            self.index_by_id_string.remove(&o.id.to_string());
             return Some(o);
         }
         None
@@ -91,6 +102,22 @@ impl OperationIndexes {
         self.index_by_creator.get(address)
     }
 
+    /// Get the operations whose ID string representation starts with `prefix`
+    /// Arguments:
+    /// * `prefix`: the start of the ID string representation
+    ///
+    /// Returns:
+    /// - an iterator over the matching operations, sorted by their ID string representation
+    pub fn get_operations_by_id_string_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = &'a OperationId> + 'a {
+        self.index_by_id_string
+            .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+            .take_while(move |(id_string, _)| id_string.starts_with(prefix))
+            .map(|(_, id)| id)
+    }
+
     /// Get operations by prefix
     /// Arguments:
     /// * `prefix`: the prefix to look up
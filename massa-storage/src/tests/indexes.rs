@@ -1,6 +1,12 @@
 use crate::Storage;
 use massa_factory_exports::test_exports::create_empty_block;
-use massa_models::{address::Address, slot::Slot};
+use massa_models::{
+    address::Address,
+    amount::Amount,
+    operation::{Operation, OperationSerializer, OperationType, SecureShareOperation},
+    secure_share::SecureShareContent,
+    slot::Slot,
+};
 use massa_signature::KeyPair;
 
 #[test]
@@ -46,3 +52,57 @@ fn test_block_fail_find() {
         .get_blocks_created_by(&Address::from_public_key(&keypair2.get_public_key()))
         .is_none());
 }
+
+fn create_operation(keypair: &KeyPair, expire_period: u64) -> SecureShareOperation {
+    let content = Operation {
+        fee: Amount::default(),
+        op: OperationType::Transaction {
+            recipient_address: Address::from_public_key(&keypair.get_public_key()),
+            amount: Amount::default(),
+        },
+        expire_period,
+    };
+    Operation::new_verifiable(content, OperationSerializer::new(), keypair).unwrap()
+}
+
+#[test]
+fn test_operation_index_by_id_string_prefix() {
+    let mut storage = Storage::create_root();
+    let keypair = KeyPair::generate(0).unwrap();
+    let operations: Vec<_> = (0..5)
+        .map(|expire_period| create_operation(&keypair, expire_period))
+        .collect();
+    storage.store_operations(operations.clone());
+
+    // every operation ID starts with the same letter, they are returned sorted by ID string
+    let mut id_strings: Vec<String> = operations.iter().map(|op| op.id.to_string()).collect();
+    id_strings.sort_unstable();
+    assert_eq!(
+        storage
+            .read_operations()
+            .get_operations_by_id_string_prefix("O")
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>(),
+        id_strings
+    );
+
+    // a full ID only matches its operation
+    let id = operations[3].id;
+    assert_eq!(
+        storage
+            .read_operations()
+            .get_operations_by_id_string_prefix(&id.to_string())
+            .collect::<Vec<_>>(),
+        vec![&id]
+    );
+
+    // removed operations are not found anymore
+    storage.drop_operation_refs(&[id].into_iter().collect());
+    assert_eq!(
+        storage
+            .read_operations()
+            .get_operations_by_id_string_prefix(&id.to_string())
+            .count(),
+        0
+    );
+}
@@ -0,0 +1,79 @@
+//! Mock controllers whose fallible methods return preset errors, to test how a module handles
+//! the failures of the modules it depends on.
+//!
+//! Only the fallible methods and `clone_box` are set: like with any mock, calling another method
+//! panics unless the test adds an expectation for it on the returned controller.
+
+use massa_consensus_exports::{error::ConsensusError, MockConsensusController};
+use massa_execution_exports::{ExecutionError, MockExecutionController};
+use massa_pos_exports::{MockSelectorController, PosError};
+
+/// Consensus controller failing with the error built by `error`
+pub fn failing_consensus_controller<F>(error: F) -> MockConsensusController
+where
+    F: Fn() -> ConsensusError + Clone + Send + 'static,
+{
+    let mut controller = MockConsensusController::new();
+    let err = error.clone();
+    controller
+        .expect_get_block_graph_status()
+        .returning(move |_, _| Err(err()));
+    let err = error.clone();
+    controller
+        .expect_get_bootstrap_part()
+        .returning(move |_, _| Err(err()));
+    let err = error.clone();
+    controller.expect_get_stats().returning(move || Err(err()));
+    controller
+        .expect_clone_box()
+        .returning(move || Box::new(failing_consensus_controller(error.clone())));
+    controller
+}
+
+/// Execution controller failing with the error built by `error`
+pub fn failing_execution_controller<F>(error: F) -> MockExecutionController
+where
+    F: Fn() -> ExecutionError + Clone + Send + 'static,
+{
+    let mut controller = MockExecutionController::new();
+    let err = error.clone();
+    controller
+        .expect_execute_readonly_request()
+        .returning(move |_| Err(err()));
+    controller
+        .expect_clone_box()
+        .returning(move || Box::new(failing_execution_controller(error.clone())));
+    controller
+}
+
+/// Selector controller failing with the error built by `error`
+pub fn failing_selector_controller<F>(error: F) -> MockSelectorController
+where
+    F: Fn() -> PosError + Clone + Send + 'static,
+{
+    let mut controller = MockSelectorController::new();
+    let err = error.clone();
+    controller
+        .expect_wait_for_draws()
+        .returning(move |_| Err(err()));
+    let err = error.clone();
+    controller
+        .expect_get_selection()
+        .returning(move |_| Err(err()));
+    let err = error.clone();
+    controller
+        .expect_get_producer()
+        .returning(move |_| Err(err()));
+    let err = error.clone();
+    controller
+        .expect_get_cycle_draw_seed()
+        .returning(move |_| Err(err()));
+    let err = error.clone();
+    controller
+        .expect_get_available_selections_in_range()
+        .returning(move |_, _| Err(err()));
+    controller
+        .expect_clone_box()
+        .returning(move || Box::new(failing_selector_controller(error.clone())));
+    controller
+}
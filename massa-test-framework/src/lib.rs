@@ -1,14 +1,22 @@
-use std::sync::{Arc, Condvar, Mutex};
+pub mod controllers;
+
+use std::{
+    sync::{Arc, Condvar, Mutex},
+    time::{Duration, Instant},
+};
 
 use massa_hash::Hash;
 use massa_models::{
     block::{Block, BlockSerializer, SecureShareBlock},
     block_header::{BlockHeader, BlockHeaderSerializer},
     block_id::BlockId,
+    operation::{compute_operations_hash, OperationIdSerializer},
     secure_share::SecureShareContent,
     slot::Slot,
 };
 use massa_signature::KeyPair;
+use massa_time::{Clock, MassaTime, TimeError};
+use tokio::sync::Notify;
 use tracing_subscriber::filter::LevelFilter;
 
 pub trait TestUniverse {
@@ -25,19 +33,79 @@ pub trait TestUniverse {
         }));
         use tracing_subscriber::prelude::*;
         let tracing_layer = tracing_subscriber::fmt::layer().with_filter(LevelFilter::DEBUG);
-        tracing_subscriber::registry().with(tracing_layer).init();
+        // several universes can run in the same test, only the first one installs the subscriber
+        let _ = tracing_subscriber::registry()
+            .with(tracing_layer)
+            .try_init();
+    }
+
+    /// Latest slot reached by the module under test, if the universe is able to observe it.
+    fn current_slot(&self) -> Option<Slot> {
+        None
+    }
+
+    /// Poll `current_slot` until it reaches `target` or `timeout` elapses.
+    /// Returns `false` on timeout or if the universe does not report its current slot.
+    fn wait_for_slot(&self, target: Slot, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.current_slot() {
+                Some(slot) if slot >= target => return true,
+                Some(_) => {}
+                None => return false,
+            }
+            if Instant::now() >= deadline {
+                return false;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
     }
 
+    /// Create a block at slot (1, 0) with the genesis blocks of a two-thread network as parents.
     fn create_block(keypair: &KeyPair) -> SecureShareBlock {
+        Self::create_block_with_thread_count(keypair, 2)
+    }
+
+    /// Create a block at slot (1, 0) with one genesis parent per thread.
+    fn create_block_with_thread_count(keypair: &KeyPair, thread_count: u8) -> SecureShareBlock {
+        Self::create_block_at(keypair, Slot::new(1, 0), genesis_block_ids(thread_count))
+    }
+
+    /// Create a chain of `length` blocks of a two-thread network, see `create_block_chain_with_thread_count`.
+    fn create_block_chain(keypair: &KeyPair, length: usize) -> Vec<SecureShareBlock> {
+        Self::create_block_chain_with_thread_count(keypair, length, 2)
+    }
+
+    /// Create `length` blocks at consecutive slots starting from (1, 0).
+    /// Each block has the latest block of each thread as parents (the genesis block of the thread
+    /// if none was created in it yet), so it references the previous block of the chain.
+    fn create_block_chain_with_thread_count(
+        keypair: &KeyPair,
+        length: usize,
+        thread_count: u8,
+    ) -> Vec<SecureShareBlock> {
+        let mut parents = genesis_block_ids(thread_count);
+        let mut slot = Slot::new(1, 0);
+        let mut blocks = Vec::with_capacity(length);
+        for _ in 0..length {
+            let block = Self::create_block_at(keypair, slot, parents.clone());
+            parents[slot.thread as usize] = block.id;
+            blocks.push(block);
+            slot = slot
+                .get_next_slot(thread_count)
+                .expect("slot overflow while creating a block chain");
+        }
+        blocks
+    }
+
+    /// Create an empty block at `slot` with the given `parents`.
+    fn create_block_at(keypair: &KeyPair, slot: Slot, parents: Vec<BlockId>) -> SecureShareBlock {
         let header = BlockHeader::new_verifiable(
             BlockHeader {
                 current_version: 0,
                 announced_version: None,
-                slot: Slot::new(1, 0),
-                parents: vec![
-                    BlockId::generate_from_hash(Hash::compute_from("Genesis 0".as_bytes())),
-                    BlockId::generate_from_hash(Hash::compute_from("Genesis 1".as_bytes())),
-                ],
+                slot,
+                parents,
                 operation_merkle_root: Hash::compute_from(&Vec::new()),
                 endorsements: Vec::new(),
                 denunciations: Vec::new(),
@@ -57,13 +125,70 @@ pub trait TestUniverse {
         )
         .unwrap()
     }
+
+    /// Panic with a descriptive message if `block` is malformed: invalid block or header signature,
+    /// `operation_merkle_root` not matching the block operations, or not one parent per thread.
+    fn assert_block_valid(block: &SecureShareBlock, thread_count: u8) {
+        if let Err(err) = block.verify_signature() {
+            panic!("block {} has an invalid signature: {}", block.id, err);
+        }
+        let header = &block.content.header;
+        if let Err(err) = header.verify_signature() {
+            panic!(
+                "header of block {} has an invalid signature: {}",
+                block.id, err
+            );
+        }
+        assert_eq!(
+            header.content.operation_merkle_root,
+            compute_operations_hash(&block.content.operations, &OperationIdSerializer::new()),
+            "operation_merkle_root of block {} does not match its operations",
+            block.id
+        );
+        assert_eq!(
+            header.content.parents.len(),
+            thread_count as usize,
+            "block {} should have one parent per thread",
+            block.id
+        );
+    }
 }
 
+/// Ids of the genesis blocks of a network with `thread_count` threads, one per thread.
+pub fn genesis_block_ids(thread_count: u8) -> Vec<BlockId> {
+    (0..thread_count)
+        .map(|thread| {
+            BlockId::generate_from_hash(Hash::compute_from(
+                format!("Genesis {}", thread).as_bytes(),
+            ))
+        })
+        .collect()
+}
+
+/// A universe able to run its module for a set of stakers, with minimal foreign controllers.
+/// Only the module under test runs for real: the foreign controllers are the mocks of its crate.
+pub trait TestStack: TestUniverse + Sized {
+    /// Foreign controllers letting the module run with `keypairs` as the only stakers.
+    /// Tests needing more can customize them and call `TestUniverse::new`.
+    fn stack_controllers(keypairs: &[KeyPair], config: &Self::Config) -> Self::ForeignControllers;
+
+    /// Start the module with the `stack_controllers` of `keypairs`.
+    fn start_stack(keypairs: &[KeyPair], config: Self::Config) -> Self {
+        let controllers = Self::stack_controllers(keypairs, &config);
+        Self::new(controllers, config)
+    }
+}
+
+/// One-shot synchronization point between the code under test and the test.
+///
+/// Use `wait` from plain threads and `wait_async` from async tasks: `wait` blocks the calling
+/// thread, which stalls (or deadlocks) a tokio runtime if called from one of its tasks.
 pub struct Breakpoint(Arc<BreakpointInner>);
 
 struct BreakpointInner {
     mutex: Mutex<bool>,
     condvar: Condvar,
+    notify: Notify,
 }
 
 impl Default for Breakpoint {
@@ -77,6 +202,7 @@ impl Breakpoint {
         Self(Arc::new(BreakpointInner {
             mutex: Mutex::new(false),
             condvar: Condvar::new(),
+            notify: Notify::new(),
         }))
     }
 
@@ -84,6 +210,8 @@ impl Breakpoint {
         Breakpoint(self.0.clone())
     }
 
+    /// Block the current thread until the breakpoint is triggered.
+    /// Not to be called from an async task, use `wait_async` there.
     pub fn wait(&self) {
         let mut started = self.0.mutex.lock().unwrap();
         while !*started {
@@ -91,10 +219,220 @@ impl Breakpoint {
         }
     }
 
+    /// Wait for the breakpoint to be triggered without blocking the async runtime.
+    /// Cancellation-safe: dropping the future before completion has no effect on the breakpoint.
+    pub async fn wait_async(&self) {
+        loop {
+            // register before checking so that a trigger in between is not missed
+            let notified = self.0.notify.notified();
+            if self.is_triggered() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Whether the breakpoint has been triggered, without waiting for it.
+    pub fn is_triggered(&self) -> bool {
+        *self.0.mutex.lock().unwrap()
+    }
+
     pub fn trigger(&self) {
         let mut started = self.0.mutex.lock().unwrap();
         *started = true;
         // We notify the condvar that the value has changed.
         self.0.condvar.notify_one();
+        self.0.notify.notify_waiters();
+    }
+}
+
+/// Test-controlled clock: time only moves when the test calls `advance` or `set`.
+/// Clones share the same time, so that the test keeps a handle on the clock given to a worker config.
+///
+/// Only consensus reads its time from its config `clock` for now: execution, pool and factory
+/// still follow the system time, so a `MockClock` does not drive their slots.
+#[derive(Debug, Clone)]
+pub struct MockClock(Arc<Mutex<MassaTime>>);
+
+/// How often a worker waiting for a time not reached yet checks a `MockClock` again
+const MOCK_CLOCK_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+impl MockClock {
+    /// Create a clock stopped at `start`.
+    pub fn new(start: MassaTime) -> Self {
+        Self(Arc::new(Mutex::new(start)))
+    }
+
+    /// Move the clock to `time`, possibly backwards.
+    pub fn set(&self, time: MassaTime) {
+        *self.0.lock().unwrap() = time;
+    }
+
+    /// Move the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        let duration = MassaTime::try_from(duration).expect("duration overflows MassaTime");
+        let mut time = self.0.lock().unwrap();
+        *time = time.saturating_add(duration);
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Result<MassaTime, TimeError> {
+        Ok(*self.0.lock().unwrap())
+    }
+
+    /// Right away if `timestamp` is reached, otherwise after a short poll interval:
+    /// the test may advance the clock at any moment.
+    fn wake_up_instant(&self, timestamp: MassaTime) -> Result<Instant, TimeError> {
+        if self.now()? >= timestamp {
+            Ok(Instant::now())
+        } else {
+            Ok(Instant::now() + MOCK_CLOCK_POLL_INTERVAL)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Universe whose current slot is set by the test.
+    struct SlotUniverse(Mutex<Option<Slot>>);
+
+    impl TestUniverse for SlotUniverse {
+        type ForeignControllers = Option<Slot>;
+        type Config = ();
+
+        fn new(current_slot: Self::ForeignControllers, _config: Self::Config) -> Self {
+            Self(Mutex::new(current_slot))
+        }
+
+        fn current_slot(&self) -> Option<Slot> {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    #[test]
+    fn wait_for_slot_reached() {
+        let universe = SlotUniverse::new(Some(Slot::new(3, 1)), ());
+        let start = Instant::now();
+        assert!(universe.wait_for_slot(Slot::new(3, 0), Duration::from_secs(10)));
+        assert!(universe.wait_for_slot(Slot::new(3, 1), Duration::from_secs(10)));
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn wait_for_slot_timeout() {
+        let universe = SlotUniverse::new(Some(Slot::new(3, 1)), ());
+        let start = Instant::now();
+        assert!(!universe.wait_for_slot(Slot::new(4, 0), Duration::from_millis(100)));
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        // a universe that does not report its slot gives up right away
+        let universe = SlotUniverse::new(None, ());
+        assert!(!universe.wait_for_slot(Slot::new(0, 0), Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn create_block_chain_with_thread_count_links() {
+        let thread_count = 3;
+        let keypair = KeyPair::generate(0).unwrap();
+        let blocks = SlotUniverse::create_block_chain_with_thread_count(&keypair, 7, thread_count);
+        assert_eq!(blocks.len(), 7);
+
+        // consecutive slots, every thread in turn
+        let slots: Vec<Slot> = blocks
+            .iter()
+            .map(|block| block.content.header.content.slot)
+            .collect();
+        assert_eq!(
+            slots,
+            vec![
+                Slot::new(1, 0),
+                Slot::new(1, 1),
+                Slot::new(1, 2),
+                Slot::new(2, 0),
+                Slot::new(2, 1),
+                Slot::new(2, 2),
+                Slot::new(3, 0),
+            ]
+        );
+
+        // the parent in each thread is the latest block of that thread, starting from the genesis blocks
+        let mut latest = genesis_block_ids(thread_count);
+        for (index, block) in blocks.iter().enumerate() {
+            SlotUniverse::assert_block_valid(block, thread_count);
+            assert_eq!(
+                block.content.header.content.parents, latest,
+                "wrong parents for block {}",
+                index
+            );
+            if index > 0 {
+                assert!(block
+                    .content
+                    .header
+                    .content
+                    .parents
+                    .contains(&blocks[index - 1].id));
+            }
+            latest[block.content.header.content.slot.thread as usize] = block.id;
+        }
+    }
+
+    #[tokio::test]
+    async fn wait_async_triggered_while_waiting() {
+        let breakpoint = Breakpoint::new();
+        let trigger = breakpoint.get_trigger_handle();
+        let waiter = tokio::spawn(async move { breakpoint.wait_async().await });
+
+        // the waiter does not return before the trigger
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiter.is_finished());
+
+        // triggered from a plain thread, as the code under test does
+        std::thread::spawn(move || trigger.trigger())
+            .join()
+            .unwrap();
+        tokio::time::timeout(Duration::from_secs(5), waiter)
+            .await
+            .expect("wait_async did not return after the trigger")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn wait_async_triggered_before_waiting() {
+        let breakpoint = Breakpoint::new();
+        breakpoint.get_trigger_handle().trigger();
+        assert!(breakpoint.is_triggered());
+        tokio::time::timeout(Duration::from_secs(5), breakpoint.wait_async())
+            .await
+            .expect("wait_async missed a trigger that happened before it was called");
+    }
+
+    #[test]
+    fn mock_clock_moves_only_when_told() {
+        let clock = MockClock::new(MassaTime::from_millis(1000));
+        let handle = clock.clone();
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(clock.now().unwrap(), MassaTime::from_millis(1000));
+
+        // clones share the time
+        handle.advance(Duration::from_millis(500));
+        assert_eq!(clock.now().unwrap(), MassaTime::from_millis(1500));
+        handle.set(MassaTime::from_millis(200));
+        assert_eq!(clock.now().unwrap(), MassaTime::from_millis(200));
+    }
+
+    #[test]
+    fn mock_clock_wake_up_instant() {
+        let clock = MockClock::new(MassaTime::from_millis(1000));
+
+        // a reached time wakes up right away, a future one is polled shortly whatever its distance
+        let before = Instant::now();
+        assert!(clock.wake_up_instant(MassaTime::from_millis(1000)).unwrap() <= Instant::now());
+        let wake_up = clock
+            .wake_up_instant(MassaTime::from_millis(1_000_000_000))
+            .unwrap();
+        assert!(wake_up > before && wake_up <= Instant::now() + MOCK_CLOCK_POLL_INTERVAL);
     }
 }
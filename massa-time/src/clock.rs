@@ -0,0 +1,31 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+//! Source of the current time of the workers
+
+use crate::{MassaTime, TimeError};
+use std::time::Instant;
+
+/// Source of the current time, used by the workers to compute the current slot.
+/// The system clock is used by the node, tests can provide a clock that they control.
+/// Only consensus takes its clock from its config, the other workers still use the system time.
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /// Current UNIX timestamp (resolution: milliseconds)
+    fn now(&self) -> Result<MassaTime, TimeError>;
+
+    /// Instant at which a worker waiting for `timestamp` should wake up and check `now` again.
+    /// The clock may not have reached `timestamp` yet at that instant.
+    fn wake_up_instant(&self, timestamp: MassaTime) -> Result<Instant, TimeError>;
+}
+
+/// Clock following the system time
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Result<MassaTime, TimeError> {
+        MassaTime::now()
+    }
+
+    fn wake_up_instant(&self, timestamp: MassaTime) -> Result<Instant, TimeError> {
+        timestamp.estimate_instant()
+    }
+}